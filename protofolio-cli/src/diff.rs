@@ -0,0 +1,123 @@
+//! Rendering of spec diffs for the `diff` command
+
+use protofolio::{Change, ChangeKind, SpecDiff};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+/// Human-readable diff: breaking changes, then non-breaking ones, then a summary line
+pub fn render_text(diff: &SpecDiff) -> String {
+    let mut out = String::new();
+    for (title, changes) in [
+        ("Breaking changes:", diff.breaking().collect::<Vec<_>>()),
+        ("Non-breaking changes:", diff.non_breaking().collect()),
+    ] {
+        if changes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "{}", title);
+        for change in changes {
+            let _ = writeln!(out, "  {}: {}", change.pointer, change.description);
+        }
+        out.push('\n');
+    }
+    if diff.is_empty() {
+        out.push_str("✓ No changes");
+    } else if diff.has_breaking_changes() {
+        let _ = write!(
+            out,
+            "✗ {} breaking change(s), {} non-breaking change(s)",
+            diff.breaking().count(),
+            diff.non_breaking().count()
+        );
+    } else {
+        let _ = write!(
+            out,
+            "✓ No breaking changes ({} non-breaking change(s))",
+            diff.non_breaking().count()
+        );
+    }
+    out
+}
+
+/// Machine-readable diff
+///
+/// ```json
+/// {
+///   "breaking": 1,
+///   "nonBreaking": 0,
+///   "changes": [
+///     {"kind": "breaking", "pointer": "/channels/users", "description": "Channel 'users' was removed"}
+///   ]
+/// }
+/// ```
+pub fn render_json(diff: &SpecDiff) -> Value {
+    json!({
+        "breaking": diff.breaking().count(),
+        "nonBreaking": diff.non_breaking().count(),
+        "changes": diff.changes().iter().map(change_json).collect::<Vec<_>>(),
+    })
+}
+
+fn change_json(change: &Change) -> Value {
+    let kind = match change.kind {
+        ChangeKind::Breaking => "breaking",
+        ChangeKind::NonBreaking => "non-breaking",
+    };
+    json!({
+        "kind": kind,
+        "pointer": change.pointer,
+        "description": change.description,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use protofolio::{diff, AsyncApiSpec};
+
+    fn spec(channels: &[&str]) -> AsyncApiSpec {
+        let channels: serde_json::Map<String, Value> = channels
+            .iter()
+            .map(|name| {
+                (
+                    (*name).to_string(),
+                    json!({"address": name, "messages": {"Event": {"payload": {"type": "object"}}}}),
+                )
+            })
+            .collect();
+        test_utils::spec(json!({"channels": channels}))
+    }
+
+    #[test]
+    fn test_render_no_changes() {
+        let spec = spec(&["events"]);
+        let diff = diff(&spec, &spec);
+
+        assert_eq!(render_text(&diff), "✓ No changes");
+        assert_eq!(
+            render_json(&diff),
+            json!({"breaking": 0, "nonBreaking": 0, "changes": []})
+        );
+    }
+
+    #[test]
+    fn test_render_changes() {
+        let diff = diff(&spec(&["events", "users"]), &spec(&["events", "orders"]));
+
+        assert_eq!(
+            render_text(&diff),
+            "Breaking changes:\n  /channels/users: Channel 'users' was removed\n\n\
+             Non-breaking changes:\n  /channels/orders: Channel 'orders' was added\n\n\
+             ✗ 1 breaking change(s), 1 non-breaking change(s)"
+        );
+
+        let json = render_json(&diff);
+        assert_eq!(json["breaking"], 1);
+        assert_eq!(json["nonBreaking"], 1);
+        assert_eq!(
+            json["changes"][0],
+            json!({"kind": "breaking", "pointer": "/channels/users", "description": "Channel 'users' was removed"})
+        );
+    }
+}
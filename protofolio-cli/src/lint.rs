@@ -0,0 +1,124 @@
+//! Rendering of lint reports for the `lint` command
+
+use protofolio::{LintIssue, LintReport};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+/// Human-readable report: one line per issue, followed by a summary line
+pub fn render_text(spec_name: &str, report: &LintReport) -> String {
+    let mut out = String::new();
+    for issue in report.issues() {
+        let _ = writeln!(out, "{}", issue);
+    }
+    if !report.is_empty() {
+        out.push('\n');
+    }
+    let errors = report.errors().count();
+    let warnings = report.warnings().count();
+    if report.is_empty() {
+        let _ = write!(out, "✓ {} follows all lint rules", spec_name);
+    } else if report.has_errors() {
+        let _ = write!(
+            out,
+            "✗ {}: {} error(s), {} warning(s)",
+            spec_name, errors, warnings
+        );
+    } else {
+        let _ = write!(out, "✓ {}: {} warning(s)", spec_name, warnings);
+    }
+    out
+}
+
+/// Machine-readable report
+///
+/// ```json
+/// {
+///   "errors": 0,
+///   "warnings": 1,
+///   "issues": [
+///     {"rule": "message-summary", "severity": "warning", "pointer": "/components/messages/Event", "message": "..."}
+///   ]
+/// }
+/// ```
+pub fn render_json(report: &LintReport) -> Value {
+    json!({
+        "errors": report.errors().count(),
+        "warnings": report.warnings().count(),
+        "issues": report.issues().iter().map(issue_json).collect::<Vec<_>>(),
+    })
+}
+
+fn issue_json(issue: &LintIssue) -> Value {
+    json!({
+        "rule": issue.rule,
+        "severity": issue.severity.to_string(),
+        "pointer": issue.pointer,
+        "message": issue.message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use protofolio::lint;
+
+    fn report(value: Value) -> LintReport {
+        lint(&test_utils::spec(value))
+    }
+
+    #[test]
+    fn test_render_clean_report() {
+        let report = report(json!({
+            "channels": {
+                "events": {"address": "events", "messages": {"Event": {
+                    "messageId": "event-v1",
+                    "summary": "An event",
+                    "examples": [{}],
+                    "payload": {"type": "object"}
+                }}}
+            }
+        }));
+
+        assert_eq!(
+            render_text("asyncapi.json", &report),
+            "✓ asyncapi.json follows all lint rules"
+        );
+        assert_eq!(
+            render_json(&report),
+            json!({"errors": 0, "warnings": 0, "issues": []})
+        );
+    }
+
+    #[test]
+    fn test_render_report_with_warnings() {
+        let report = report(json!({
+            "channels": {
+                "events": {"address": "Events", "messages": {"Event": {
+                    "summary": "An event",
+                    "examples": [{}],
+                    "payload": {"type": "object"}
+                }}}
+            }
+        }));
+
+        assert_eq!(
+            render_text("asyncapi.json", &report),
+            "warning at /channels/events/address: Channel address 'Events' is not dot.case (e.g. 'orders.created') [channel-address-dot-case]\n\n\
+             ✓ asyncapi.json: 1 warning(s)"
+        );
+        assert_eq!(
+            render_json(&report),
+            json!({
+                "errors": 0,
+                "warnings": 1,
+                "issues": [{
+                    "rule": "channel-address-dot-case",
+                    "severity": "warning",
+                    "pointer": "/channels/events/address",
+                    "message": "Channel address 'Events' is not dot.case (e.g. 'orders.created')"
+                }]
+            })
+        );
+    }
+}
@@ -1,17 +1,31 @@
 //! protofolio-cli - CLI tool for generating TypeScript types from AsyncAPI specifications
 //!
 //! This tool generates TypeScript type definitions from AsyncAPI 3.0 specifications
-//! using Modelina.
+//! with a built-in generator; no external runtime is required. It can also
+//! validate specification files, check them against team conventions with
+//! configurable lint rules, and detect breaking changes between two versions
+//! of a specification, e.g. to gate spec changes in CI. `bundle` pulls the
+//! files a specification references into a single document, `split` does the
+//! reverse, and `merge` combines the specifications of several services into
+//! one document.
+
+mod diff;
+mod lint;
+#[cfg(test)]
+mod test_utils;
+mod typescript;
+mod validate;
 
 use clap::{Parser, Subcommand};
-use protofolio::AsyncApiSpec;
+use protofolio::{
+    AsyncApiSpec, BundleError, DocumentFormat, Linter, MergeError, MergeStrategy, RuleSet,
+};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 
 #[derive(Parser)]
 #[command(name = "protofolio")]
-#[command(about = "Generate TypeScript types from AsyncAPI specifications", long_about = None)]
+#[command(about = "Generate TypeScript types from, validate, lint, diff, merge, bundle and split AsyncAPI specifications", long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
@@ -33,6 +47,116 @@ enum Commands {
         #[arg(short, long)]
         format: Option<SpecFormat>,
     },
+
+    /// Validate an AsyncAPI specification file, exiting non-zero if it is invalid
+    Validate {
+        /// Path to the AsyncAPI specification file (JSON or YAML)
+        #[arg(short, long)]
+        spec: PathBuf,
+
+        /// Output format of the validation report
+        #[arg(short, long, default_value = "text")]
+        format: ReportFormat,
+
+        /// Format of the input spec file (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
+
+    /// Check an AsyncAPI specification file against lint rules, exiting non-zero on errors
+    Lint {
+        /// Path to the AsyncAPI specification file (JSON or YAML)
+        #[arg(short, long)]
+        spec: PathBuf,
+
+        /// Ruleset file (TOML or YAML) enabling, disabling or setting the severity of rules
+        #[arg(short, long)]
+        ruleset: Option<PathBuf>,
+
+        /// Output format of the lint report
+        #[arg(short, long, default_value = "text")]
+        format: ReportFormat,
+
+        /// Format of the input spec file (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
+
+    /// Compare two AsyncAPI specification files, exiting non-zero on breaking changes
+    Diff {
+        /// Path to the old (e.g. published) specification file
+        old: PathBuf,
+
+        /// Path to the new specification file
+        new: PathBuf,
+
+        /// Output format of the diff
+        #[arg(short, long, default_value = "text")]
+        format: ReportFormat,
+
+        /// Format of both spec files (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
+
+    /// Bundle a specification whose `$ref`s point at other files into a single document
+    Bundle {
+        /// Path to the root AsyncAPI specification file (JSON or YAML)
+        spec: PathBuf,
+
+        /// Output file (printed to stdout if not specified)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Format of the bundled document (from the output file extension, or YAML)
+        #[arg(short, long)]
+        format: Option<SpecFormat>,
+
+        /// Format of the input spec file (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
+
+    /// Merge several AsyncAPI specification files into one document
+    Merge {
+        /// Paths to the specification files; info is taken from the first
+        #[arg(required = true, num_args = 2..)]
+        specs: Vec<PathBuf>,
+
+        /// Output file (printed to stdout if not specified)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// How to resolve conflicting definitions
+        #[arg(long, default_value = "fail")]
+        strategy: ConflictStrategy,
+
+        /// Format of the merged document (from the output file extension, or YAML)
+        #[arg(short, long)]
+        format: Option<SpecFormat>,
+
+        /// Format of the input spec files (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
+
+    /// Split a specification into a root document and one file per component
+    Split {
+        /// Path to the AsyncAPI specification file (JSON or YAML)
+        spec: PathBuf,
+
+        /// Output directory
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Format of the written files
+        #[arg(short, long, default_value = "yaml")]
+        format: SpecFormat,
+
+        /// Format of the input spec file (auto-detected if not specified)
+        #[arg(long)]
+        spec_format: Option<SpecFormat>,
+    },
 }
 
 #[derive(Clone, Copy, clap::ValueEnum)]
@@ -41,6 +165,31 @@ enum SpecFormat {
     Yaml,
 }
 
+impl From<SpecFormat> for DocumentFormat {
+    fn from(format: SpecFormat) -> Self {
+        match format {
+            SpecFormat::Json => DocumentFormat::Json,
+            SpecFormat::Yaml => DocumentFormat::Yaml,
+        }
+    }
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum ConflictStrategy {
+    /// Exit with an error listing the conflicts
+    Fail,
+    /// Keep the definition of the earlier file
+    PreferLeft,
+    /// Keep both, prefixing the later file's definition with its file name
+    Namespace,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum ReportFormat {
+    Text,
+    Json,
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -50,6 +199,42 @@ fn main() {
             output,
             format,
         } => generate_types(&spec, &output, format),
+        Commands::Validate {
+            spec,
+            format,
+            spec_format,
+        } => validate_spec_file(&spec, format, spec_format),
+        Commands::Lint {
+            spec,
+            ruleset,
+            format,
+            spec_format,
+        } => lint_spec_file(&spec, ruleset.as_deref(), format, spec_format),
+        Commands::Diff {
+            old,
+            new,
+            format,
+            spec_format,
+        } => diff_spec_files(&old, &new, format, spec_format),
+        Commands::Bundle {
+            spec,
+            output,
+            format,
+            spec_format,
+        } => bundle_spec_file(&spec, output.as_deref(), format, spec_format),
+        Commands::Merge {
+            specs,
+            output,
+            strategy,
+            format,
+            spec_format,
+        } => merge_spec_files(&specs, output.as_deref(), strategy, format, spec_format),
+        Commands::Split {
+            spec,
+            output,
+            format,
+            spec_format,
+        } => split_spec_file(&spec, &output, format, spec_format),
     } {
         eprintln!("Error: {}", e);
         std::process::exit(1);
@@ -61,36 +246,11 @@ fn generate_types(
     output_dir: &Path,
     format: Option<SpecFormat>,
 ) -> Result<(), Error> {
-    // Check if spec file exists
-    if !spec_path.exists() {
-        return Err(Error::SpecFileNotFound(spec_path.to_path_buf()));
-    }
-
-    // Detect format if not specified
-    let detected_format = format.unwrap_or_else(|| {
-        let ext = spec_path
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-        match ext.as_str() {
-            "yaml" | "yml" => SpecFormat::Yaml,
-            _ => SpecFormat::Json,
-        }
-    });
-
-    // Read and parse the spec
     println!(
         "Reading AsyncAPI specification from: {}",
         spec_path.display()
     );
-    let spec_content = fs::read_to_string(spec_path)?;
-    let spec: AsyncApiSpec = match detected_format {
-        SpecFormat::Json => serde_json::from_str(&spec_content)
-            .map_err(|e| Error::ParseError(format!("Failed to parse JSON: {}", e)))?,
-        SpecFormat::Yaml => serde_yaml_ng::from_str(&spec_content)
-            .map_err(|e| Error::ParseError(format!("Failed to parse YAML: {}", e)))?,
-    };
+    let spec = read_spec(spec_path, format)?;
 
     println!("✓ Successfully parsed AsyncAPI specification");
     println!("  Title: {}", spec.info.title);
@@ -102,89 +262,266 @@ fn generate_types(
         println!("Created output directory: {}", output_dir.display());
     }
 
-    // Write spec to temporary file for Node.js script
-    let temp_dir = std::env::temp_dir();
-    let temp_spec_file = temp_dir.join("asyncapi-spec.json");
-    let spec_json = serde_json::to_string_pretty(&spec)?;
-    fs::write(&temp_spec_file, spec_json)?;
-
     println!("Generating TypeScript types...");
+    let output_file = output_dir.join("index.ts");
+    fs::write(&output_file, typescript::generate(&spec))?;
+    println!("  ✓ Generated: {}", output_file.display());
 
-    // Get the path to the Node.js script
-    let script_path = get_script_path()?;
-
-    // Run the Node.js script
-    let output = Command::new("node")
-        .arg(&script_path)
-        .arg(&temp_spec_file)
-        .arg(output_dir)
-        .output()
-        .map_err(|e| Error::CommandError(format!("Failed to execute Node.js: {}", e)))?;
+    println!(
+        "✓ TypeScript types generated successfully in: {}",
+        output_dir.display()
+    );
 
-    // Clean up temp file
-    let _ = fs::remove_file(&temp_spec_file);
+    Ok(())
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(Error::GenerationError(format!(
-            "TypeScript generation failed:\n{}",
-            stderr
-        )));
+fn validate_spec_file(
+    spec_path: &Path,
+    report_format: ReportFormat,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let spec = read_spec(spec_path, spec_format)?;
+    let report = protofolio::validate_spec_report(&spec);
+
+    match report_format {
+        ReportFormat::Text => println!(
+            "{}",
+            validate::render_text(&spec_path.display().to_string(), &report)
+        ),
+        ReportFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&validate::render_json(&report))
+                .map_err(|e| Error::ParseError(e.to_string()))?
+        ),
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    if !stdout.is_empty() {
-        print!("{}", stdout);
+    if report.is_valid() {
+        Ok(())
+    } else {
+        Err(Error::InvalidSpec(report.errors().count()))
     }
+}
 
-    println!(
-        "✓ TypeScript types generated successfully in: {}",
-        output_dir.display()
-    );
+fn lint_spec_file(
+    spec_path: &Path,
+    ruleset_path: Option<&Path>,
+    report_format: ReportFormat,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let spec = read_spec(spec_path, spec_format)?;
+    let mut linter = Linter::new();
+    if let Some(ruleset_path) = ruleset_path {
+        let ruleset =
+            RuleSet::from_path(ruleset_path).map_err(|e| Error::ParseError(e.to_string()))?;
+        linter = linter
+            .with_ruleset(ruleset)
+            .map_err(|e| Error::ParseError(e.to_string()))?;
+    }
+    let report = linter.lint(&spec);
+
+    match report_format {
+        ReportFormat::Text => println!(
+            "{}",
+            lint::render_text(&spec_path.display().to_string(), &report)
+        ),
+        ReportFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&lint::render_json(&report))
+                .map_err(|e| Error::ParseError(e.to_string()))?
+        ),
+    }
 
-    Ok(())
+    if report.has_errors() {
+        Err(Error::LintErrors(report.errors().count()))
+    } else {
+        Ok(())
+    }
 }
 
-fn get_script_path() -> Result<PathBuf, Error> {
-    // Strategy 1: Try relative to current working directory
-    let script_path = PathBuf::from("scripts/generate-types.js");
-    if script_path.exists() {
-        return Ok(script_path);
+fn diff_spec_files(
+    old_path: &Path,
+    new_path: &Path,
+    report_format: ReportFormat,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let old = read_spec(old_path, spec_format)?;
+    let new = read_spec(new_path, spec_format)?;
+    let changes = protofolio::diff(&old, &new);
+
+    match report_format {
+        ReportFormat::Text => println!("{}", diff::render_text(&changes)),
+        ReportFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&diff::render_json(&changes))
+                .map_err(|e| Error::ParseError(e.to_string()))?
+        ),
     }
 
-    // Strategy 2: Try absolute path from current directory
-    if let Ok(cwd) = std::env::current_dir() {
-        let script_path = cwd.join("scripts").join("generate-types.js");
-        if script_path.exists() {
-            return Ok(script_path);
-        }
+    if changes.has_breaking_changes() {
+        Err(Error::BreakingChanges(changes.breaking().count()))
+    } else {
+        Ok(())
+    }
+}
+
+fn bundle_spec_file(
+    spec_path: &Path,
+    output: Option<&Path>,
+    format: Option<SpecFormat>,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let document = read_document(spec_path, spec_format)?;
+    // Pull in objects referenced from other files
+    let spec = protofolio::bundle_document(document, spec_path).map_err(Error::Bundle)?;
+    write_spec(&spec, output, format)?;
+    if let Some(output) = output {
+        eprintln!(
+            "✓ Bundled {} into {}",
+            spec_path.display(),
+            output.display()
+        );
     }
+    Ok(())
+}
 
-    // Strategy 3: Try relative to the executable location
-    // This works when the CLI is installed and run from anywhere
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(exe_dir) = exe_path.parent() {
-            // For installed binaries, look for scripts in the workspace root
-            // The structure is: workspace/scripts/generate-types.js
-            // and the binary is in: workspace/target/release/protofolio
-            let mut search_dir = exe_dir;
-            for _ in 0..5 {
-                // Look for scripts directory at this level
-                let script_path = search_dir.join("scripts").join("generate-types.js");
-                if script_path.exists() {
-                    return Ok(script_path);
+fn merge_spec_files(
+    spec_paths: &[PathBuf],
+    output: Option<&Path>,
+    strategy: ConflictStrategy,
+    format: Option<SpecFormat>,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let (first, rest) = spec_paths
+        .split_first()
+        .ok_or_else(|| Error::ParseError("No specification files given".to_string()))?;
+    let mut spec = read_spec(first, spec_format)?;
+
+    for spec_path in rest {
+        let other = read_spec(spec_path, spec_format)?;
+        let strategy = match strategy {
+            ConflictStrategy::Fail => MergeStrategy::Fail,
+            ConflictStrategy::PreferLeft => MergeStrategy::PreferLeft,
+            // Each file's definitions are namespaced by its name
+            ConflictStrategy::Namespace => MergeStrategy::Namespace(
+                spec_path
+                    .file_stem()
+                    .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()),
+            ),
+        };
+        match spec.merge(other, strategy) {
+            Ok(conflicts) => {
+                for conflict in conflicts {
+                    eprintln!("warning: {} ({})", conflict, spec_path.display());
                 }
-                // Go up one level
-                if let Some(parent) = search_dir.parent() {
-                    search_dir = parent;
-                } else {
-                    break;
+            }
+            Err(MergeError::Conflicts(conflicts)) => {
+                for conflict in &conflicts {
+                    eprintln!("error: {} ({})", conflict, spec_path.display());
                 }
+                return Err(Error::MergeConflicts(conflicts.len()));
             }
+            Err(e) => return Err(Error::Merge(e)),
         }
     }
 
-    Err(Error::ScriptNotFound)
+    write_spec(&spec, output, format)?;
+    if let Some(output) = output {
+        eprintln!(
+            "✓ Merged {} specification(s) into {}",
+            spec_paths.len(),
+            output.display()
+        );
+    }
+    Ok(())
+}
+
+/// Write `spec` to `output`, or stdout if not given
+///
+/// Without an explicit format, the output file extension decides (YAML by default).
+fn write_spec(
+    spec: &AsyncApiSpec,
+    output: Option<&Path>,
+    format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let format = format.map_or_else(
+        || output.map_or(DocumentFormat::Yaml, DocumentFormat::from_path),
+        DocumentFormat::from,
+    );
+    let rendered = match format {
+        DocumentFormat::Json => {
+            protofolio::to_json(spec).map_err(|e| Error::ParseError(e.to_string()))?
+        }
+        DocumentFormat::Yaml => {
+            protofolio::to_yaml(spec).map_err(|e| Error::ParseError(e.to_string()))?
+        }
+    };
+
+    match output {
+        Some(output) => fs::write(output, rendered)?,
+        None => println!("{}", rendered),
+    }
+    Ok(())
+}
+
+fn split_spec_file(
+    spec_path: &Path,
+    output_dir: &Path,
+    format: SpecFormat,
+    spec_format: Option<SpecFormat>,
+) -> Result<(), Error> {
+    let spec = read_spec(spec_path, spec_format)?;
+    let files = protofolio::split(&spec, output_dir, format.into()).map_err(Error::Split)?;
+
+    for file in &files {
+        println!("  ✓ Written: {}", file.display());
+    }
+    println!(
+        "✓ Split {} into {} file(s) in: {}",
+        spec_path.display(),
+        files.len(),
+        output_dir.display()
+    );
+    Ok(())
+}
+
+/// Read and parse a JSON or YAML specification file
+///
+/// References to other files are kept as they are; only `bundle` follows them.
+fn read_spec(spec_path: &Path, format: Option<SpecFormat>) -> Result<AsyncApiSpec, Error> {
+    let document = read_document(spec_path, format)?;
+    serde_json::from_value(document)
+        .map_err(|e| Error::ParseError(format!("Invalid AsyncAPI specification: {}", e)))
+}
+
+/// Read and parse a JSON or YAML file without interpreting it as a specification
+fn read_document(spec_path: &Path, format: Option<SpecFormat>) -> Result<serde_json::Value, Error> {
+    // Check if spec file exists
+    if !spec_path.exists() {
+        return Err(Error::SpecFileNotFound(spec_path.to_path_buf()));
+    }
+
+    // Detect format if not specified
+    let detected_format = format.unwrap_or_else(|| {
+        let ext = spec_path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => SpecFormat::Yaml,
+            _ => SpecFormat::Json,
+        }
+    });
+
+    // Read and parse the spec
+    let spec_content = fs::read_to_string(spec_path)?;
+    let document = match detected_format {
+        SpecFormat::Json => serde_json::from_str(&spec_content)
+            .map_err(|e| Error::ParseError(format!("Failed to parse JSON: {}", e)))?,
+        SpecFormat::Yaml => serde_yaml_ng::from_str(&spec_content)
+            .map_err(|e| Error::ParseError(format!("Failed to parse YAML: {}", e)))?,
+    };
+    Ok(document)
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -198,17 +535,24 @@ enum Error {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
-    #[error("JSON serialization error: {0}")]
-    Json(#[from] serde_json::Error),
+    #[error("Specification is invalid: {0} error(s)")]
+    InvalidSpec(usize),
+
+    #[error("Found {0} lint error(s)")]
+    LintErrors(usize),
+
+    #[error("Found {0} breaking change(s)")]
+    BreakingChanges(usize),
+
+    #[error("Failed to bundle specification: {0}")]
+    Bundle(BundleError),
 
-    #[error("Command execution error: {0}")]
-    CommandError(String),
+    #[error("Failed to split specification: {0}")]
+    Split(BundleError),
 
-    #[error("TypeScript generation error: {0}")]
-    GenerationError(String),
+    #[error("Found {0} conflicting definition(s); use --strategy prefer-left or namespace to resolve them")]
+    MergeConflicts(usize),
 
-    #[error(
-        "Could not find generate-types.js script. Please ensure scripts/generate-types.js exists."
-    )]
-    ScriptNotFound,
+    #[error("Failed to merge specifications: {0}")]
+    Merge(MergeError),
 }
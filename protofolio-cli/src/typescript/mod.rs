@@ -0,0 +1,405 @@
+//! Native TypeScript type generation
+//!
+//! Walks an [`AsyncApiSpec`] and emits a single TypeScript module containing:
+//!
+//! - an interface (or type alias) for every schema in `components.schemas`
+//! - a type for every message payload and headers schema
+//! - string-literal unions for enums and unions for `oneOf`/`anyOf`
+//!   (discriminated when the variants carry a literal tag)
+//! - a `ChannelMessages` map from channel name to the union of its message types
+
+mod schema;
+
+use protofolio::{local_ref, AsyncApiSpec, IndexMap, Message, MessageOrRef};
+use schema::{
+    doc_comment, is_interface, render_members, render_type, render_variant, type_name, TypeNames,
+    INDENT,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+/// Banner placed at the top of every generated file
+const HEADER: &str = "// This file was generated by protofolio. Do not edit manually.\n";
+
+/// Generate TypeScript declarations for all schemas and messages of a spec
+///
+/// Output is deterministic: declarations are emitted in name order, so the same
+/// spec always produces the same file.
+pub fn generate(spec: &AsyncApiSpec) -> String {
+    let mut generator = Generator::default();
+    generator.collect(spec);
+    generator.finish()
+}
+
+/// Accumulates declarations while walking a spec
+#[derive(Default)]
+struct Generator {
+    /// Declaration source keyed by type name, in emission order
+    declarations: Vec<(String, String)>,
+    /// Channel name to the type names of its messages
+    channel_messages: BTreeMap<String, Vec<String>>,
+    /// Names component schemas are declared under, for rendering `$ref`s
+    names: TypeNames,
+}
+
+impl Generator {
+    fn collect(&mut self, spec: &AsyncApiSpec) {
+        if let Some(schemas) = spec.components.as_ref().and_then(|c| c.schemas.as_ref()) {
+            // Name every schema before declaring any, so refs between them render
+            // the final names
+            let schemas = sorted(schemas);
+            let names: Vec<String> = schemas
+                .iter()
+                .map(|(key, _)| {
+                    self.names
+                        .insert(local_ref(["components", "schemas", key]), &type_name(key))
+                })
+                .collect();
+            for (name, (_, schema)) in names.iter().zip(schemas) {
+                self.declare(name, schema);
+            }
+        }
+
+        let mut component_messages = HashMap::new();
+        if let Some(messages) = spec.components.as_ref().and_then(|c| c.messages.as_ref()) {
+            for (key, message) in sorted(messages) {
+                let name = self.declare_message(key, message);
+                component_messages.insert(local_ref(["components", "messages", key]), name);
+            }
+        }
+
+        // Inline messages first, so channel message refs can be resolved afterwards
+        let mut inline_messages = HashMap::new();
+        let channels = sorted(&spec.channels);
+        for (channel_name, channel) in &channels {
+            for (key, message_or_ref) in sorted(&channel.messages) {
+                if let MessageOrRef::Message(message) = message_or_ref {
+                    let name = self.declare_message(key, message);
+                    inline_messages
+                        .insert(local_ref(["channels", channel_name, "messages", key]), name);
+                }
+            }
+        }
+
+        for (channel_name, channel) in channels {
+            let types = sorted(&channel.messages)
+                .into_iter()
+                .filter_map(|(key, message_or_ref)| match message_or_ref {
+                    MessageOrRef::Message(_) => inline_messages
+                        .get(&local_ref(["channels", channel_name, "messages", key]))
+                        .cloned(),
+                    MessageOrRef::Ref(reference) => component_messages
+                        .get(&reference.ref_path)
+                        .or_else(|| inline_messages.get(&reference.ref_path))
+                        .cloned(),
+                })
+                .collect();
+            self.channel_messages.insert(channel_name.clone(), types);
+        }
+    }
+
+    /// Declare the payload (and headers) type of a message, returning the payload type name
+    fn declare_message(&mut self, key: &str, message: &Message) -> String {
+        let name = type_name(message.name.as_deref().unwrap_or(key));
+        let payload = with_description(&message.payload.schema, message);
+        let name = self.declare(&name, &payload);
+        if let Some(ref headers) = message.headers {
+            self.declare(&format!("{}Headers", name), &headers.schema);
+        }
+        name
+    }
+
+    /// Declare a named type, returning the name it was declared under
+    ///
+    /// Identical declarations are emitted once; a different declaration with an
+    /// already used name gets a numeric suffix.
+    fn declare(&mut self, name: &str, schema: &Value) -> String {
+        // A payload that is just a ref to a schema of the same name needs no alias
+        if schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .map(|reference| self.names.get(reference))
+            == Some(name.to_string())
+        {
+            return name.to_string();
+        }
+
+        let mut candidate = name.to_string();
+        let mut suffix = 2;
+        loop {
+            let source = declaration(&self.names, &candidate, schema);
+            match self.declarations.iter().find(|(n, _)| *n == candidate) {
+                Some((_, existing)) if *existing == source => return candidate,
+                Some(_) => {
+                    candidate = format!("{}{}", name, suffix);
+                    suffix += 1;
+                }
+                None => {
+                    self.declarations.push((candidate.clone(), source));
+                    return candidate;
+                }
+            }
+        }
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::from(HEADER);
+        for (_, source) in &self.declarations {
+            out.push('\n');
+            out.push_str(source);
+        }
+
+        out.push_str("\n/** Message types that can be sent over each channel */\n");
+        out.push_str("export interface ChannelMessages {\n");
+        for (channel, types) in &self.channel_messages {
+            let union = if types.is_empty() {
+                "never".to_string()
+            } else {
+                types.join(" | ")
+            };
+            let _ = writeln!(
+                out,
+                "{}{}: {};",
+                INDENT,
+                Value::String(channel.clone()),
+                union
+            );
+        }
+        out.push_str("}\n");
+        out.push_str("\n/** Names of all channels in the specification */\n");
+        out.push_str("export type ChannelName = keyof ChannelMessages;\n");
+        out
+    }
+}
+
+/// Source of a top-level `interface` or `type` declaration
+fn declaration(names: &TypeNames, name: &str, schema: &Value) -> String {
+    let mut out = doc_comment(schema, 0);
+    if is_interface(schema) {
+        if let Value::Object(object) = schema {
+            let _ = writeln!(out, "export interface {} {{", name);
+            out.push_str(&render_members(names, object, 1));
+            out.push_str("}\n");
+        }
+        return out;
+    }
+
+    let variants = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(Value::as_array);
+    match variants {
+        // Unions of object literals read better one variant per line
+        Some(variants) if variants.len() > 1 && render_type(names, schema, 1).contains('\n') => {
+            let _ = writeln!(out, "export type {} =", name);
+            let discriminator = schema.get("discriminator").and_then(Value::as_str);
+            let rendered: Vec<String> = variants
+                .iter()
+                .map(|variant| render_variant(names, variant, discriminator, 2))
+                .collect();
+            for (index, variant) in rendered.iter().enumerate() {
+                let end = if index + 1 == rendered.len() { ";" } else { "" };
+                let _ = writeln!(out, "{}| {}{}", INDENT, variant, end);
+            }
+        }
+        _ => {
+            let _ = writeln!(
+                out,
+                "export type {} = {};",
+                name,
+                render_type(names, schema, 0)
+            );
+        }
+    }
+    out
+}
+
+/// Attach the message description to a payload schema lacking one, for `JSDoc`
+fn with_description(schema: &Value, message: &Message) -> Value {
+    let mut schema = schema.clone();
+    let description = message
+        .description
+        .as_ref()
+        .or(message.summary.as_ref())
+        .or(message.title.as_ref());
+    if let (Value::Object(object), Some(description)) = (&mut schema, description) {
+        if !object.contains_key("description") && !object.contains_key("$ref") {
+            object.insert(
+                "description".to_string(),
+                Value::String(description.clone()),
+            );
+        }
+    }
+    schema
+}
+
+fn sorted<V>(map: &IndexMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use serde_json::json;
+
+    #[test]
+    fn test_generate_interfaces_and_channel_map() {
+        let spec = test_utils::spec(json!({
+            "channels": {
+                "user.events": {
+                    "address": "user.events",
+                    "messages": {
+                        "UserCreated": {
+                            "name": "UserCreated",
+                            "summary": "A user was created",
+                            "payload": {
+                                "type": "object",
+                                "properties": {
+                                    "id": {"type": "string", "description": "User ID"},
+                                    "email": {"type": ["string", "null"]},
+                                    "roles": {"type": "array", "items": {"$ref": "#/components/schemas/Role"}},
+                                    "address": {"$ref": "#/components/schemas/Address"}
+                                },
+                                "required": ["id", "roles", "address"]
+                            },
+                            "headers": {
+                                "type": "object",
+                                "properties": {"traceId": {"type": "string"}},
+                                "required": ["traceId"]
+                            }
+                        },
+                        "UserDeleted": {"$ref": "#/components/messages/UserDeleted"}
+                    }
+                },
+                "audit": {"address": "audit", "messages": {}}
+            },
+            "components": {
+                "schemas": {
+                    "Address": {
+                        "type": "object",
+                        "properties": {"street": {"type": "string"}, "zip-code": {"type": "string"}},
+                        "required": ["street"]
+                    },
+                    "Role": {"type": "string", "enum": ["admin", "member"], "description": "Role of a user"}
+                },
+                "messages": {
+                    "UserDeleted": {
+                        "payload": {
+                            "type": "object",
+                            "properties": {"id": {"type": "string"}, "at": {"type": "integer"}},
+                            "required": ["id"]
+                        }
+                    }
+                }
+            }
+        }));
+
+        insta::assert_snapshot!(generate(&spec));
+    }
+
+    #[test]
+    fn test_generate_discriminated_unions() {
+        let spec = test_utils::spec(json!({
+            "channels": {
+                "shapes": {
+                    "address": "shapes",
+                    "messages": {
+                        "ShapeEvent": {
+                            "payload": {
+                                "oneOf": [
+                                    {
+                                        "type": "object",
+                                        "properties": {"kind": {"const": "circle"}, "radius": {"type": "number"}},
+                                        "required": ["kind", "radius"]
+                                    },
+                                    {
+                                        "type": "object",
+                                        "properties": {"kind": {"const": "square"}, "side": {"type": "number"}},
+                                        "required": ["kind", "side"]
+                                    }
+                                ]
+                            }
+                        },
+                        "Animal": {
+                            "payload": {
+                                "discriminator": "petType",
+                                "oneOf": [
+                                    {"$ref": "#/components/schemas/Cat"},
+                                    {"$ref": "#/components/schemas/Dog"}
+                                ]
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Cat": {"type": "object", "properties": {"lives": {"type": "integer"}}},
+                    "Dog": {"type": "object", "properties": {"breed": {"type": "string"}}}
+                }
+            }
+        }));
+
+        insta::assert_snapshot!(generate(&spec));
+    }
+
+    #[test]
+    fn test_generate_renames_conflicting_messages() {
+        let spec = test_utils::spec(json!({
+            "channels": {
+                "a": {
+                    "address": "a",
+                    "messages": {"Event": {"payload": {"type": "object", "properties": {"a": {"type": "string"}}}}}
+                },
+                "b": {
+                    "address": "b",
+                    "messages": {"Event": {"payload": {"type": "object", "properties": {"b": {"type": "string"}}}}}
+                },
+                "c": {
+                    "address": "c",
+                    "messages": {"Event": {"payload": {"type": "object", "properties": {"a": {"type": "string"}}}}}
+                }
+            }
+        }));
+
+        let output = generate(&spec);
+        assert!(output.contains("export interface Event {"));
+        assert!(output.contains("export interface Event2 {"));
+        assert!(!output.contains("Event3"));
+        assert!(output.contains("  \"c\": Event;"));
+    }
+
+    #[test]
+    fn test_generate_refs_to_renamed_schemas() {
+        let spec = test_utils::spec(json!({
+            "channels": {
+                "profiles": {
+                    "address": "profiles",
+                    "messages": {"ProfileChanged": {"payload": {
+                        "type": "object",
+                        "properties": {
+                            "old": {"$ref": "#/components/schemas/UserProfile"},
+                            "new": {"$ref": "#/components/schemas/user.profile"}
+                        }
+                    }}}
+                }
+            },
+            "components": {
+                "schemas": {
+                    "UserProfile": {"type": "object", "properties": {"name": {"type": "string"}}},
+                    "user.profile": {"type": "object", "properties": {"email": {"type": "string"}}}
+                }
+            }
+        }));
+
+        let output = generate(&spec);
+        assert!(output.contains("export interface UserProfile {\n  name?: string;"));
+        assert!(output.contains("export interface UserProfile2 {\n  email?: string;"));
+        assert!(output.contains("  old?: UserProfile;\n"));
+        assert!(output.contains("  new?: UserProfile2;\n"));
+    }
+}
@@ -0,0 +1,469 @@
+//! Conversion of JSON Schema values into TypeScript type expressions
+
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+/// Indentation unit used in generated code
+pub const INDENT: &str = "  ";
+
+/// Convert an arbitrary string (component key, message name) into a
+/// `PascalCase` TypeScript identifier
+///
+/// `user.created` becomes `UserCreated`, `order-placed` becomes `OrderPlaced`,
+/// and names that are already `PascalCase` are kept as-is.
+pub fn type_name(raw: &str) -> String {
+    let mut name = String::with_capacity(raw.len());
+    for part in raw.split(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+    if name.is_empty() {
+        return "Unnamed".to_string();
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// Type name referenced by a `$ref` (the last JSON Pointer segment)
+fn ref_type_name(reference: &str) -> String {
+    let segment = reference.rsplit('/').next().unwrap_or(reference);
+    type_name(&segment.replace("~1", "/").replace("~0", "~"))
+}
+
+/// The names schemas were declared under, keyed by the reference pointing to them
+///
+/// Two component keys can map to the same type name (`user.profile` and
+/// `UserProfile`), in which case the later one is declared with a numeric
+/// suffix and its `$ref`s must render that name.
+#[derive(Default)]
+pub struct TypeNames(HashMap<String, String>);
+
+impl TypeNames {
+    /// Record the type name of the schema at `reference`, suffixing `name` if
+    /// another schema was already recorded under it; returns the recorded name
+    pub fn insert(&mut self, reference: String, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut suffix = 2;
+        while self.0.values().any(|used| *used == candidate) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.0.insert(reference, candidate.clone());
+        candidate
+    }
+
+    /// Type name referenced by a `$ref`: the recorded name, falling back to the
+    /// last JSON Pointer segment for schemas that were not recorded
+    pub fn get(&self, reference: &str) -> String {
+        self.0
+            .get(reference)
+            .cloned()
+            .unwrap_or_else(|| ref_type_name(reference))
+    }
+}
+
+/// Render a JSON Schema as a TypeScript type expression
+///
+/// `indent` is the indentation level of the line the expression starts on; the
+/// members of object literals are indented one level deeper.
+pub fn render_type(names: &TypeNames, schema: &Value, indent: usize) -> String {
+    let object = match schema {
+        Value::Bool(false) => return "never".to_string(),
+        Value::Object(object) => object,
+        _ => return "unknown".to_string(),
+    };
+
+    let rendered = render_object_schema(names, object, indent);
+    if object.get("nullable").and_then(Value::as_bool) == Some(true) && rendered != "null" {
+        return format!("{} | null", rendered);
+    }
+    rendered
+}
+
+fn render_object_schema(names: &TypeNames, object: &Map<String, Value>, indent: usize) -> String {
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        return names.get(reference);
+    }
+    if let Some(value) = object.get("const") {
+        return literal(value);
+    }
+    if let Some(Value::Array(values)) = object.get("enum") {
+        return join_union(values.iter().map(literal).collect());
+    }
+    if let Some(Value::Array(variants)) = object.get("oneOf").or_else(|| object.get("anyOf")) {
+        let discriminator = object.get("discriminator").and_then(Value::as_str);
+        let rendered = variants
+            .iter()
+            .map(|variant| render_variant(names, variant, discriminator, indent))
+            .collect();
+        return join_union(rendered);
+    }
+    if let Some(Value::Array(parts)) = object.get("allOf") {
+        let mut rendered: Vec<String> = parts
+            .iter()
+            .map(|part| render_type(names, part, indent))
+            .collect();
+        if object.contains_key("properties") {
+            rendered.push(render_object(names, object, indent));
+        }
+        rendered.dedup();
+        return rendered.join(" & ");
+    }
+
+    match object.get("type") {
+        Some(Value::String(ty)) => render_primitive(names, ty, object, indent),
+        Some(Value::Array(types)) => join_union(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|ty| render_primitive(names, ty, object, indent))
+                .collect(),
+        ),
+        _ if object.contains_key("properties") => render_object(names, object, indent),
+        _ if object.contains_key("items") => render_array(names, object, indent),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Render one `oneOf`/`anyOf` variant, tagging `$ref` variants with the
+/// discriminator property when one is declared
+pub fn render_variant(
+    names: &TypeNames,
+    variant: &Value,
+    discriminator: Option<&str>,
+    indent: usize,
+) -> String {
+    let rendered = render_type(names, variant, indent);
+    match (discriminator, variant.get("$ref").and_then(Value::as_str)) {
+        (Some(property), Some(reference)) => {
+            let name = names.get(reference);
+            format!(
+                "({} & {{ {}: {} }})",
+                rendered,
+                property_key(property),
+                literal(&Value::String(name))
+            )
+        }
+        _ => rendered,
+    }
+}
+
+fn render_primitive(
+    names: &TypeNames,
+    ty: &str,
+    object: &Map<String, Value>,
+    indent: usize,
+) -> String {
+    match ty {
+        "string" => "string".to_string(),
+        "integer" | "number" => "number".to_string(),
+        "boolean" => "boolean".to_string(),
+        "null" => "null".to_string(),
+        "array" => render_array(names, object, indent),
+        "object" => render_object(names, object, indent),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn render_array(names: &TypeNames, object: &Map<String, Value>, indent: usize) -> String {
+    if let Some(Value::Array(items)) = object.get("prefixItems") {
+        let items: Vec<String> = items
+            .iter()
+            .map(|item| render_type(names, item, indent))
+            .collect();
+        return format!("[{}]", items.join(", "));
+    }
+    match object.get("items") {
+        Some(Value::Array(items)) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| render_type(names, item, indent))
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+        Some(items) => {
+            let item = render_type(names, items, indent);
+            if item.contains(" | ") || item.contains(" & ") {
+                format!("({})[]", item)
+            } else {
+                format!("{}[]", item)
+            }
+        }
+        None => "unknown[]".to_string(),
+    }
+}
+
+/// Render an object schema as an object literal type (`{ a: string; b?: number }`)
+pub fn render_object(names: &TypeNames, object: &Map<String, Value>, indent: usize) -> String {
+    let Some(Value::Object(properties)) = object.get("properties") else {
+        let value_type = match object.get("additionalProperties") {
+            Some(Value::Bool(false)) => return "Record<string, never>".to_string(),
+            Some(schema @ Value::Object(_)) => render_type(names, schema, indent),
+            _ => "unknown".to_string(),
+        };
+        return format!("Record<string, {}>", value_type);
+    };
+    if properties.is_empty() {
+        return "Record<string, never>".to_string();
+    }
+
+    let mut out = String::from("{\n");
+    out.push_str(&render_members(names, object, indent + 1));
+    out.push_str(&INDENT.repeat(indent));
+    out.push('}');
+    out
+}
+
+/// Render the properties of an object schema as interface members, one per line
+pub fn render_members(names: &TypeNames, object: &Map<String, Value>, indent: usize) -> String {
+    let required: Vec<&str> = object
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let pad = INDENT.repeat(indent);
+
+    let mut out = String::new();
+    if let Some(Value::Object(properties)) = object.get("properties") {
+        for (name, schema) in properties {
+            out.push_str(&doc_comment(schema, indent));
+            let optional = if required.contains(&name.as_str()) {
+                ""
+            } else {
+                "?"
+            };
+            let _ = writeln!(
+                out,
+                "{}{}{}: {};",
+                pad,
+                property_key(name),
+                optional,
+                render_type(names, schema, indent)
+            );
+        }
+    }
+    if let Some(schema @ Value::Object(_)) = object.get("additionalProperties") {
+        let _ = writeln!(
+            out,
+            "{}[key: string]: {};",
+            pad,
+            render_type(names, schema, indent)
+        );
+    }
+    out
+}
+
+/// `JSDoc` comment for a schema's `description` (or `title`), empty if it has none
+pub fn doc_comment(schema: &Value, indent: usize) -> String {
+    let Some(text) = schema
+        .get("description")
+        .or_else(|| schema.get("title"))
+        .and_then(Value::as_str)
+    else {
+        return String::new();
+    };
+    let pad = INDENT.repeat(indent);
+    let text = text.replace("*/", "*\\/");
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() <= 1 {
+        return format!("{}/** {} */\n", pad, text.trim());
+    }
+    let mut out = format!("{}/**\n", pad);
+    for line in lines {
+        let _ = writeln!(out, "{} * {}", pad, line);
+    }
+    let _ = writeln!(out, "{} */", pad);
+    out
+}
+
+/// Whether a schema is best declared as an `interface` rather than a type alias
+pub fn is_interface(schema: &Value) -> bool {
+    let Value::Object(object) = schema else {
+        return false;
+    };
+    let composed = [
+        "$ref", "const", "enum", "oneOf", "anyOf", "allOf", "nullable",
+    ]
+    .iter()
+    .any(|key| object.contains_key(*key));
+    let object_type = match object.get("type") {
+        Some(Value::String(ty)) => ty == "object",
+        None => true,
+        _ => false,
+    };
+    !composed
+        && object_type
+        && object
+            .get("properties")
+            .and_then(Value::as_object)
+            .is_some_and(|properties| !properties.is_empty())
+}
+
+fn property_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if valid {
+        name.to_string()
+    } else {
+        literal(&Value::String(name.to_string()))
+    }
+}
+
+fn literal(value: &Value) -> String {
+    match value {
+        Value::Array(_) | Value::Object(_) => "unknown".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn join_union(mut variants: Vec<String>) -> String {
+    let mut seen = Vec::with_capacity(variants.len());
+    variants.retain(|variant| {
+        if seen.contains(variant) {
+            false
+        } else {
+            seen.push(variant.clone());
+            true
+        }
+    });
+    match variants.len() {
+        0 => "never".to_string(),
+        _ => variants.join(" | "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(type_name("user.created"), "UserCreated");
+        assert_eq!(type_name("order-placed"), "OrderPlaced");
+        assert_eq!(type_name("UserCreated"), "UserCreated");
+        assert_eq!(type_name("2fa"), "_2fa");
+        assert_eq!(type_name("..."), "Unnamed");
+    }
+
+    #[test]
+    fn test_render_primitives() {
+        assert_eq!(
+            render_type(&TypeNames::default(), &json!({"type": "string"}), 0),
+            "string"
+        );
+        assert_eq!(
+            render_type(&TypeNames::default(), &json!({"type": "integer"}), 0),
+            "number"
+        );
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"type": ["string", "null"]}),
+                0
+            ),
+            "string | null"
+        );
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"type": "array", "items": {"type": "boolean"}}),
+                0
+            ),
+            "boolean[]"
+        );
+        assert_eq!(
+            render_type(&TypeNames::default(), &json!(true), 0),
+            "unknown"
+        );
+    }
+
+    #[test]
+    fn test_render_enum_and_refs() {
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"type": "string", "enum": ["a", "b"]}),
+                0
+            ),
+            "\"a\" | \"b\""
+        );
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"$ref": "#/components/schemas/user.profile"}),
+                0
+            ),
+            "UserProfile"
+        );
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"type": "array", "items": {"anyOf": [{"$ref": "#/$defs/A"}, {"type": "null"}]}}),
+                0
+            ),
+            "(A | null)[]"
+        );
+    }
+
+    #[test]
+    fn test_type_names() {
+        let mut names = TypeNames::default();
+        assert_eq!(
+            names.insert(
+                "#/components/schemas/UserProfile".to_string(),
+                "UserProfile"
+            ),
+            "UserProfile"
+        );
+        assert_eq!(
+            names.insert(
+                "#/components/schemas/user.profile".to_string(),
+                "UserProfile"
+            ),
+            "UserProfile2"
+        );
+        assert_eq!(
+            render_type(
+                &names,
+                &json!({"$ref": "#/components/schemas/user.profile"}),
+                0
+            ),
+            "UserProfile2"
+        );
+        assert_eq!(
+            render_type(&names, &json!({"$ref": "#/components/schemas/Other"}), 0),
+            "Other"
+        );
+    }
+
+    #[test]
+    fn test_render_object_literal() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"id": {"type": "string"}, "my-field": {"type": "number"}},
+            "required": ["id"]
+        });
+        assert_eq!(
+            render_type(&TypeNames::default(), &schema, 0),
+            "{\n  id: string;\n  \"my-field\"?: number;\n}"
+        );
+        assert_eq!(
+            render_type(
+                &TypeNames::default(),
+                &json!({"type": "object", "additionalProperties": {"type": "integer"}}),
+                0
+            ),
+            "Record<string, number>"
+        );
+    }
+}
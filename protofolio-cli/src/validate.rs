@@ -0,0 +1,120 @@
+//! Rendering of validation reports for the `validate` command
+
+use protofolio::{Severity, ValidationIssue, ValidationReport};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+/// Human-readable report: one block per issue, followed by a summary line
+pub fn render_text(spec_name: &str, report: &ValidationReport) -> String {
+    let mut out = String::new();
+    for issue in report.issues() {
+        let _ = writeln!(out, "{}\n", issue);
+    }
+    let errors = report.errors().count();
+    let warnings = report.warnings().count();
+    if report.is_valid() {
+        let _ = write!(out, "✓ {} is valid", spec_name);
+        if warnings > 0 {
+            let _ = write!(out, " ({} warning(s))", warnings);
+        }
+    } else {
+        let _ = write!(
+            out,
+            "✗ {} is invalid: {} error(s), {} warning(s)",
+            spec_name, errors, warnings
+        );
+    }
+    out
+}
+
+/// Machine-readable report
+///
+/// ```json
+/// {
+///   "valid": false,
+///   "errors": 1,
+///   "warnings": 0,
+///   "issues": [
+///     {"severity": "error", "pointer": "/info/title", "message": "...", "hint": "..."}
+///   ]
+/// }
+/// ```
+pub fn render_json(report: &ValidationReport) -> Value {
+    json!({
+        "valid": report.is_valid(),
+        "errors": report.errors().count(),
+        "warnings": report.warnings().count(),
+        "issues": report.issues().iter().map(issue_json).collect::<Vec<_>>(),
+    })
+}
+
+fn issue_json(issue: &ValidationIssue) -> Value {
+    let severity = match issue.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    json!({
+        "severity": severity,
+        "pointer": issue.pointer,
+        "message": issue.error.message(),
+        "hint": issue.error.hint(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use protofolio::validate_spec_report;
+
+    fn report(value: Value) -> ValidationReport {
+        validate_spec_report(&test_utils::spec(value))
+    }
+
+    #[test]
+    fn test_render_valid_report() {
+        let report = report(json!({
+            "channels": {
+                "events": {"address": "events", "messages": {"Event": {"payload": {"type": "object"}}}}
+            }
+        }));
+
+        assert_eq!(
+            render_text("asyncapi.json", &report),
+            "✓ asyncapi.json is valid"
+        );
+        assert_eq!(
+            render_json(&report),
+            json!({"valid": true, "errors": 0, "warnings": 0, "issues": []})
+        );
+    }
+
+    #[test]
+    fn test_render_invalid_report() {
+        let report = report(json!({
+            "info": {"title": "", "version": "1.0.0"},
+            "channels": {"events": {"address": "events", "messages": {}}}
+        }));
+
+        let text = render_text("asyncapi.json", &report);
+        assert!(text.contains("error at /info/title: Missing required field: info.title"));
+        assert!(
+            text.contains("error at /channels/events/messages: Channel 'events' has no messages")
+        );
+        assert!(text.ends_with("✗ asyncapi.json is invalid: 2 error(s), 0 warning(s)"));
+
+        let json = render_json(&report);
+        assert_eq!(json["valid"], false);
+        assert_eq!(json["errors"], 2);
+        assert_eq!(json["issues"][0]["severity"], "error");
+        assert_eq!(json["issues"][0]["pointer"], "/info/title");
+        assert_eq!(
+            json["issues"][0]["message"],
+            "Missing required field: info.title"
+        );
+        assert!(json["issues"][0]["hint"]
+            .as_str()
+            .unwrap()
+            .starts_with("Add the missing field"));
+    }
+}
@@ -1,19 +1,62 @@
 //! Parser structure and implementation for `AsyncApi` attributes
 
+use crate::bindings::{is_binding_protocol, parse_binding, push_binding, BindingAttrs};
+use crate::extensions::{parse_extensions, ExtensionAttr};
 use crate::parse_utils::parse_optional_comma;
 use syn::{parse::Parse, Error, LitStr, Token};
 
 use super::{info::InfoAttrs, security::SecuritySchemeAttrs, server::ServerAttrs, tag::TagAttrs};
 
+/// Parser structure for a channel declaration: `"name"` or `("name", kafka(...), ...)`
+pub struct ChannelAttrs {
+    pub name: LitStr,
+    pub bindings: Vec<BindingAttrs>,
+}
+
+impl Parse for ChannelAttrs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self {
+                name: input.parse()?,
+                bindings: Vec::new(),
+            });
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let name = content.parse()?;
+        let mut bindings = Vec::new();
+        while !content.is_empty() {
+            content.parse::<Token![,]>()?;
+            if content.is_empty() {
+                break;
+            }
+            let ident: syn::Ident = content.parse()?;
+            let ident_str = ident.to_string();
+            if !is_binding_protocol(&ident_str) {
+                return Err(Error::new_spanned(
+                    &ident,
+                    format!(
+                        "Unknown channel binding '{ident_str}'. Expected one of: amqp, kafka, mqtt, nats, ws\n\nExample: channels(\"events\", (\"orders\", kafka(topic = \"orders\", partitions = 12)))"
+                    ),
+                ));
+            }
+            push_binding(&mut bindings, parse_binding(ident, &content)?)?;
+        }
+        Ok(Self { name, bindings })
+    }
+}
+
 /// Parser structure for asyncapi attributes
 pub struct AsyncApiAttrs {
     pub info: Option<InfoAttrs>,
     pub servers: Vec<ServerAttrs>,
     pub security_schemes: Vec<SecuritySchemeAttrs>,
-    pub channels: Vec<LitStr>,
+    pub channels: Vec<ChannelAttrs>,
     pub messages: Vec<syn::Path>,
     pub operations: Vec<syn::Path>,
     pub tags: Vec<TagAttrs>,
+    pub extensions: Vec<ExtensionAttr>,
 }
 
 impl Parse for AsyncApiAttrs {
@@ -25,6 +68,7 @@ impl Parse for AsyncApiAttrs {
         let mut messages = Vec::new();
         let mut operations = Vec::new();
         let mut tags = Vec::new();
+        let mut extensions = Vec::new();
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
@@ -94,11 +138,13 @@ impl Parse for AsyncApiAttrs {
                         content.parse::<Token![,]>()?;
                     }
                 }
+            } else if ident_str == "extensions" {
+                extensions = parse_extensions(input)?;
             } else {
                 return Err(Error::new_spanned(
                         &ident,
                         format!(
-                            "Unexpected identifier '{ident_str}'. Expected one of: info, servers, security_schemes, channels, messages, operations, tags\n\nExample: #[asyncapi(info(title = \"...\", version = \"...\"), channels(\"channel1\"), messages(Message1), tags((name = \"orders\", description = \"Order operations\")))]"
+                            "Unexpected identifier '{ident_str}'. Expected one of: info, servers, security_schemes, channels, messages, operations, tags, extensions\n\nExample: #[asyncapi(info(title = \"...\", version = \"...\"), channels(\"channel1\"), messages(Message1), tags((name = \"orders\", description = \"Order operations\")))]"
                         ),
                     ));
             }
@@ -114,6 +160,7 @@ impl Parse for AsyncApiAttrs {
             messages,
             operations,
             tags,
+            extensions,
         })
     }
 }
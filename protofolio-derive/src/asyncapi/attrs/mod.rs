@@ -6,7 +6,7 @@ mod security;
 mod server;
 mod tag;
 
-pub use asyncapi::AsyncApiAttrs;
+pub use asyncapi::{AsyncApiAttrs, ChannelAttrs};
 pub use security::SecuritySchemeAttrs;
 pub use server::ServerAttrs;
 pub use tag::TagAttrs;
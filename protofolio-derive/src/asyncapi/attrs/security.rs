@@ -1,7 +1,7 @@
 //! Parser structure and implementation for security scheme attributes
 
 use crate::parse_utils::parse_optional_comma;
-use syn::{parse::Parse, Error, LitStr, Token};
+use syn::{ext::IdentExt, parse::Parse, Error, Ident, LitStr, Token};
 
 /// Parser structure for security scheme attributes
 pub struct SecuritySchemeAttrs {
@@ -28,7 +28,8 @@ impl Parse for SecuritySchemeAttrs {
         let mut open_id_connect_url = None;
 
         while !input.is_empty() {
-            let ident: syn::Ident = input.parse()?;
+            // `type` and `in` are keywords
+            let ident = Ident::parse_any(input)?;
             input.parse::<Token![=]>()?;
             let lit: LitStr = input.parse()?;
 
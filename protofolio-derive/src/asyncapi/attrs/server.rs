@@ -1,5 +1,6 @@
 //! Parser structures and implementations for server attributes
 
+use super::info::ExternalDocsAttrs;
 use crate::parse_utils::parse_optional_comma;
 use syn::{parse::Parse, Error, LitStr, Token};
 
@@ -13,14 +14,40 @@ pub struct ServerVariableAttrs {
 }
 
 /// Parser structure for server attributes
+///
+/// A server is given either as a `url` (split into host and pathname at compile
+/// time) or as an explicit `host` with an optional `pathname`.
 pub struct ServerAttrs {
     pub name: LitStr,
-    pub url: LitStr,
+    pub host: String,
+    pub pathname: Option<String>,
     pub protocol: LitStr,
+    pub protocol_version: Option<LitStr>,
+    pub title: Option<LitStr>,
+    pub summary: Option<LitStr>,
+    pub description: Option<LitStr>,
+    pub tags: Vec<LitStr>,
+    pub external_docs: Option<ExternalDocsAttrs>,
     pub security: Vec<Vec<LitStr>>, // List of security requirement lists
     pub variables: Vec<ServerVariableAttrs>,
 }
 
+/// Split a server URL into its host and pathname, dropping the scheme
+///
+/// `nats://localhost:4222` yields `("localhost:4222", None)` and
+/// `wss://api.example.com/ws` yields `("api.example.com", Some("/ws"))`.
+fn split_url(url: &str) -> (String, Option<String>) {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    match without_scheme.find('/') {
+        Some(index) if index + 1 < without_scheme.len() => (
+            without_scheme[..index].to_string(),
+            Some(without_scheme[index..].to_string()),
+        ),
+        Some(index) => (without_scheme[..index].to_string(), None),
+        None => (without_scheme.to_string(), None),
+    }
+}
+
 impl Parse for ServerVariableAttrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut name = None;
@@ -84,8 +111,16 @@ impl Parse for ServerVariableAttrs {
 impl Parse for ServerAttrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut name = None;
-        let mut url = None;
+        let mut url: Option<LitStr> = None;
+        let mut host: Option<LitStr> = None;
+        let mut pathname: Option<LitStr> = None;
         let mut protocol = None;
+        let mut protocol_version = None;
+        let mut title = None;
+        let mut summary = None;
+        let mut description = None;
+        let mut tags = Vec::new();
+        let mut external_docs = None;
         let mut security = Vec::new();
         let mut variables = Vec::new();
 
@@ -93,8 +128,16 @@ impl Parse for ServerAttrs {
             let ident: syn::Ident = input.parse()?;
             let ident_str = ident.to_string();
 
+            if ident_str == "external_docs" || ident_str == "externalDocs" {
+                let content;
+                syn::parenthesized!(content in input);
+                external_docs = Some(content.parse()?);
+                parse_optional_comma(input)?;
+                continue;
+            }
+
             input.parse::<Token![=]>()?;
-            if ident_str == "security" || ident_str == "variables" {
+            if ident_str == "security" || ident_str == "variables" || ident_str == "tags" {
                 let content;
                 syn::bracketed!(content in input);
                 if ident_str == "security" {
@@ -107,6 +150,13 @@ impl Parse for ServerAttrs {
                         }
                     }
                     security.push(req_list);
+                } else if ident_str == "tags" {
+                    while !content.is_empty() {
+                        tags.push(content.parse()?);
+                        if content.peek(Token![,]) {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
                 } else {
                     while !content.is_empty() {
                         let var_content;
@@ -123,12 +173,18 @@ impl Parse for ServerAttrs {
                 match ident_str.as_str() {
                     "name" => name = Some(lit),
                     "url" => url = Some(lit),
+                    "host" => host = Some(lit),
+                    "pathname" => pathname = Some(lit),
                     "protocol" => protocol = Some(lit),
+                    "protocol_version" | "protocolVersion" => protocol_version = Some(lit),
+                    "title" => title = Some(lit),
+                    "summary" => summary = Some(lit),
+                    "description" => description = Some(lit),
                     _ => {
                         return Err(Error::new(
                             ident.span(),
                             format!(
-                                "Unknown server attribute '{ident}'. Expected one of: name, url, protocol, security, variables\n\nExample: #[asyncapi(servers((name = \"nats\", url = \"nats://localhost:4222\", protocol = \"nats\", security = [\"userPassword\"], variables = [(name = \"host\", default = \"localhost\")])))]"
+                                "Unknown server attribute '{ident}'. Expected one of: name, url, host, pathname, protocol, protocol_version, title, summary, description, tags, external_docs, security, variables\n\nExample: #[asyncapi(servers((name = \"nats\", host = \"localhost:4222\", protocol = \"nats\", security = [\"userPassword\"], variables = [(name = \"host\", default = \"localhost\")])))]"
                             ),
                         ));
                     }
@@ -138,10 +194,37 @@ impl Parse for ServerAttrs {
             parse_optional_comma(input)?;
         }
 
+        let (host, pathname) = match (url, host) {
+            (Some(url), None) => {
+                if let Some(pathname) = pathname {
+                    return Err(Error::new(
+                        pathname.span(),
+                        "server 'pathname' cannot be combined with 'url'. Use either url = \"...\" or host = \"...\" with pathname = \"...\"",
+                    ));
+                }
+                split_url(&url.value())
+            }
+            (None, Some(host)) => (host.value(), pathname.map(|p| p.value())),
+            (Some(url), Some(_)) => {
+                return Err(Error::new(
+                    url.span(),
+                    "server accepts either 'url' or 'host', not both. Use either url = \"...\" or host = \"...\" with pathname = \"...\"",
+                ));
+            }
+            (None, None) => return Err(input.error("server requires 'host' (or 'url')")),
+        };
+
         Ok(Self {
             name: name.ok_or_else(|| input.error("server requires 'name'"))?,
-            url: url.ok_or_else(|| input.error("server requires 'url'"))?,
+            host,
+            pathname,
             protocol: protocol.ok_or_else(|| input.error("server requires 'protocol'"))?,
+            protocol_version,
+            title,
+            summary,
+            description,
+            tags,
+            external_docs,
             security,
             variables,
         })
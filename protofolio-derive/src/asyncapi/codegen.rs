@@ -1,6 +1,7 @@
 //! Code generation for servers, channels, and impl block in `AsyncApi` derive macro
 
-use crate::asyncapi::attrs::{SecuritySchemeAttrs, ServerAttrs, TagAttrs};
+use crate::asyncapi::attrs::{ChannelAttrs, SecuritySchemeAttrs, ServerAttrs, TagAttrs};
+use crate::bindings::{generate_bindings_value, BindingTarget};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -11,8 +12,44 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
         .iter()
         .map(|server| {
             let name_lit = &server.name;
-            let url_lit = &server.url;
+            let host_str = &server.host;
             let protocol_lit = &server.protocol;
+            let pathname_expr = server.pathname.as_ref().map_or_else(
+                || quote! { None },
+                |pathname| quote! { Some(#pathname.to_string()) },
+            );
+            let protocol_version_expr = optional_string_expr(server.protocol_version.as_ref());
+            let title_expr = optional_string_expr(server.title.as_ref());
+            let summary_expr = optional_string_expr(server.summary.as_ref());
+            let description_expr = optional_string_expr(server.description.as_ref());
+            let tags_expr = if server.tags.is_empty() {
+                quote! { None }
+            } else {
+                let tag_names: Vec<String> = server.tags.iter().map(syn::LitStr::value).collect();
+                quote! {
+                    Some(vec![
+                        #(protofolio::Tag {
+                            name: #tag_names.to_string(),
+                            description: None,
+                            extensions: protofolio::Extensions::new(),
+                        }),*
+                    ])
+                }
+            };
+            let external_docs_expr = server.external_docs.as_ref().map_or_else(
+                || quote! { None },
+                |ext_docs| {
+                    let url_lit = &ext_docs.url;
+                    let desc_expr = optional_string_expr(ext_docs.description.as_ref());
+                    quote! {
+                        Some(protofolio::ExternalDocumentation {
+                            url: #url_lit.to_string(),
+                            description: #desc_expr,
+                            extensions: protofolio::Extensions::new(),
+                        })
+                    }
+                },
+            );
 
             // Generate security requirements if present
             let security_expr = if server.security.is_empty() {
@@ -27,13 +64,13 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
                             .map(|scheme_name| {
                                 let name_str = scheme_name.value();
                                 quote! {
-                                    (#name_str.to_string(), vec![])
+                                    #name_str.to_string(), Vec::new()
                                 }
                             })
                             .collect();
                         quote! {
                             {
-                                let mut req = std::collections::HashMap::new();
+                                let mut req = protofolio::IndexMap::new();
                                 #(
                                     req.insert(#scheme_names);
                                 )*
@@ -111,6 +148,7 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
                                     default: #default_expr,
                                     description: #description_expr,
                                     examples: #examples_expr,
+                                    extensions: protofolio::Extensions::new(),
                                 }
                             );
                         }
@@ -119,7 +157,7 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
 
                 quote! {
                     {
-                        let mut vars = std::collections::HashMap::new();
+                        let mut vars = protofolio::IndexMap::new();
                         #(#var_code)*
                         Some(vars)
                     }
@@ -130,11 +168,19 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
                 builder = builder.server(
                     #name_lit.to_string(),
                     Server {
-                        url: #url_lit.to_string(),
+                        host: #host_str.to_string(),
                         protocol: #protocol_lit.to_string(),
-                        description: None,
+                        protocol_version: #protocol_version_expr,
+                        pathname: #pathname_expr,
+                        title: #title_expr,
+                        summary: #summary_expr,
+                        description: #description_expr,
+                        tags: #tags_expr,
+                        external_docs: #external_docs_expr,
+                        bindings: None,
                         security: #security_expr,
                         variables: #variables_expr,
+                        extensions: protofolio::Extensions::new(),
                     }
                 );
             }
@@ -142,11 +188,22 @@ pub fn generate_servers_code(servers: &[ServerAttrs]) -> Vec<TokenStream> {
         .collect()
 }
 
+/// Generate an `Option<String>` expression from an optional string literal
+fn optional_string_expr(lit: Option<&syn::LitStr>) -> TokenStream {
+    lit.map_or_else(
+        || quote! { None },
+        |lit| {
+            let value = lit.value();
+            quote! { Some(#value.to_string()) }
+        },
+    )
+}
+
 /// Generate code for security scheme initialization
 pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenStream {
     if schemes.is_empty() {
         quote! {
-            let security_schemes_map: Option<std::collections::HashMap<String, protofolio::SecurityScheme>> = None;
+            let security_schemes_map: Option<protofolio::IndexMap<String, protofolio::SecurityScheme>> = None;
         }
     } else {
         let scheme_code: Vec<TokenStream> = schemes
@@ -167,6 +224,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         quote! {
                             protofolio::SecurityScheme::UserPassword {
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -182,6 +240,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                             protofolio::SecurityScheme::ApiKey {
                                 in_: #in_expr,
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -202,6 +261,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                                 scheme: #scheme_str.to_string(),
                                 bearer_format: #bearer_format_expr,
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -219,6 +279,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                                 name: #name_param_str.to_string(),
                                 in_: #in_str.to_string(),
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -231,6 +292,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                             protofolio::SecurityScheme::OpenIdConnect {
                                 open_id_connect_url: #oidc_url_str.to_string(),
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -238,6 +300,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         quote! {
                             protofolio::SecurityScheme::X509 {
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -245,6 +308,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         quote! {
                             protofolio::SecurityScheme::SymmetricEncryption {
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -252,6 +316,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         quote! {
                             protofolio::SecurityScheme::AsymmetricEncryption {
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -259,6 +324,7 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         quote! {
                             protofolio::SecurityScheme::MutualTls {
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -272,8 +338,10 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                                     client_credentials: None,
                                     implicit: None,
                                     password: None,
+                                    extensions: protofolio::Extensions::new(),
                                 },
                                 description: #desc_expr,
+                                extensions: protofolio::Extensions::new(),
                             }
                         }
                     }
@@ -281,14 +349,14 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
                         // This should be caught during parsing, but handle gracefully
                         quote! {
                             compile_error!(concat!("Unknown security scheme type: ", #scheme_type, ". Supported types: userPassword, apiKey, http, httpApiKey, oauth2, openIdConnect, X509, symmetricEncryption, asymmetricEncryption, mutualTLS"));
-                            protofolio::SecurityScheme::UserPassword { description: None }
+                            protofolio::SecurityScheme::UserPassword { description: None, extensions: protofolio::Extensions::new() }
                         }
                     }
                 };
 
                 quote! {
                     security_schemes_map.insert(
-                        #name_lit.value().to_string(),
+                        #name_lit.to_string(),
                         #scheme_expr
                     );
                 }
@@ -296,29 +364,35 @@ pub fn generate_security_schemes_code(schemes: &[SecuritySchemeAttrs]) -> TokenS
             .collect();
 
         quote! {
-            let mut security_schemes_map: std::collections::HashMap<String, protofolio::SecurityScheme> = std::collections::HashMap::new();
+            let mut security_schemes_map: protofolio::IndexMap<String, protofolio::SecurityScheme> = protofolio::IndexMap::new();
             #(#scheme_code)*
-            let security_schemes_map: Option<std::collections::HashMap<String, protofolio::SecurityScheme>> = Some(security_schemes_map);
+            let security_schemes_map: Option<protofolio::IndexMap<String, protofolio::SecurityScheme>> = Some(security_schemes_map);
         }
     }
 }
 
 /// Generate code for channel initialization
-pub fn generate_channels_code(channels: &[syn::LitStr]) -> Vec<TokenStream> {
+pub fn generate_channels_code(channels: &[ChannelAttrs]) -> Vec<TokenStream> {
     channels
         .iter()
         .map(|channel| {
-            let channel_name_lit = channel;
+            let channel_name_lit = &channel.name;
+            let bindings_expr = generate_bindings_value(&channel.bindings, BindingTarget::Channel)
+                .map_or_else(
+                    || quote! { None },
+                    |value| quote! { Some(protofolio::ChannelBindingsOrRef::bindings(#value)) },
+                );
             quote! {
                 channels_map.insert(
                     #channel_name_lit.to_string(),
                     Channel {
                         address: #channel_name_lit.to_string(),
                         description: None,
-                        messages: HashMap::new(),
+                        messages: IndexMap::new(),
                         servers: None,
                         parameters: None,
-                        bindings: None,
+                        bindings: #bindings_expr,
+                        extensions: protofolio::Extensions::new(),
                     }
                 );
             }
@@ -330,13 +404,13 @@ pub fn generate_channels_code(channels: &[syn::LitStr]) -> Vec<TokenStream> {
 pub fn generate_operations_map_code(operations: &[TokenStream]) -> TokenStream {
     if operations.is_empty() {
         quote! {
-            let operations_map: Option<HashMap<String, Operation>> = None;
+            let operations_map: Option<IndexMap<String, Operation>> = None;
         }
     } else {
         quote! {
-            let mut operations_map: HashMap<String, Operation> = HashMap::new();
+            let mut operations_map: IndexMap<String, Operation> = IndexMap::new();
             #(#operations)*
-            let operations_map: Option<HashMap<String, Operation>> = Some(operations_map);
+            let operations_map: Option<IndexMap<String, Operation>> = Some(operations_map);
         }
     }
 }
@@ -345,13 +419,13 @@ pub fn generate_operations_map_code(operations: &[TokenStream]) -> TokenStream {
 pub fn generate_operations_map_try_code(operations: &[TokenStream]) -> TokenStream {
     if operations.is_empty() {
         quote! {
-            let operations_map: Option<HashMap<String, Operation>> = None;
+            let operations_map: Option<IndexMap<String, Operation>> = None;
         }
     } else {
         quote! {
-            let mut operations_map: HashMap<String, Operation> = HashMap::new();
+            let mut operations_map: IndexMap<String, Operation> = IndexMap::new();
             #(#operations)*
-            let operations_map: Option<HashMap<String, Operation>> = Some(operations_map);
+            let operations_map: Option<IndexMap<String, Operation>> = Some(operations_map);
         }
     }
 }
@@ -378,6 +452,7 @@ pub fn generate_tags_code(tags: &[TagAttrs]) -> TokenStream {
                     protofolio::Tag {
                         name: #name_lit.to_string(),
                         description: #desc_expr,
+                        extensions: protofolio::Extensions::new(),
                     }
                 }
             })
@@ -396,25 +471,26 @@ pub fn generate_impl_block(
     ident: &Ident,
     info_title: &str,
     info_version: &str,
-    info_desc_expr: TokenStream,
-    info_external_docs_expr: TokenStream,
-    info_contact_expr: TokenStream,
-    info_license_expr: TokenStream,
-    info_terms_of_service_expr: TokenStream,
+    info_desc_expr: &TokenStream,
+    info_external_docs_expr: &TokenStream,
+    info_contact_expr: &TokenStream,
+    info_license_expr: &TokenStream,
+    info_terms_of_service_expr: &TokenStream,
     servers: &[TokenStream],
-    security_schemes_code: TokenStream,
+    security_schemes_code: &TokenStream,
     channels: &[TokenStream],
     messages: &[TokenStream],
     messages_try: &[TokenStream],
-    operations_code: TokenStream,
-    operations_code_try: TokenStream,
-    tags_code: TokenStream,
+    operations_code: &TokenStream,
+    operations_code_try: &TokenStream,
+    tags_code: &TokenStream,
+    extensions_code: &TokenStream,
 ) -> TokenStream {
     quote! {
         impl protofolio::AsyncApi for #ident {
             fn asyncapi() -> protofolio::AsyncApiSpec {
                 use protofolio::{AsyncApiBuilder, Info, Server, Channel, Message, MessagePayload, Operation, schema_for_type};
-                use std::collections::HashMap;
+                use protofolio::IndexMap;
                 use serde_json::json;
                 use schemars::JsonSchema;
 
@@ -427,6 +503,7 @@ pub fn generate_impl_block(
                         contact: #info_contact_expr,
                         license: #info_license_expr,
                         terms_of_service: #info_terms_of_service_expr,
+                        extensions: protofolio::Extensions::new(),
                     });
 
                 // Add servers
@@ -436,7 +513,7 @@ pub fn generate_impl_block(
                 #security_schemes_code
 
                 // Build channels with messages
-                let mut channels_map: HashMap<String, Channel> = HashMap::new();
+                let mut channels_map: IndexMap<String, Channel> = IndexMap::new();
 
                 // Initialize channels
                 #(#channels)*
@@ -470,13 +547,14 @@ pub fn generate_impl_block(
                 // Add root-level tags if any
                 #tags_code
                 spec.tags = tags_vec;
+                spec.extensions = #extensions_code;
 
                 spec
             }
 
             fn try_asyncapi() -> Result<protofolio::AsyncApiSpec, protofolio::ValidationError> {
                 use protofolio::{AsyncApiBuilder, Info, Server, Channel, Message, MessagePayload, Operation, schema_for_type, ValidationError};
-                use std::collections::HashMap;
+                use protofolio::IndexMap;
                 use serde_json::json;
                 use schemars::JsonSchema;
 
@@ -489,6 +567,7 @@ pub fn generate_impl_block(
                         contact: #info_contact_expr,
                         license: #info_license_expr,
                         terms_of_service: #info_terms_of_service_expr,
+                        extensions: protofolio::Extensions::new(),
                     });
 
                 // Add servers
@@ -498,7 +577,7 @@ pub fn generate_impl_block(
                 #security_schemes_code
 
                 // Build channels with messages
-                let mut channels_map: HashMap<String, Channel> = HashMap::new();
+                let mut channels_map: IndexMap<String, Channel> = IndexMap::new();
 
                 // Initialize channels
                 #(#channels)*
@@ -532,6 +611,7 @@ pub fn generate_impl_block(
                 // Add root-level tags if any
                 #tags_code
                 spec.tags = tags_vec;
+                spec.extensions = #extensions_code;
 
                 // Validate the spec
                 protofolio::validate_spec(&spec)?;
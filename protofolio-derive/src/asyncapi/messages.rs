@@ -59,16 +59,43 @@ pub fn generate_messages_code(messages: &[syn::Path], ident: &Ident) -> Vec<Toke
                             schema: schema,
                         },
                         external_docs: #message_type_ident::external_docs(),
-                        examples: #message_type_ident::examples(),
+                        // Each example payload becomes a Message Example Object
+                        examples: #message_type_ident::examples().map(|payloads| {
+                            payloads
+                                .into_iter()
+                                .map(|payload| serde_json::json!({"payload": payload}))
+                                .collect()
+                        }),
                         headers: #message_type_ident::headers(),
                         correlation_id: #message_type_ident::correlation_id(),
                         traits: None,
-                        bindings: None,
+                        bindings: #message_type_ident::bindings(),
+                        extensions: #message_type_ident::extensions(),
                     };
 
-                    channels_map.get_mut(channel_name)
-                        .expect(&format!("Channel '{}' should exist (validated at compile time)", channel_name))
-                        .messages.insert(message_name_str.to_string(), protofolio::MessageOrRef::message(message));
+                    let channel = channels_map.get_mut(channel_name)
+                        .expect(&format!("Channel '{}' should exist (validated at compile time)", channel_name));
+                    if let Some(parameters) = #message_type_ident::parameters() {
+                        // Messages sharing a channel share its parameters, so their declarations must agree
+                        let channel_parameters = channel.parameters.get_or_insert_with(IndexMap::new);
+                        for (name, parameter) in parameters {
+                            match channel_parameters.get(&name) {
+                                Some(declared) if *declared != parameter => panic!(
+                                    "Message '{}' declares parameter '{}' of channel '{}' differently than another message on that channel\n\nHint: Give every message on channel '{}' the same parameters((name = \"{}\", ...)) declaration",
+                                    message_name_str,
+                                    name,
+                                    channel_name,
+                                    channel_name,
+                                    name
+                                ),
+                                Some(_) => {}
+                                None => {
+                                    channel_parameters.insert(name, parameter);
+                                }
+                            }
+                        }
+                    }
+                    channel.messages.insert(message_name_str.to_string(), protofolio::MessageOrRef::message(message));
                 }
             }
         })
@@ -124,18 +151,41 @@ pub fn generate_messages_try_code(messages: &[syn::Path], ident: &Ident) -> Vec<
                             schema: schema,
                         },
                         external_docs: #message_type_ident::external_docs(),
-                        examples: #message_type_ident::examples(),
+                        // Each example payload becomes a Message Example Object
+                        examples: #message_type_ident::examples().map(|payloads| {
+                            payloads
+                                .into_iter()
+                                .map(|payload| serde_json::json!({"payload": payload}))
+                                .collect()
+                        }),
                         headers: #message_type_ident::headers(),
                         correlation_id: #message_type_ident::correlation_id(),
                         traits: None,
-                        bindings: None,
+                        bindings: #message_type_ident::bindings(),
+                        extensions: #message_type_ident::extensions(),
                     };
 
-                    channels_map.get_mut(channel_name)
+                    let channel = channels_map.get_mut(channel_name)
                         .ok_or_else(|| protofolio::ValidationError::InvalidChannelReference(
                             format!("Channel '{}' should exist (validated above)", channel_name)
-                        ))?
-                        .messages.insert(message_name_str.to_string(), protofolio::MessageOrRef::message(message));
+                        ))?;
+                    if let Some(parameters) = #message_type_ident::parameters() {
+                        let channel_parameters = channel.parameters.get_or_insert_with(IndexMap::new);
+                        for (name, parameter) in parameters {
+                            match channel_parameters.get(&name) {
+                                Some(declared) if *declared != parameter => {
+                                    return Err(protofolio::ValidationError::InvalidChannelParameter(
+                                        format!("Message '{}' declares parameter '{}' of channel '{}' differently than another message on that channel", message_name_str, name, channel_name)
+                                    ));
+                                }
+                                Some(_) => {}
+                                None => {
+                                    channel_parameters.insert(name, parameter);
+                                }
+                            }
+                        }
+                    }
+                    channel.messages.insert(message_name_str.to_string(), protofolio::MessageOrRef::message(message));
                 }
             }
         })
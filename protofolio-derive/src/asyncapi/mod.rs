@@ -6,7 +6,7 @@ mod messages;
 mod operations;
 
 use crate::asyncapi::{
-    attrs::AsyncApiAttrs,
+    attrs::{AsyncApiAttrs, SecuritySchemeAttrs, ServerAttrs},
     codegen::{
         generate_channels_code, generate_impl_block, generate_operations_map_code,
         generate_operations_map_try_code, generate_security_schemes_code, generate_servers_code,
@@ -15,10 +15,12 @@ use crate::asyncapi::{
     messages::{generate_messages_code, generate_messages_try_code},
     operations::{generate_operations_code, generate_operations_try_code},
 };
+use crate::extensions::generate_extensions_value;
+use crate::parse_utils::template_placeholders;
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Error};
+use syn::{DeriveInput, Error, LitStr};
 
 /// Derive `AsyncApi` implementation
 #[allow(clippy::too_many_lines)] // Macro code is inherently long
@@ -39,6 +41,7 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
     let mut messages = Vec::new();
     let mut operations = Vec::new();
     let mut tags = Vec::new();
+    let mut extensions = Vec::new();
 
     for attr in &input.attrs {
         if attr.path().is_ident("asyncapi") {
@@ -93,6 +96,9 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
 
             // Process tags
             tags.extend(parser.tags);
+
+            // Process specification extensions
+            extensions.extend(parser.extensions);
         }
     }
 
@@ -110,6 +116,9 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
         );
     };
 
+    validate_security_requirements(&servers, &security_schemes);
+    validate_server_variables(&servers);
+
     let info_desc_expr = info_description.as_ref().map_or_else(
         || quote! { None },
         |desc| {
@@ -133,6 +142,7 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
                 Some(protofolio::ExternalDocumentation {
                     url: #url_lit.to_string(),
                     description: #desc_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
@@ -167,6 +177,7 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
                     name: #name_expr,
                     url: #url_expr,
                     email: #email_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
@@ -187,6 +198,7 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
                 Some(protofolio::License {
                     name: #name_lit.to_string(),
                     url: #url_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
@@ -224,23 +236,111 @@ pub fn derive_asyncapi(input: DeriveInput) -> Result<TokenStream, Error> {
     // Generate code for tags
     let tags_code = generate_tags_code(&tags);
 
+    // Generate code for specification extensions
+    let extensions_code = generate_extensions_value(&extensions);
+
     // Generate the impl block
     Ok(generate_impl_block(
         ident,
         &info_title,
         &info_version,
-        info_desc_expr,
-        info_external_docs_expr,
-        info_contact_expr,
-        info_license_expr,
-        info_terms_of_service_expr,
+        &info_desc_expr,
+        &info_external_docs_expr,
+        &info_contact_expr,
+        &info_license_expr,
+        &info_terms_of_service_expr,
         &servers_code,
-        security_schemes_code,
+        &security_schemes_code,
         &channels_code,
         &messages_code,
         &messages_try_code,
-        operations_code,
-        operations_code_try,
-        tags_code,
+        &operations_code,
+        &operations_code_try,
+        &tags_code,
+        &extensions_code,
     ))
 }
+
+/// Check that every security scheme a server requires is declared in `security_schemes(...)`
+fn validate_security_requirements(servers: &[ServerAttrs], schemes: &[SecuritySchemeAttrs]) {
+    let declared: Vec<String> = schemes.iter().map(|scheme| scheme.name.value()).collect();
+    for server in servers {
+        for name in server.security.iter().flatten() {
+            if !declared.contains(&name.value()) {
+                abort!(
+                    name,
+                    "Server '{}' requires security scheme '{}', which is not declared in security_schemes(...).\n\nHint: Declared schemes: {:?}. Check the name for typos or declare the scheme, e.g. security_schemes((name = \"{}\", type = \"userPassword\"))",
+                    server.name.value(),
+                    name.value(),
+                    declared,
+                    name.value()
+                );
+            }
+        }
+    }
+}
+
+/// Check that every `{placeholder}` in a server's host and pathname has a variable
+/// and vice versa, and that variable defaults are among their enum values
+fn validate_server_variables(servers: &[ServerAttrs]) {
+    for server in servers {
+        let template = format!(
+            "{}{}",
+            server.host,
+            server.pathname.as_deref().unwrap_or("")
+        );
+        let placeholders = match template_placeholders(&template) {
+            Ok(placeholders) => placeholders,
+            Err(problem) => abort!(
+                server.name,
+                "Server '{}' URL '{}' is invalid: {}.\n\nHint: Placeholders look like '{{host}}:{{port}}'",
+                server.name.value(),
+                template,
+                problem
+            ),
+        };
+
+        for placeholder in &placeholders {
+            if !server
+                .variables
+                .iter()
+                .any(|variable| variable.name.value() == *placeholder)
+            {
+                abort!(
+                    server.name,
+                    "Server '{}' URL '{}' has placeholder '{{{}}}' without a variable.\n\nHint: Declare it with variables = [(name = \"{}\", default = \"...\")]",
+                    server.name.value(),
+                    template,
+                    placeholder,
+                    placeholder
+                );
+            }
+        }
+
+        for variable in &server.variables {
+            let name = variable.name.value();
+            if !placeholders.contains(&name.as_str()) {
+                abort!(
+                    variable.name,
+                    "Variable '{}' does not appear in the URL '{}' of server '{}'.\n\nHint: Add '{{{}}}' to the server URL or remove the variable",
+                    name,
+                    template,
+                    server.name.value(),
+                    name
+                );
+            }
+            if let (Some(default), Some(values)) = (&variable.default, &variable.enum_values) {
+                let values: Vec<String> = values.iter().map(LitStr::value).collect();
+                if !values.contains(&default.value()) {
+                    abort!(
+                        default,
+                        "Default '{}' of server variable '{}' is not one of its enum values {:?}",
+                        default.value(),
+                        name,
+                        values
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Protocol binding attributes shared by the derive macros
+//!
+//! `kafka(...)`, `mqtt(...)`, `nats(...)`, `amqp(...)` and `ws(...)` are accepted on
+//! channels, messages and operations. Each `field = value` pair sets a field of
+//! the matching binding struct (e.g. `protofolio::KafkaChannelConfig`), so
+//! unknown fields and mistyped values are reported by the compiler at the
+//! attribute. Values are literals, arrays of literals (`cc = ["audit"]`) or
+//! nested groups for nested objects (`exchange(name = "billing", type = "topic")`).
+//! A type in value position (`query = QueryParams`) is replaced by its JSON
+//! schema, for schema-valued fields such as the `ws` binding's `query` and `headers`.
+
+use crate::parse_utils::parse_optional_comma;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse::ParseStream, Error, Ident, Lit, Token, Type};
+
+/// Protocols that can be used as binding attributes
+pub const BINDING_PROTOCOLS: &[&str] = &["amqp", "kafka", "mqtt", "nats", "ws"];
+
+/// String fields whose values are an enum in the binding structs: (protocol, field path, values)
+const ENUM_FIELDS: &[(&str, &str, &[&str])] = &[
+    ("amqp", "is", &["routingKey", "queue"]),
+    (
+        "amqp",
+        "exchange.type",
+        &["topic", "direct", "fanout", "default", "headers"],
+    ),
+    ("ws", "method", &["GET", "POST"]),
+];
+
+/// Object the bindings are attached to; selects the bindings struct
+#[derive(Clone, Copy)]
+pub enum BindingTarget {
+    Channel,
+    Operation,
+    Message,
+}
+
+impl BindingTarget {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Channel => "Channel",
+            Self::Operation => "Operation",
+            Self::Message => "Message",
+        }
+    }
+}
+
+/// Value of a binding field
+pub enum BindingValue {
+    /// `field = "value"`
+    Lit(Lit),
+    /// `field = ["a", "b"]`
+    Array(Vec<Lit>),
+    /// `field = SomeType`, the JSON schema of a type
+    Schema(syn::Type),
+    /// `field(nested = "value", ...)`
+    Nested(Vec<(Ident, BindingValue)>),
+}
+
+/// Parser structure for a single protocol binding, e.g. `kafka(topic = "orders", partitions = 12)`
+pub struct BindingAttrs {
+    pub protocol: Ident,
+    pub fields: Vec<(Ident, BindingValue)>,
+}
+
+/// Whether `ident` starts a binding attribute
+pub fn is_binding_protocol(ident: &str) -> bool {
+    BINDING_PROTOCOLS.contains(&ident)
+}
+
+/// Parse the parenthesized `field = value` list following a protocol identifier
+pub fn parse_binding(protocol: Ident, input: ParseStream) -> syn::Result<BindingAttrs> {
+    let fields = parse_fields(&protocol, "", input)?;
+    Ok(BindingAttrs { protocol, fields })
+}
+
+/// Parse a parenthesized field list; `path` is the dotted path of the enclosing group
+fn parse_fields(
+    protocol: &Ident,
+    path: &str,
+    input: ParseStream,
+) -> syn::Result<Vec<(Ident, BindingValue)>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut fields: Vec<(Ident, BindingValue)> = Vec::new();
+    while !content.is_empty() {
+        // Field names may be keywords, e.g. `type`
+        let field = Ident::parse_any(&content)?;
+        let field_path = if path.is_empty() {
+            field.to_string()
+        } else {
+            format!("{path}.{field}")
+        };
+        if fields.iter().any(|(existing, _)| *existing == field) {
+            return Err(Error::new(
+                field.span(),
+                format!("Duplicate {protocol} binding field '{field_path}'"),
+            ));
+        }
+        let value = if content.peek(syn::token::Paren) {
+            BindingValue::Nested(parse_fields(protocol, &field_path, &content)?)
+        } else {
+            content.parse::<Token![=]>()?;
+            if content.peek(syn::token::Bracket) {
+                let items;
+                syn::bracketed!(items in content);
+                let mut values = Vec::new();
+                while !items.is_empty() {
+                    values.push(items.parse::<Lit>()?);
+                    parse_optional_comma(&items)?;
+                }
+                BindingValue::Array(values)
+            } else if !content.peek(Lit) {
+                BindingValue::Schema(content.parse::<Type>()?)
+            } else {
+                let value: Lit = content.parse()?;
+                check_enum_value(protocol, &field_path, &value)?;
+                BindingValue::Lit(value)
+            }
+        };
+        fields.push((field, value));
+        parse_optional_comma(&content)?;
+    }
+    Ok(fields)
+}
+
+/// Reject string values outside the enum of fields listed in [`ENUM_FIELDS`]
+fn check_enum_value(protocol: &Ident, field_path: &str, value: &Lit) -> syn::Result<()> {
+    let Lit::Str(value) = value else {
+        return Ok(());
+    };
+    let allowed = ENUM_FIELDS
+        .iter()
+        .find(|(enum_protocol, path, _)| protocol == enum_protocol && *path == field_path);
+    match allowed {
+        Some((_, _, values)) if !values.contains(&value.value().as_str()) => Err(Error::new(
+            value.span(),
+            format!(
+                "Invalid value '{}' for {} binding field '{}'. Expected one of: {}",
+                value.value(),
+                protocol,
+                field_path,
+                values.join(", ")
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Add a parsed binding to `bindings`, rejecting a second binding for the same protocol
+pub fn push_binding(bindings: &mut Vec<BindingAttrs>, binding: BindingAttrs) -> syn::Result<()> {
+    if bindings
+        .iter()
+        .any(|existing| existing.protocol == binding.protocol)
+    {
+        return Err(Error::new(
+            binding.protocol.span(),
+            format!("Duplicate '{}' binding", binding.protocol),
+        ));
+    }
+    bindings.push(binding);
+    Ok(())
+}
+
+/// Generate an expression building the bindings object, e.g. `protofolio::ChannelBindings`
+///
+/// Returns `None` if there are no bindings.
+pub fn generate_bindings_value(
+    bindings: &[BindingAttrs],
+    target: BindingTarget,
+) -> Option<TokenStream> {
+    if bindings.is_empty() {
+        return None;
+    }
+
+    let container = format_ident!("{}Bindings", target.name());
+    let protocols = bindings.iter().map(|binding| {
+        let protocol = &binding.protocol;
+        let assignments = generate_assignments(&binding.fields);
+        quote! {
+            {
+                let binding = bindings.#protocol.get_or_insert_with(Default::default);
+                #assignments
+            }
+        }
+    });
+
+    Some(quote! {
+        {
+            let mut bindings = protofolio::#container::default();
+            #(#protocols)*
+            bindings
+        }
+    })
+}
+
+/// Assign each field of `binding`; nested groups fill the nested struct in place
+fn generate_assignments(fields: &[(Ident, BindingValue)]) -> TokenStream {
+    let assignments = fields.iter().map(|(field, value)| {
+        let field = field_ident(field);
+        match value {
+            BindingValue::Lit(value) => {
+                let value = literal_value(value);
+                quote! { binding.#field = #value; }
+            }
+            BindingValue::Array(values) => {
+                let values = values.iter().map(|value| match value {
+                    Lit::Str(value) => quote! { ::core::convert::Into::into(#value) },
+                    value => quote! { #value },
+                });
+                quote! { binding.#field = Some(vec![#(#values),*]); }
+            }
+            BindingValue::Schema(ty) => {
+                quote! { binding.#field = protofolio::schema_for_type::<#ty>().ok(); }
+            }
+            BindingValue::Nested(fields) => {
+                let assignments = generate_assignments(fields);
+                quote! {
+                    {
+                        let binding = binding.#field.get_or_insert_with(Default::default);
+                        #assignments
+                    }
+                }
+            }
+        }
+    });
+    quote! { #(#assignments)* }
+}
+
+/// `Option` value of a literal field
+fn literal_value(value: &Lit) -> TokenStream {
+    match value {
+        // String fields are `String`, `serde_json::Value` or an enum with `TryFrom<&str>`
+        Lit::Str(value) => quote! { ::core::convert::TryFrom::try_from(#value).ok() },
+        value => quote! { Some(#value) },
+    }
+}
+
+/// Field names that are keywords (`type`) need the raw identifier form
+fn field_ident(field: &Ident) -> Ident {
+    let name = field.to_string();
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        field.clone()
+    } else {
+        Ident::new_raw(&name, field.span())
+    }
+}
@@ -0,0 +1,98 @@
+//! Specification extension attributes shared by the derive macros
+//!
+//! `extensions(x_owner = "payments", x_internal = true)` adds `x-` fields to
+//! the generated object. Underscores in identifier names become dashes; names
+//! that need underscores can be given as strings (`"x-retention_ms" = 1000`).
+//! Values are literals or arrays of literals.
+
+use crate::parse_utils::parse_optional_comma;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::ParseStream, Error, Ident, Lit, LitStr, Token};
+
+/// Value of an extension field
+pub enum ExtensionValue {
+    /// `x_owner = "payments"`
+    Lit(Lit),
+    /// `x_teams = ["billing", "payments"]`
+    Array(Vec<Lit>),
+}
+
+/// A single extension field, e.g. `x_owner = "payments"`
+pub struct ExtensionAttr {
+    pub name: LitStr,
+    pub value: ExtensionValue,
+}
+
+/// Parse the parenthesized field list of `extensions(...)`
+pub fn parse_extensions(input: ParseStream) -> syn::Result<Vec<ExtensionAttr>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut extensions: Vec<ExtensionAttr> = Vec::new();
+    while !content.is_empty() {
+        let name = if content.peek(LitStr) {
+            content.parse::<LitStr>()?
+        } else {
+            let ident: Ident = content.parse()?;
+            LitStr::new(&ident.to_string().replace('_', "-"), ident.span())
+        };
+        if !name.value().starts_with("x-") {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "Extension '{}' must start with 'x-'\n\nExample: extensions(x_owner = \"payments\")",
+                    name.value()
+                ),
+            ));
+        }
+        if extensions
+            .iter()
+            .any(|existing| existing.name.value() == name.value())
+        {
+            return Err(Error::new(
+                name.span(),
+                format!("Duplicate extension '{}'", name.value()),
+            ));
+        }
+        content.parse::<Token![=]>()?;
+        let value = if content.peek(syn::token::Bracket) {
+            let items;
+            syn::bracketed!(items in content);
+            let mut values = Vec::new();
+            while !items.is_empty() {
+                values.push(items.parse::<Lit>()?);
+                parse_optional_comma(&items)?;
+            }
+            ExtensionValue::Array(values)
+        } else {
+            ExtensionValue::Lit(content.parse()?)
+        };
+        extensions.push(ExtensionAttr { name, value });
+        parse_optional_comma(&content)?;
+    }
+    Ok(extensions)
+}
+
+/// Generate an expression building the `protofolio::Extensions` map
+pub fn generate_extensions_value(extensions: &[ExtensionAttr]) -> TokenStream {
+    if extensions.is_empty() {
+        return quote! { protofolio::Extensions::new() };
+    }
+    let inserts = extensions.iter().map(|extension| {
+        let name = &extension.name;
+        let value = match &extension.value {
+            ExtensionValue::Lit(value) => quote! { serde_json::Value::from(#value) },
+            ExtensionValue::Array(values) => {
+                quote! { serde_json::Value::Array(vec![#(serde_json::Value::from(#values)),*]) }
+            }
+        };
+        quote! { extensions.insert(#name.to_string(), #value); }
+    });
+    quote! {
+        {
+            let mut extensions = protofolio::Extensions::new();
+            #(#inserts)*
+            extensions
+        }
+    }
+}
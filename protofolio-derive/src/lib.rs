@@ -46,6 +46,8 @@
 //! - Schema generation fails
 
 mod asyncapi;
+mod bindings;
+mod extensions;
 mod message;
 mod operation;
 mod parse_utils;
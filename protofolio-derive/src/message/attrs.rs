@@ -1,6 +1,10 @@
 //! Parser structures and implementations for `AsyncApiMessage` attributes
 
-use crate::parse_utils::{parse_examples_array, parse_optional_comma, parse_tags_array};
+use crate::bindings::{is_binding_protocol, parse_binding, push_binding, BindingAttrs};
+use crate::extensions::{parse_extensions, ExtensionAttr};
+use crate::parse_utils::{
+    parse_examples_array, parse_optional_comma, parse_string_array, parse_tags_array,
+};
 use syn::{parse::Parse, Error, LitStr, Path, Token};
 
 /// Parser structure for external documentation attributes
@@ -15,6 +19,16 @@ pub struct CorrelationIdAttrs {
     pub description: Option<LitStr>,
 }
 
+/// Parser structure for a channel parameter
+pub struct ParameterAttrs {
+    pub name: LitStr,
+    pub description: Option<LitStr>,
+    pub enum_values: Option<Vec<LitStr>>,
+    pub default: Option<LitStr>,
+    pub examples: Option<Vec<LitStr>>,
+    pub location: Option<LitStr>,
+}
+
 /// Parser structure for message attributes
 pub struct MessageAttrs {
     pub channel: Option<LitStr>,
@@ -28,8 +42,13 @@ pub struct MessageAttrs {
     pub external_docs: Option<ExternalDocsAttrs>,
     pub example: Option<LitStr>,
     pub examples: Option<Vec<LitStr>>,
+    /// Emit a test that deserializes every example into the message type
+    pub test_examples: bool,
     pub headers: Option<Path>,
     pub correlation_id: Option<CorrelationIdAttrs>,
+    pub parameters: Option<Vec<ParameterAttrs>>,
+    pub bindings: Vec<BindingAttrs>,
+    pub extensions: Vec<ExtensionAttr>,
 }
 
 impl Parse for ExternalDocsAttrs {
@@ -108,6 +127,73 @@ impl Parse for CorrelationIdAttrs {
     }
 }
 
+impl Parse for ParameterAttrs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+        let mut description = None;
+        let mut enum_values = None;
+        let mut default = None;
+        let mut examples = None;
+        let mut location = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![enum]) {
+                // `enum` is a keyword, so it cannot be parsed as an identifier
+                input.parse::<Token![enum]>()?;
+                input.parse::<Token![=]>()?;
+                enum_values = Some(parse_string_array(input)?);
+            } else if lookahead.peek(syn::Ident) {
+                let ident: syn::Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                match ident.to_string().as_str() {
+                    "name" => name = Some(input.parse()?),
+                    "description" => description = Some(input.parse()?),
+                    "default" => default = Some(input.parse()?),
+                    "examples" => examples = Some(parse_string_array(input)?),
+                    "location" => location = Some(input.parse()?),
+                    _ => {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!(
+                                "Unknown parameter attribute '{ident}'. Expected one of: name, description, enum, default, examples, location\n\nExample: #[asyncapi(parameters((name = \"tenantId\", description = \"Tenant identifier\", enum = [\"acme\", \"globex\"], default = \"acme\", location = \"$message.payload#/tenant\")))]"
+                            ),
+                        ));
+                    }
+                }
+            } else {
+                return Err(lookahead.error());
+            }
+
+            parse_optional_comma(input)?;
+        }
+
+        Ok(Self {
+            name: name.ok_or_else(|| input.error("parameter requires 'name'"))?,
+            description,
+            enum_values,
+            default,
+            examples,
+            location,
+        })
+    }
+}
+
+/// Parse a `parameters((...), (...))` list
+fn parse_parameters(input: syn::parse::ParseStream) -> syn::Result<Vec<ParameterAttrs>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut parameters = Vec::new();
+    while !content.is_empty() {
+        let parameter;
+        syn::parenthesized!(parameter in content);
+        parameters.push(parameter.parse()?);
+        parse_optional_comma(&content)?;
+    }
+    Ok(parameters)
+}
+
 impl Parse for MessageAttrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut channel = None;
@@ -121,8 +207,12 @@ impl Parse for MessageAttrs {
         let mut external_docs = None;
         let mut example = None;
         let mut examples = None;
+        let mut test_examples = false;
         let mut headers = None;
         let mut correlation_id = None;
+        let mut parameters = None;
+        let mut bindings = Vec::new();
+        let mut extensions = Vec::new();
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
@@ -138,6 +228,8 @@ impl Parse for MessageAttrs {
                 } else if ident == "examples" {
                     input.parse::<Token![=]>()?;
                     examples = Some(parse_examples_array(input)?);
+                } else if ident == "test_examples" {
+                    test_examples = true;
                 } else if ident_str == "external_docs" || ident_str == "externalDocs" {
                     let content;
                     syn::parenthesized!(content in input);
@@ -146,6 +238,12 @@ impl Parse for MessageAttrs {
                     let content;
                     syn::parenthesized!(content in input);
                     correlation_id = Some(content.parse()?);
+                } else if is_binding_protocol(&ident_str) {
+                    push_binding(&mut bindings, parse_binding(ident, input)?)?;
+                } else if ident == "extensions" {
+                    extensions = parse_extensions(input)?;
+                } else if ident == "parameters" {
+                    parameters = Some(parse_parameters(input)?);
                 } else if ident == "headers" {
                     input.parse::<Token![=]>()?;
                     headers = Some(input.parse::<Path>()?);
@@ -191,7 +289,7 @@ impl Parse for MessageAttrs {
                             return Err(Error::new(
                                 span,
                                 format!(
-                                    "Unknown attribute '{ident_str}'. Expected one of: channel, summary, description, messageId, name, title, contentType, tags, example, examples, headers, external_docs, correlation_id\n\nExample: #[asyncapi(channel = \"events\", messageId = \"event-v1\", name = \"Event\", summary = \"An event\", tags = [\"events\"], example = \"{{\\\"id\\\": \\\"123\\\"}}\", headers = MyHeaders, external_docs(url = \"https://example.com/docs\"), correlation_id(location = \"$message.header#/correlationId\"))]"
+                                    "Unknown attribute '{ident_str}'. Expected one of: channel, summary, description, messageId, name, title, contentType, tags, example, examples, test_examples, headers, external_docs, correlation_id, parameters, extensions, amqp, kafka, mqtt, nats, ws\n\nExample: #[asyncapi(channel = \"events\", messageId = \"event-v1\", name = \"Event\", summary = \"An event\", tags = [\"events\"], example = \"{{\\\"id\\\": \\\"123\\\"}}\", headers = MyHeaders, external_docs(url = \"https://example.com/docs\"), correlation_id(location = \"$message.header#/correlationId\"))]"
                                 ),
                             ));
                         }
@@ -216,8 +314,12 @@ impl Parse for MessageAttrs {
             external_docs,
             example,
             examples,
+            test_examples,
             headers,
             correlation_id,
+            parameters,
+            bindings,
+            extensions,
         })
     }
 }
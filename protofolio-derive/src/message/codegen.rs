@@ -1,8 +1,10 @@
 //! Code generation for `AsyncApiMessage` derive macro
 
-use crate::message::attrs::{CorrelationIdAttrs, ExternalDocsAttrs};
+use crate::bindings::{generate_bindings_value, BindingAttrs, BindingTarget};
+use crate::extensions::{generate_extensions_value, ExtensionAttr};
+use crate::message::attrs::{CorrelationIdAttrs, ExternalDocsAttrs, ParameterAttrs};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Ident, LitStr, Path};
 
 /// Generate optional field code
@@ -24,6 +26,7 @@ pub fn generate_tags_code(tags: &Option<Vec<LitStr>>) -> TokenStream {
                         protofolio::Tag {
                             name: #tag.to_string(),
                             description: None,
+                            extensions: protofolio::Extensions::new(),
                         }
                     }
                 })
@@ -50,6 +53,7 @@ pub fn generate_external_docs_code(external_docs: &Option<ExternalDocsAttrs>) ->
                 Some(protofolio::ExternalDocumentation {
                     url: #url_lit.to_string(),
                     description: #desc_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
@@ -88,6 +92,31 @@ pub fn generate_examples_code(
     }
 }
 
+/// Generate a test that deserializes every example payload into the message type
+///
+/// Emitted for `test_examples`, which requires the message type to implement
+/// `Deserialize`.
+pub fn generate_examples_test(ident: &Ident) -> TokenStream {
+    let test_ident = format_ident!("__protofolio_examples_deserialize_{}", ident);
+    quote! {
+        #[allow(non_snake_case)]
+        #[cfg(test)]
+        #[test]
+        fn #test_ident() {
+            for (index, payload) in #ident::examples().unwrap_or_default().into_iter().enumerate() {
+                if let Err(e) = serde_json::from_value::<#ident>(payload) {
+                    panic!(
+                        "Example {} of message '{}' does not deserialize: {}\n\nHint: Update the example to match the current fields of the type",
+                        index,
+                        stringify!(#ident),
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Generate headers code
 /// `Headers` is a type path that should implement `JsonSchema`
 pub fn generate_headers_code(headers: &Option<Path>) -> TokenStream {
@@ -130,27 +159,95 @@ pub fn generate_correlation_id_code(correlation_id: &Option<CorrelationIdAttrs>)
                 Some(protofolio::CorrelationId {
                     location: #location_lit.to_string(),
                     description: #desc_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
     )
 }
 
+/// Generate channel parameters code
+pub fn generate_parameters_code(parameters: Option<&Vec<ParameterAttrs>>) -> TokenStream {
+    parameters.map_or_else(
+        || quote! { None },
+        |parameter_list| {
+            let string_opt = |lit: &Option<LitStr>| {
+                lit.as_ref()
+                    .map_or_else(|| quote! { None }, |lit| quote! { Some(#lit.to_string()) })
+            };
+            let list_opt = |lits: &Option<Vec<LitStr>>| {
+                lits.as_ref().map_or_else(
+                    || quote! { None },
+                    |lits| quote! { Some(vec![#(#lits.to_string()),*]) },
+                )
+            };
+            let inserts: Vec<_> = parameter_list
+                .iter()
+                .map(|parameter| {
+                    let name = &parameter.name;
+                    let description = string_opt(&parameter.description);
+                    let enum_values = list_opt(&parameter.enum_values);
+                    let default = string_opt(&parameter.default);
+                    let examples = list_opt(&parameter.examples);
+                    let location = string_opt(&parameter.location);
+                    quote! {
+                        parameters.insert(
+                            #name.to_string(),
+                            protofolio::Parameter {
+                                description: #description,
+                                enum_values: #enum_values,
+                                default: #default,
+                                examples: #examples,
+                                schema: None,
+                                location: #location,
+                                extensions: protofolio::Extensions::new(),
+                            },
+                        );
+                    }
+                })
+                .collect();
+            quote! {
+                {
+                    let mut parameters = protofolio::IndexMap::new();
+                    #(#inserts)*
+                    Some(parameters)
+                }
+            }
+        },
+    )
+}
+
+/// Generate protocol bindings code
+pub fn generate_bindings_code(bindings: &[BindingAttrs]) -> TokenStream {
+    generate_bindings_value(bindings, BindingTarget::Message).map_or_else(
+        || quote! { None },
+        |value| quote! { Some(protofolio::MessageBindingsOrRef::bindings(#value)) },
+    )
+}
+
+/// Generate specification extensions code
+pub fn generate_extensions_code(extensions: &[ExtensionAttr]) -> TokenStream {
+    generate_extensions_value(extensions)
+}
+
 /// Generate the complete impl block for `AsyncApiMessage`
 pub fn generate_impl_block(
     ident: &Ident,
     channel_lit: &LitStr,
-    summary_opt: TokenStream,
-    desc_opt: TokenStream,
-    message_id_opt: TokenStream,
-    name_opt: TokenStream,
-    title_opt: TokenStream,
-    content_type_opt: TokenStream,
-    tags_opt: TokenStream,
-    external_docs_opt: TokenStream,
-    examples_opt: TokenStream,
-    headers_opt: TokenStream,
-    correlation_id_opt: TokenStream,
+    summary_opt: &TokenStream,
+    desc_opt: &TokenStream,
+    message_id_opt: &TokenStream,
+    name_opt: &TokenStream,
+    title_opt: &TokenStream,
+    content_type_opt: &TokenStream,
+    tags_opt: &TokenStream,
+    external_docs_opt: &TokenStream,
+    examples_opt: &TokenStream,
+    headers_opt: &TokenStream,
+    correlation_id_opt: &TokenStream,
+    parameters_opt: &TokenStream,
+    bindings_opt: &TokenStream,
+    extensions: &TokenStream,
 ) -> TokenStream {
     quote! {
         impl #ident {
@@ -202,7 +299,7 @@ pub fn generate_impl_block(
                 #external_docs_opt
             }
 
-            /// Get the examples for this message
+            /// Get the example payloads for this message
             pub fn examples() -> Option<Vec<serde_json::Value>> {
                 #examples_opt
             }
@@ -216,6 +313,21 @@ pub fn generate_impl_block(
             pub fn correlation_id() -> Option<protofolio::CorrelationId> {
                 #correlation_id_opt
             }
+
+            /// Get the parameters of this message's channel address
+            pub fn parameters() -> Option<protofolio::IndexMap<String, protofolio::Parameter>> {
+                #parameters_opt
+            }
+
+            /// Get the protocol bindings for this message
+            pub fn bindings() -> Option<protofolio::MessageBindingsOrRef> {
+                #bindings_opt
+            }
+
+            /// Get the specification extensions (`x-` fields) for this message
+            pub fn extensions() -> protofolio::Extensions {
+                #extensions
+            }
         }
     }
 }
@@ -4,16 +4,19 @@ mod attrs;
 mod codegen;
 
 use crate::message::{
-    attrs::MessageAttrs,
+    attrs::{MessageAttrs, ParameterAttrs},
     codegen::{
-        generate_correlation_id_code, generate_examples_code, generate_external_docs_code,
+        generate_bindings_code, generate_correlation_id_code, generate_examples_code,
+        generate_examples_test, generate_extensions_code, generate_external_docs_code,
         generate_headers_code, generate_impl_block, generate_optional_field_code,
-        generate_tags_code,
+        generate_parameters_code, generate_tags_code,
     },
 };
+use crate::parse_utils::template_placeholders;
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
-use syn::{DeriveInput, Error};
+use quote::quote;
+use syn::{DeriveInput, Error, LitStr};
 
 /// Derive `AsyncApiMessage` implementation
 #[allow(clippy::too_many_lines)] // Macro code is inherently long
@@ -32,8 +35,12 @@ pub fn derive_asyncapi_message(input: DeriveInput) -> Result<TokenStream, Error>
     let mut external_docs = None;
     let mut example = None;
     let mut examples = None;
+    let mut test_examples = false;
     let mut headers = None;
     let mut correlation_id = None;
+    let mut parameters = None;
+    let mut bindings = Vec::new();
+    let mut extensions = Vec::new();
 
     for attr in &input.attrs {
         if attr.path().is_ident("asyncapi") {
@@ -62,8 +69,12 @@ pub fn derive_asyncapi_message(input: DeriveInput) -> Result<TokenStream, Error>
                     external_docs = attrs.external_docs;
                     example = attrs.example;
                     examples = attrs.examples;
+                    test_examples = attrs.test_examples;
                     headers = attrs.headers;
                     correlation_id = attrs.correlation_id;
+                    parameters = attrs.parameters;
+                    bindings = attrs.bindings;
+                    extensions = attrs.extensions;
                 }
                 Err(e) => {
                     abort!(
@@ -83,6 +94,15 @@ pub fn derive_asyncapi_message(input: DeriveInput) -> Result<TokenStream, Error>
         );
     };
 
+    validate_channel_parameters(&channel_lit, parameters.as_deref().unwrap_or_default());
+    if test_examples && example.is_none() && examples.is_none() {
+        abort!(
+            ident,
+            "Message '{}' has test_examples but no examples to test.\n\nHint: Add example = \"...\" or examples = [...], or remove test_examples",
+            ident
+        );
+    }
+
     // Generate optional field code
     let summary_opt = generate_optional_field_code(&summary);
     let desc_opt = generate_optional_field_code(&description);
@@ -95,21 +115,82 @@ pub fn derive_asyncapi_message(input: DeriveInput) -> Result<TokenStream, Error>
     let examples_opt = generate_examples_code(&example, &examples);
     let headers_opt = generate_headers_code(&headers);
     let correlation_id_opt = generate_correlation_id_code(&correlation_id);
+    let parameters_opt = generate_parameters_code(parameters.as_ref());
+    let bindings_opt = generate_bindings_code(&bindings);
+    let extensions_code = generate_extensions_code(&extensions);
+    let examples_test = if test_examples {
+        generate_examples_test(ident)
+    } else {
+        quote! {}
+    };
 
     // Generate code that stores metadata
-    Ok(generate_impl_block(
+    let impl_block = generate_impl_block(
         ident,
         &channel_lit,
-        summary_opt,
-        desc_opt,
-        message_id_opt,
-        name_opt,
-        title_opt,
-        content_type_opt,
-        tags_opt,
-        external_docs_opt,
-        examples_opt,
-        headers_opt,
-        correlation_id_opt,
-    ))
+        &summary_opt,
+        &desc_opt,
+        &message_id_opt,
+        &name_opt,
+        &title_opt,
+        &content_type_opt,
+        &tags_opt,
+        &external_docs_opt,
+        &examples_opt,
+        &headers_opt,
+        &correlation_id_opt,
+        &parameters_opt,
+        &bindings_opt,
+        &extensions_code,
+    );
+    Ok(quote! {
+        #impl_block
+        #examples_test
+    })
+}
+
+/// Check that every `{placeholder}` in the channel address has a parameter and vice versa
+fn validate_channel_parameters(channel_lit: &LitStr, parameters: &[ParameterAttrs]) {
+    let address = channel_lit.value();
+    let placeholders = match template_placeholders(&address) {
+        Ok(placeholders) => placeholders,
+        Err(problem) => abort!(
+            channel_lit,
+            "Channel address '{}' is invalid: {}.\n\nHint: Placeholders look like 'orders.{{tenantId}}.created'",
+            address,
+            problem
+        ),
+    };
+
+    for placeholder in &placeholders {
+        if !parameters.iter().any(|p| p.name.value() == *placeholder) {
+            abort!(
+                channel_lit,
+                "Channel address '{}' has placeholder '{{{}}}' without a parameter.\n\nHint: Declare it with parameters((name = \"{}\", description = \"...\"))",
+                address,
+                placeholder,
+                placeholder
+            );
+        }
+    }
+
+    for (index, parameter) in parameters.iter().enumerate() {
+        let name = parameter.name.value();
+        if parameters[..index].iter().any(|p| p.name.value() == name) {
+            abort!(
+                parameter.name,
+                "Parameter '{}' is declared more than once",
+                name
+            );
+        }
+        if !placeholders.contains(&name.as_str()) {
+            abort!(
+                parameter.name,
+                "Parameter '{}' does not appear in channel address '{}'.\n\nHint: Add '{{{}}}' to the channel address or remove the parameter",
+                name,
+                address,
+                name
+            );
+        }
+    }
 }
@@ -1,5 +1,7 @@
 //! Parser structures and implementations for `AsyncApiOperation` attributes
 
+use crate::bindings::{is_binding_protocol, parse_binding, push_binding, BindingAttrs};
+use crate::extensions::{parse_extensions, ExtensionAttr};
 use crate::parse_utils::{parse_optional_comma, parse_tags_array};
 use syn::{parse::Parse, Error, LitStr, Token};
 
@@ -9,6 +11,14 @@ pub struct ExternalDocsAttrs {
     pub description: Option<LitStr>,
 }
 
+/// Parser structure for operation reply attributes
+pub struct ReplyAttrs {
+    pub channel: Option<LitStr>,
+    pub messages: Vec<syn::Path>,
+    pub address: Option<LitStr>,
+    pub address_description: Option<LitStr>,
+}
+
 /// Parser structure for operation attributes
 pub struct OperationAttrs {
     pub operation_id: Option<LitStr>,
@@ -19,6 +29,9 @@ pub struct OperationAttrs {
     pub description: Option<LitStr>,
     pub tags: Option<Vec<LitStr>>,
     pub external_docs: Option<ExternalDocsAttrs>,
+    pub reply: Option<ReplyAttrs>,
+    pub bindings: Vec<BindingAttrs>,
+    pub extensions: Vec<ExtensionAttr>,
 }
 
 impl Parse for ExternalDocsAttrs {
@@ -59,6 +72,75 @@ impl Parse for ExternalDocsAttrs {
     }
 }
 
+impl Parse for ReplyAttrs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut channel = None;
+        let mut messages = Vec::new();
+        let mut address = None;
+        let mut address_description = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(syn::Ident) {
+                let ident: syn::Ident = input.parse()?;
+
+                if ident == "messages" {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    while !content.is_empty() {
+                        messages.push(content.parse()?);
+                        if content.peek(Token![,]) {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                } else {
+                    input.parse::<Token![=]>()?;
+                    let lit: LitStr = input.parse()?;
+
+                    match ident.to_string().as_str() {
+                        "channel" => channel = Some(lit),
+                        "address" => address = Some(lit),
+                        "address_description" | "addressDescription" => {
+                            address_description = Some(lit);
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ident.span(),
+                                format!(
+                                    "Unknown reply attribute '{ident}'. Expected one of: channel, messages, address, address_description\n\nExample: #[asyncapi(reply(channel = \"user.replies\", messages(UserFound), address = \"$message.header#/replyTo\"))]"
+                                ),
+                            ));
+                        }
+                    }
+                }
+            } else {
+                return Err(lookahead.error());
+            }
+
+            parse_optional_comma(input)?;
+        }
+
+        if channel.is_none() && address.is_none() {
+            return Err(input.error("reply requires 'channel' or 'address'"));
+        }
+        if !messages.is_empty() && channel.is_none() {
+            return Err(input.error(
+                "reply messages(...) requires 'channel': reply messages must belong to the reply channel",
+            ));
+        }
+        if address.is_none() && address_description.is_some() {
+            return Err(input.error("reply 'address_description' requires 'address'"));
+        }
+
+        Ok(Self {
+            channel,
+            messages,
+            address,
+            address_description,
+        })
+    }
+}
+
 impl Parse for OperationAttrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut operation_id = None;
@@ -69,6 +151,9 @@ impl Parse for OperationAttrs {
         let mut description = None;
         let mut tags = None;
         let mut external_docs = None;
+        let mut reply = None;
+        let mut bindings = Vec::new();
+        let mut extensions = Vec::new();
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
@@ -96,6 +181,14 @@ impl Parse for OperationAttrs {
                     let content;
                     syn::parenthesized!(content in input);
                     external_docs = Some(content.parse()?);
+                } else if ident == "reply" {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    reply = Some(content.parse()?);
+                } else if ident == "extensions" {
+                    extensions = parse_extensions(input)?;
+                } else if is_binding_protocol(&ident_str) {
+                    push_binding(&mut bindings, parse_binding(ident, input)?)?;
                 } else {
                     // Check if this is a tags array
                     if ident == "tags" {
@@ -117,7 +210,7 @@ impl Parse for OperationAttrs {
                                 return Err(Error::new(
                                     span,
                                     format!(
-                                        "Unknown attribute '{}'. Expected one of: id, action, channel, messages, summary, description, tags, external_docs\n\nExample: #[asyncapi(id = \"op-1\", action = \"send\", channel = \"events\", messages(MyMessage), summary = \"Operation summary\", tags = [\"tag1\"], external_docs(url = \"https://example.com/docs\"))]",
+                                        "Unknown attribute '{}'. Expected one of: id, action, channel, messages, summary, description, tags, external_docs, reply, extensions, amqp, kafka, mqtt, nats, ws\n\nExample: #[asyncapi(id = \"op-1\", action = \"send\", channel = \"events\", messages(MyMessage), summary = \"Operation summary\", tags = [\"tag1\"], external_docs(url = \"https://example.com/docs\"))]",
                                         ident_str
                                     ),
                                 ));
@@ -141,6 +234,9 @@ impl Parse for OperationAttrs {
             description,
             tags,
             external_docs,
+            reply,
+            bindings,
+            extensions,
         })
     }
 }
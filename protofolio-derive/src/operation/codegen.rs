@@ -1,6 +1,8 @@
 //! Code generation for `AsyncApiOperation` derive macro
 
-use crate::operation::attrs::ExternalDocsAttrs;
+use crate::bindings::{generate_bindings_value, BindingAttrs, BindingTarget};
+use crate::extensions::{generate_extensions_value, ExtensionAttr};
+use crate::operation::attrs::{ExternalDocsAttrs, ReplyAttrs};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Ident, LitStr};
@@ -24,6 +26,7 @@ pub fn generate_tags_code(tags: &Option<Vec<LitStr>>) -> TokenStream {
                         protofolio::Tag {
                             name: #tag.to_string(),
                             description: None,
+                            extensions: protofolio::Extensions::new(),
                         }
                     }
                 })
@@ -50,12 +53,83 @@ pub fn generate_external_docs_code(external_docs: &Option<ExternalDocsAttrs>) ->
                 Some(protofolio::ExternalDocumentation {
                     url: #url_lit.to_string(),
                     description: #desc_expr,
+                    extensions: protofolio::Extensions::new(),
                 })
             }
         },
     )
 }
 
+/// Generate operation reply code
+///
+/// Reply messages are referenced as messages of the reply channel.
+pub fn generate_reply_code(reply: Option<&ReplyAttrs>) -> TokenStream {
+    reply.map_or_else(
+        || quote! { None },
+        |reply| {
+            let address_expr = reply.address.as_ref().map_or_else(
+                || quote! { None },
+                |location| {
+                    let desc_expr = reply.address_description.as_ref().map_or_else(
+                        || quote! { None },
+                        |desc| quote! { Some(#desc.to_string()) },
+                    );
+                    quote! {
+                        Some(protofolio::OperationReplyAddressOrRef::address(
+                            protofolio::OperationReplyAddress {
+                                description: #desc_expr,
+                                location: #location.to_string(),
+                                extensions: protofolio::Extensions::new(),
+                            }
+                        ))
+                    }
+                },
+            );
+            let channel_expr = reply.channel.as_ref().map_or_else(
+                || quote! { None },
+                |channel| {
+                    quote! {
+                        Some(protofolio::ChannelReference {
+                            ref_path: protofolio::local_ref(["channels", #channel]),
+                        })
+                    }
+                },
+            );
+            let messages_expr = match (&reply.channel, reply.messages.as_slice()) {
+                (Some(channel), messages) if !messages.is_empty() => quote! {
+                    Some(vec![#(
+                        protofolio::MessageReference {
+                            ref_path: protofolio::local_ref(["channels", #channel, "messages", stringify!(#messages)]),
+                        }
+                    ),*])
+                },
+                _ => quote! { None },
+            };
+            quote! {
+                Some(protofolio::OperationReply {
+                    address: #address_expr,
+                    channel: #channel_expr,
+                    messages: #messages_expr,
+                    extensions: protofolio::Extensions::new(),
+                })
+            }
+        },
+    )
+}
+
+/// Generate protocol bindings code
+pub fn generate_bindings_code(bindings: &[BindingAttrs]) -> TokenStream {
+    generate_bindings_value(bindings, BindingTarget::Operation).map_or_else(
+        || quote! { None },
+        |value| quote! { Some(protofolio::OperationBindingsOrRef::bindings(#value)) },
+    )
+}
+
+/// Generate specification extensions code
+pub fn generate_extensions_code(extensions: &[ExtensionAttr]) -> TokenStream {
+    generate_extensions_value(extensions)
+}
+
 /// Generate the complete impl block for `AsyncApiOperation`
 pub fn generate_impl_block(
     ident: &Ident,
@@ -63,10 +137,13 @@ pub fn generate_impl_block(
     operation_id_lit: &LitStr,
     action_lit: &LitStr,
     messages: &[syn::Path],
-    summary_opt: TokenStream,
-    desc_opt: TokenStream,
-    tags_opt: TokenStream,
-    external_docs_opt: TokenStream,
+    summary_opt: &TokenStream,
+    desc_opt: &TokenStream,
+    tags_opt: &TokenStream,
+    external_docs_opt: &TokenStream,
+    reply_opt: &TokenStream,
+    bindings_opt: &TokenStream,
+    extensions: &TokenStream,
 ) -> TokenStream {
     quote! {
         impl #ident {
@@ -113,6 +190,18 @@ pub fn generate_impl_block(
             fn external_docs() -> Option<protofolio::ExternalDocumentation> {
                 #external_docs_opt
             }
+
+            fn reply() -> Option<protofolio::OperationReply> {
+                #reply_opt
+            }
+
+            fn bindings() -> Option<protofolio::OperationBindingsOrRef> {
+                #bindings_opt
+            }
+
+            fn extensions() -> protofolio::Extensions {
+                #extensions
+            }
         }
     }
 }
@@ -6,8 +6,8 @@ mod codegen;
 use crate::operation::{
     attrs::OperationAttrs,
     codegen::{
-        generate_external_docs_code, generate_impl_block, generate_optional_field_code,
-        generate_tags_code,
+        generate_bindings_code, generate_extensions_code, generate_external_docs_code,
+        generate_impl_block, generate_optional_field_code, generate_reply_code, generate_tags_code,
     },
 };
 use proc_macro2::TokenStream;
@@ -28,6 +28,9 @@ pub fn derive_asyncapi_operation(input: DeriveInput) -> Result<TokenStream, Erro
     let mut description = None;
     let mut tags = None;
     let mut external_docs = None;
+    let mut reply = None;
+    let mut bindings = Vec::new();
+    let mut extensions = Vec::new();
 
     for attr in &input.attrs {
         if attr.path().is_ident("asyncapi") {
@@ -53,6 +56,9 @@ pub fn derive_asyncapi_operation(input: DeriveInput) -> Result<TokenStream, Erro
                     description = attrs.description;
                     tags = attrs.tags;
                     external_docs = attrs.external_docs;
+                    reply = attrs.reply;
+                    bindings = attrs.bindings;
+                    extensions = attrs.extensions;
                 }
                 Err(e) => {
                     abort!(
@@ -108,6 +114,9 @@ pub fn derive_asyncapi_operation(input: DeriveInput) -> Result<TokenStream, Erro
     let desc_opt = generate_optional_field_code(&description);
     let tags_opt = generate_tags_code(&tags);
     let external_docs_opt = generate_external_docs_code(&external_docs);
+    let reply_opt = generate_reply_code(reply.as_ref());
+    let bindings_opt = generate_bindings_code(&bindings);
+    let extensions_code = generate_extensions_code(&extensions);
 
     // Generate code that stores metadata
     Ok(generate_impl_block(
@@ -116,9 +125,12 @@ pub fn derive_asyncapi_operation(input: DeriveInput) -> Result<TokenStream, Erro
         &operation_id_lit,
         &action_lit,
         &messages,
-        summary_opt,
-        desc_opt,
-        tags_opt,
-        external_docs_opt,
+        &summary_opt,
+        &desc_opt,
+        &tags_opt,
+        &external_docs_opt,
+        &reply_opt,
+        &bindings_opt,
+        &extensions_code,
     ))
 }
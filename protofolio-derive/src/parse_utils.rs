@@ -46,3 +46,52 @@ pub fn parse_examples_array(input: ParseStream) -> syn::Result<Vec<LitStr>> {
     }
     Ok(example_list)
 }
+
+/// Parse an array of string literals from bracketed content
+///
+/// Expects format: `["value1", "value2", ...]`
+pub fn parse_string_array(input: ParseStream) -> syn::Result<Vec<LitStr>> {
+    let content;
+    syn::bracketed!(content in input);
+    let mut values = Vec::new();
+    while !content.is_empty() {
+        values.push(content.parse::<LitStr>()?);
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(values)
+}
+
+/// Names of the `{placeholders}` in a channel address or server URL, in order
+///
+/// Follows the rules of the runtime validation, so a template accepted here is
+/// also valid in the generated spec: a `}` without a matching `{`, an unclosed
+/// `{`, nested braces and empty `{}` are rejected.
+pub fn template_placeholders(template: &str) -> Result<Vec<&str>, String> {
+    let mut placeholders = Vec::new();
+    let mut rest = template;
+    loop {
+        match (rest.find('{'), rest.find('}')) {
+            (None, None) => return Ok(placeholders),
+            (open, Some(close)) if open.map_or(true, |open| close < open) => {
+                return Err("'}' without a matching '{'".to_string());
+            }
+            (Some(open), _) => {
+                let after = &rest[open + 1..];
+                let Some(end) = after.find(['{', '}']) else {
+                    return Err("unclosed '{'".to_string());
+                };
+                if after[end..].starts_with('{') {
+                    return Err("placeholders cannot be nested".to_string());
+                }
+                if end == 0 {
+                    return Err("empty placeholder '{}'".to_string());
+                }
+                placeholders.push(&after[..end]);
+                rest = &after[end + 1..];
+            }
+            (None, Some(_)) => unreachable!("handled by the '}}' arm"),
+        }
+    }
+}
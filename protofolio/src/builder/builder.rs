@@ -9,8 +9,8 @@
 //! # Example
 //!
 //! ```rust,no_run
-//! use protofolio::{AsyncApiBuilder, Info, Server, Channel, Message, MessagePayload};
-//! use std::collections::HashMap;
+//! use protofolio::{AsyncApiBuilder, Info, Server, Channel, Message, MessageOrRef, MessagePayload};
+//! use protofolio::IndexMap;
 //!
 //! let spec = AsyncApiBuilder::new()
 //!     .info(Info {
@@ -18,19 +18,32 @@
 //!         version: "1.0.0".to_string(),
 //!         description: Some("API description".to_string()),
 //!         external_docs: None,
+//!         contact: None,
+//!         license: None,
+//!         terms_of_service: None,
+//!         extensions: Default::default(),
 //!     })
 //!     .server("nats".to_string(), Server {
-//!         url: "nats://localhost:4222".to_string(),
+//!         host: "localhost:4222".to_string(),
 //!         protocol: "nats".to_string(),
+//!         protocol_version: None,
+//!         pathname: None,
+//!         title: None,
+//!         summary: None,
 //!         description: None,
+//!         tags: None,
+//!         external_docs: None,
+//!         bindings: None,
 //!         security: None,
+//!         variables: None,
+//!         extensions: Default::default(),
 //!     })
 //!     .channel("events".to_string(), Channel {
 //!         address: "events".to_string(),
 //!         description: None,
 //!         messages: {
-//!             let mut m = HashMap::new();
-//!             m.insert("Event".to_string(), Message {
+//!             let mut m = IndexMap::new();
+//!             m.insert("Event".to_string(), MessageOrRef::message(Message {
 //!                 message_id: None,
 //!                 name: None,
 //!                 title: None,
@@ -45,20 +58,27 @@
 //!                 examples: None,
 //!                 headers: None,
 //!                 correlation_id: None,
-//!             });
+//!                 traits: None,
+//!                 bindings: None,
+//!                 extensions: Default::default(),
+//!             }));
 //!             m
 //!         },
 //!         servers: None,
 //!         parameters: None,
 //!         bindings: None,
+//!         extensions: Default::default(),
 //!     })
 //!     .build();
 //! ```
 
 use crate::error::ValidationError;
+use crate::protocol::{ChannelBindings, MessageBindings, OperationBindings, ServerBindings};
+use crate::schema::definitions;
 use crate::spec::{Tag, *};
 use crate::types::ASYNCAPI_VERSION;
 use crate::validation;
+use indexmap::IndexMap;
 
 /// Builder for AsyncAPI specifications
 ///
@@ -83,12 +103,14 @@ impl AsyncApiBuilder {
                     contact: None,
                     license: None,
                     terms_of_service: None,
+                    extensions: Extensions::new(),
                 },
                 servers: None,
                 channels: Default::default(),
                 operations: None,
                 components: None,
                 tags: None,
+                extensions: Extensions::new(),
             },
         }
     }
@@ -143,7 +165,7 @@ impl AsyncApiBuilder {
         mut self,
         name: String,
         channel: Channel,
-        parameters: std::collections::HashMap<String, Parameter>,
+        parameters: indexmap::IndexMap<String, Parameter>,
     ) -> Self {
         let mut ch = channel;
         ch.parameters = Some(parameters);
@@ -157,7 +179,7 @@ impl AsyncApiBuilder {
         mut self,
         name: String,
         channel: Channel,
-        bindings: serde_json::Value,
+        bindings: ChannelBindings,
     ) -> Self {
         use crate::spec::ChannelBindingsOrRef;
         let mut ch = channel;
@@ -222,6 +244,23 @@ impl AsyncApiBuilder {
         self
     }
 
+    /// Add a root-level specification extension (the name must start with `x-`)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` does not start with `x-`. Such a key would clash with
+    /// the spec's own fields when serialized.
+    #[must_use]
+    pub fn extension(mut self, name: String, value: serde_json::Value) -> Self {
+        assert!(
+            name.starts_with("x-"),
+            "Extension '{}' must start with 'x-'",
+            name
+        );
+        self.spec.extensions.insert(name, value);
+        self
+    }
+
     /// Add a message reference to a channel (references a component message)
     #[must_use]
     pub fn channel_message_ref(
@@ -230,14 +269,12 @@ impl AsyncApiBuilder {
         message_name: String,
         component_name: String,
     ) -> Self {
-        use crate::spec::{MessageOrRef, MessageReference};
+        use crate::spec::MessageOrRef;
 
         if let Some(channel) = self.spec.channels.get_mut(&channel_name) {
-            let ref_path = format!("#/components/messages/{}", component_name);
-            channel.messages.insert(
-                message_name,
-                MessageOrRef::Ref(MessageReference { ref_path }),
-            );
+            channel
+                .messages
+                .insert(message_name, MessageOrRef::component_ref(&component_name));
         }
         self
     }
@@ -261,7 +298,7 @@ impl AsyncApiBuilder {
 
     /// Add a component channel bindings
     #[must_use]
-    pub fn component_channel_bindings(mut self, name: String, bindings: serde_json::Value) -> Self {
+    pub fn component_channel_bindings(mut self, name: String, bindings: ChannelBindings) -> Self {
         if self.spec.components.is_none() {
             self.spec.components = Some(Components::default());
         }
@@ -270,7 +307,7 @@ impl AsyncApiBuilder {
                 components.channel_bindings = Some(Default::default());
             }
             if let Some(ref mut channel_bindings) = components.channel_bindings {
-                channel_bindings.insert(name, bindings);
+                channel_bindings.insert(name, ChannelBindingsOrRef::bindings(bindings));
             }
         }
         self
@@ -278,7 +315,7 @@ impl AsyncApiBuilder {
 
     /// Add a component message bindings
     #[must_use]
-    pub fn component_message_bindings(mut self, name: String, bindings: serde_json::Value) -> Self {
+    pub fn component_message_bindings(mut self, name: String, bindings: MessageBindings) -> Self {
         if self.spec.components.is_none() {
             self.spec.components = Some(Components::default());
         }
@@ -287,7 +324,28 @@ impl AsyncApiBuilder {
                 components.message_bindings = Some(Default::default());
             }
             if let Some(ref mut message_bindings) = components.message_bindings {
-                message_bindings.insert(name, bindings);
+                message_bindings.insert(name, MessageBindingsOrRef::bindings(bindings));
+            }
+        }
+        self
+    }
+
+    /// Add a component operation bindings
+    #[must_use]
+    pub fn component_operation_bindings(
+        mut self,
+        name: String,
+        bindings: OperationBindings,
+    ) -> Self {
+        if self.spec.components.is_none() {
+            self.spec.components = Some(Components::default());
+        }
+        if let Some(ref mut components) = self.spec.components {
+            if components.operation_bindings.is_none() {
+                components.operation_bindings = Some(IndexMap::new());
+            }
+            if let Some(ref mut operation_bindings) = components.operation_bindings {
+                operation_bindings.insert(name, OperationBindingsOrRef::bindings(bindings));
             }
         }
         self
@@ -295,7 +353,7 @@ impl AsyncApiBuilder {
 
     /// Add a component server bindings
     #[must_use]
-    pub fn component_server_bindings(mut self, name: String, bindings: serde_json::Value) -> Self {
+    pub fn component_server_bindings(mut self, name: String, bindings: ServerBindings) -> Self {
         if self.spec.components.is_none() {
             self.spec.components = Some(Components::default());
         }
@@ -304,7 +362,7 @@ impl AsyncApiBuilder {
                 components.server_bindings = Some(Default::default());
             }
             if let Some(ref mut server_bindings) = components.server_bindings {
-                server_bindings.insert(name, bindings);
+                server_bindings.insert(name, ServerBindingsOrRef::bindings(bindings));
             }
         }
         self
@@ -352,9 +410,51 @@ impl AsyncApiBuilder {
         self
     }
 
+    /// Add a component operation reply
+    #[must_use]
+    pub fn component_reply(mut self, name: String, reply: crate::spec::OperationReply) -> Self {
+        if self.spec.components.is_none() {
+            self.spec.components = Some(Components::default());
+        }
+        if let Some(ref mut components) = self.spec.components {
+            if components.replies.is_none() {
+                components.replies = Some(IndexMap::new());
+            }
+            if let Some(ref mut replies) = components.replies {
+                replies.insert(name, reply);
+            }
+        }
+        self
+    }
+
+    /// Add a component operation reply address
+    #[must_use]
+    pub fn component_reply_address(
+        mut self,
+        name: String,
+        address: crate::spec::OperationReplyAddress,
+    ) -> Self {
+        if self.spec.components.is_none() {
+            self.spec.components = Some(Components::default());
+        }
+        if let Some(ref mut components) = self.spec.components {
+            if components.reply_addresses.is_none() {
+                components.reply_addresses = Some(IndexMap::new());
+            }
+            if let Some(ref mut reply_addresses) = components.reply_addresses {
+                reply_addresses.insert(name, address);
+            }
+        }
+        self
+    }
+
     /// Build the final specification
+    ///
+    /// Subschema definitions (`$defs`) of message payloads and headers are
+    /// hoisted into `components.schemas`, and their `$ref`s rewritten to match.
     #[must_use]
-    pub fn build(self) -> AsyncApiSpec {
+    pub fn build(mut self) -> AsyncApiSpec {
+        definitions::hoist_spec_definitions(&mut self.spec);
         self.spec
     }
 
@@ -396,6 +496,40 @@ impl AsyncApiBuilder {
         let bindings = MqttProtocol::channel_binding(topic, qos, retain);
         self.channel_with_bindings(name, channel, bindings)
     }
+
+    /// Add an AMQP channel with bindings
+    #[cfg(feature = "amqp")]
+    #[must_use]
+    pub fn amqp_channel(
+        self,
+        name: String,
+        channel: Channel,
+        is: crate::protocol::AmqpChannelIs,
+        exchange: Option<crate::protocol::AmqpExchange>,
+        queue: Option<crate::protocol::AmqpQueue>,
+    ) -> Self {
+        use crate::protocol::AmqpProtocol;
+        let bindings = AmqpProtocol::channel_binding(is, exchange, queue);
+        self.channel_with_bindings(name, channel, bindings)
+    }
+
+    /// Add a WebSocket channel with bindings
+    ///
+    /// `query` and `headers` are object schemas, e.g. from [`schema_for_type`](crate::schema_for_type).
+    #[cfg(feature = "ws")]
+    #[must_use]
+    pub fn ws_channel(
+        self,
+        name: String,
+        channel: Channel,
+        method: Option<crate::protocol::WsMethod>,
+        query: Option<serde_json::Value>,
+        headers: Option<serde_json::Value>,
+    ) -> Self {
+        use crate::protocol::WsProtocol;
+        let bindings = WsProtocol::channel_binding(method, query, headers);
+        self.channel_with_bindings(name, channel, bindings)
+    }
 }
 
 impl Default for AsyncApiBuilder {
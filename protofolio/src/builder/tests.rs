@@ -3,7 +3,7 @@ mod tests {
     use crate::builder::AsyncApiBuilder;
     use crate::spec::{Channel, Info, Parameter, Server, ServerVariable};
     use crate::types::ASYNCAPI_VERSION;
-    use std::collections::HashMap;
+    use indexmap::IndexMap;
 
     #[test]
     fn test_builder_new() {
@@ -25,6 +25,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .build();
 
@@ -44,15 +45,24 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .server(
                 "nats".to_string(),
                 Server {
-                    url: "nats://localhost:4222".to_string(),
+                    host: "localhost:4222".to_string(),
                     protocol: "nats".to_string(),
+                    protocol_version: None,
+                    pathname: None,
+                    title: None,
+                    summary: None,
                     description: None,
+                    tags: None,
+                    external_docs: None,
+                    bindings: None,
                     security: None,
                     variables: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -60,12 +70,12 @@ mod tests {
         assert!(spec.servers.is_some());
         let servers = spec.servers.unwrap();
         assert!(servers.contains_key("nats"));
-        assert_eq!(servers["nats"].url, "nats://localhost:4222");
+        assert_eq!(servers["nats"].host, "localhost:4222");
     }
 
     #[test]
     fn test_builder_server_with_variables() {
-        let mut variables = HashMap::new();
+        let mut variables = IndexMap::new();
         variables.insert(
             "host".to_string(),
             ServerVariable {
@@ -73,6 +83,7 @@ mod tests {
                 description: Some("Server hostname".to_string()),
                 enum_values: None,
                 examples: None,
+                extensions: Default::default(),
             },
         );
         variables.insert(
@@ -86,6 +97,7 @@ mod tests {
                     "4224".to_string(),
                 ]),
                 examples: Some(vec!["4222".to_string()]),
+                extensions: Default::default(),
             },
         );
 
@@ -98,15 +110,24 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .server(
                 "nats".to_string(),
                 Server {
-                    url: "nats://{host}:{port}".to_string(),
+                    host: "{host}:{port}".to_string(),
                     protocol: "nats".to_string(),
+                    protocol_version: None,
+                    pathname: None,
+                    title: None,
+                    summary: None,
                     description: None,
+                    tags: None,
+                    external_docs: None,
+                    bindings: None,
                     security: None,
                     variables: Some(variables),
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -115,7 +136,7 @@ mod tests {
         let servers = spec.servers.unwrap();
         assert!(servers.contains_key("nats"));
         let server = &servers["nats"];
-        assert_eq!(server.url, "nats://{host}:{port}");
+        assert_eq!(server.host, "{host}:{port}");
         assert!(server.variables.is_some());
         let vars = server.variables.as_ref().unwrap();
         assert!(vars.contains_key("host"));
@@ -143,16 +164,18 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
                 Channel {
                     address: "test.channel".to_string(),
                     description: Some("Test channel".to_string()),
-                    messages: HashMap::new(),
+                    messages: IndexMap::new(),
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -166,13 +189,17 @@ mod tests {
 
     #[test]
     fn test_builder_channel_with_params() {
-        let mut params = HashMap::new();
+        let mut params = IndexMap::new();
         params.insert(
             "tripId".to_string(),
             Parameter {
                 description: Some("Trip ID".to_string()),
+                enum_values: None,
+                default: None,
+                examples: None,
                 schema: Some(serde_json::json!({"type": "string"})),
                 location: None,
+                extensions: Default::default(),
             },
         );
 
@@ -185,16 +212,18 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel_with_params(
                 "trip.{tripId}".to_string(),
                 Channel {
                     address: "trip.{tripId}".to_string(),
                     description: None,
-                    messages: HashMap::new(),
+                    messages: IndexMap::new(),
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
                 params.clone(),
             )
@@ -208,11 +237,13 @@ mod tests {
 
     #[test]
     fn test_builder_channel_with_bindings() {
-        let bindings = serde_json::json!({
-            "nats": {
-                "queue": "workers"
-            }
-        });
+        let bindings = crate::protocol::ChannelBindings {
+            nats: Some(crate::protocol::NatsChannelConfig {
+                queue: Some("workers".to_string()),
+                binding_version: None,
+            }),
+            ..Default::default()
+        };
 
         let spec = AsyncApiBuilder::new()
             .info(Info {
@@ -223,16 +254,18 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel_with_bindings(
                 "test.channel".to_string(),
                 Channel {
                     address: "test.channel".to_string(),
                     description: None,
-                    messages: HashMap::new(),
+                    messages: IndexMap::new(),
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
                 bindings.clone(),
             )
@@ -243,11 +276,123 @@ mod tests {
         let channel_bindings = spec.channels["test.channel"].bindings.as_ref().unwrap();
         match channel_bindings {
             crate::spec::ChannelBindingsOrRef::Bindings(b) => {
-                assert_eq!(b["nats"]["queue"], "workers");
+                assert_eq!(b, &bindings);
             }
-            crate::spec::ChannelBindingsOrRef::Ref(_) => {
-                panic!("Expected bindings, got reference");
+            _ => {
+                panic!("Expected typed bindings");
             }
         }
     }
+
+    #[test]
+    fn test_builder_hoists_schema_definitions() {
+        use crate::spec::{Message, MessageOrRef, MessagePayload};
+        use serde_json::json;
+
+        let message = Message {
+            message_id: None,
+            name: Some("OrderPlaced".to_string()),
+            title: None,
+            summary: None,
+            description: None,
+            content_type: None,
+            tags: None,
+            payload: MessagePayload {
+                schema: json!({
+                    "type": "object",
+                    "properties": {"address": {"$ref": "#/$defs/Address"}},
+                    "$defs": {"Address": {"type": "object"}}
+                }),
+            },
+            external_docs: None,
+            examples: None,
+            headers: None,
+            correlation_id: None,
+            traits: None,
+            bindings: None,
+            extensions: Default::default(),
+        };
+        let mut messages = IndexMap::new();
+        messages.insert("OrderPlaced".to_string(), MessageOrRef::Message(message));
+
+        let spec = AsyncApiBuilder::new()
+            .channel(
+                "orders".to_string(),
+                Channel {
+                    address: "orders".to_string(),
+                    description: None,
+                    messages,
+                    servers: None,
+                    parameters: None,
+                    bindings: None,
+                    extensions: Default::default(),
+                },
+            )
+            .build();
+
+        let MessageOrRef::Message(ref message) = spec.channels["orders"].messages["OrderPlaced"]
+        else {
+            panic!("Expected inline message");
+        };
+        assert!(message.payload.schema.get("$defs").is_none());
+        assert_eq!(
+            message.payload.schema["properties"]["address"]["$ref"],
+            "#/components/schemas/Address"
+        );
+        let schemas = spec.components.unwrap().schemas.unwrap();
+        assert_eq!(schemas["Address"]["type"], "object");
+    }
+
+    #[test]
+    fn test_builder_component_replies() {
+        use crate::spec::OperationReplyAddressOrRef;
+        use crate::spec::{ChannelReference, OperationReply, OperationReplyAddress};
+
+        let spec = AsyncApiBuilder::new()
+            .component_reply_address(
+                "ReplyTo".to_string(),
+                OperationReplyAddress {
+                    description: None,
+                    location: "$message.header#/replyTo".to_string(),
+                    extensions: Default::default(),
+                },
+            )
+            .component_reply(
+                "UserReply".to_string(),
+                OperationReply {
+                    address: Some(OperationReplyAddressOrRef::component_ref("ReplyTo")),
+                    channel: Some(ChannelReference {
+                        ref_path: "#/channels/user.replies".to_string(),
+                    }),
+                    messages: None,
+                    extensions: Default::default(),
+                },
+            )
+            .build();
+
+        let json = serde_json::to_value(spec.components.unwrap()).unwrap();
+        assert_eq!(
+            json["replyAddresses"]["ReplyTo"]["location"],
+            "$message.header#/replyTo"
+        );
+        assert_eq!(
+            json["replies"]["UserReply"]["address"]["$ref"],
+            "#/components/replyAddresses/ReplyTo"
+        );
+    }
+
+    #[test]
+    fn test_builder_extension() {
+        let spec = AsyncApiBuilder::new()
+            .extension("x-owner".to_string(), serde_json::json!("payments"))
+            .build();
+
+        assert_eq!(spec.extensions["x-owner"], "payments");
+    }
+
+    #[test]
+    #[should_panic(expected = "Extension 'channels' must start with 'x-'")]
+    fn test_builder_extension_without_prefix() {
+        let _ = AsyncApiBuilder::new().extension("channels".to_string(), serde_json::json!({}));
+    }
 }
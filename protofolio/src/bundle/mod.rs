@@ -0,0 +1,415 @@
+//! Multi-file specifications
+//!
+//! [`bundle`] loads a specification whose `$ref`s point into other local files
+//! (`$ref: './common/headers.yaml#/Headers'`) and moves every referenced object
+//! into `components`, producing a single self-contained document. [`split`] does
+//! the reverse and writes one file per component.
+
+mod split;
+
+pub use split::split;
+
+use crate::error::{BundleError, RefError};
+use crate::resolve::{local_ref, lookup_ref, parse_ref};
+use crate::spec::AsyncApiSpec;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Format of a specification document on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// JSON document
+    Json,
+    /// YAML document
+    Yaml,
+}
+
+impl DocumentFormat {
+    /// Format of a file, from its extension: `.json` is JSON, anything else YAML
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Yaml,
+        }
+    }
+
+    /// File extension, without the dot
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+        }
+    }
+
+    fn parse(self, content: &str) -> Result<Value, String> {
+        match self {
+            Self::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            Self::Yaml => serde_yaml_ng::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn render(self, document: &Value) -> Result<String, String> {
+        match self {
+            Self::Json => serde_json::to_string_pretty(document).map_err(|e| e.to_string()),
+            Self::Yaml => serde_yaml_ng::to_string(document).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Load a specification file, bundling the local files it references
+///
+/// Objects referenced in other files are added to `components` (a message to
+/// `components.messages`, a schema to `components.schemas`, ...) and the
+/// references are rewritten to point there. References to the same object share
+/// one component, named after the last segment of the reference (or the file
+/// name); a numeric suffix is added if the name is taken. Objects without a
+/// matching components section, such as a whole channel, are inlined.
+///
+/// # Errors
+///
+/// Returns `BundleError` if a file cannot be read or parsed, a reference cannot
+/// be followed, or the bundled document is not a valid specification
+///
+/// # Example
+///
+/// ```rust,no_run
+/// let spec = protofolio::bundle("asyncapi.yaml")?;
+/// std::fs::write("bundled.yaml", protofolio::to_yaml(&spec)?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn bundle(path: impl AsRef<Path>) -> Result<AsyncApiSpec, BundleError> {
+    let path = path.as_ref();
+    let document = read_document(path)?;
+    bundle_document(document, path)
+}
+
+/// Bundle an already parsed specification document read from `path`
+///
+/// `path` locates the files referenced by relative `$ref`s. See [`bundle`].
+///
+/// # Errors
+///
+/// Returns `BundleError` like [`bundle`]
+pub fn bundle_document(document: Value, path: &Path) -> Result<AsyncApiSpec, BundleError> {
+    let root = canonicalize(path)?;
+    let mut bundler = Bundler::new(root.clone(), &document);
+    let mut document = document;
+    bundler.walk(&mut document, &mut Vec::new(), &root)?;
+
+    if let (false, Some(root)) = (bundler.components.is_empty(), document.as_object_mut()) {
+        let components = section_map(root, "components");
+        for (section, name, component) in bundler.components {
+            section_map(components, &section).insert(name, component);
+        }
+    }
+    serde_json::from_value(document).map_err(|e| BundleError::InvalidSpec(e.to_string()))
+}
+
+/// State of a bundling run
+struct Bundler {
+    /// The root document's file
+    root: PathBuf,
+    /// External documents loaded so far
+    documents: HashMap<PathBuf, Value>,
+    /// Local reference of every external object moved into `components`, by file and path
+    imported: HashMap<(PathBuf, Vec<String>), String>,
+    /// Component names in use, by section
+    taken: HashSet<(String, String)>,
+    /// Components to add: (section, name, object)
+    components: Vec<(String, String, Value)>,
+    /// External objects being inlined, to detect cycles
+    inlining: Vec<String>,
+}
+
+impl Bundler {
+    fn new(root: PathBuf, document: &Value) -> Self {
+        let mut bundler = Self {
+            root,
+            documents: HashMap::new(),
+            imported: HashMap::new(),
+            taken: HashSet::new(),
+            components: Vec::new(),
+            inlining: Vec::new(),
+        };
+        let sections = document
+            .get("components")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(section, entries)| Some((section, entries.as_object()?)));
+        for (section, entries) in sections {
+            for (name, entry) in entries {
+                bundler.taken.insert((section.clone(), name.clone()));
+                // A component stored in its own file: other references to that file point here
+                let Some(reference) = entry.get("$ref").and_then(Value::as_str) else {
+                    continue;
+                };
+                if let Ok(key) = external_key(reference, &bundler.root) {
+                    bundler
+                        .imported
+                        .insert(key, local_ref(["components", section, name]));
+                }
+            }
+        }
+        bundler
+    }
+
+    /// Replace the external references in `value`, located at `path` in the root
+    /// document and read from the file `base`
+    fn walk(
+        &mut self,
+        value: &mut Value,
+        path: &mut Vec<String>,
+        base: &Path,
+    ) -> Result<(), BundleError> {
+        if let Some(reference) = value.get("$ref").and_then(Value::as_str) {
+            let reference = reference.to_string();
+            return self.replace_ref(value, &reference, path, base);
+        }
+        match value {
+            Value::Object(object) => {
+                for (key, child) in object.iter_mut() {
+                    path.push(key.clone());
+                    let result = self.walk(child, path, base);
+                    path.pop();
+                    result?;
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    path.push(index.to_string());
+                    let result = self.walk(item, path, base);
+                    path.pop();
+                    result?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn replace_ref(
+        &mut self,
+        value: &mut Value,
+        reference: &str,
+        path: &mut Vec<String>,
+        base: &Path,
+    ) -> Result<(), BundleError> {
+        // References inside the root document stay as they are
+        if reference.starts_with('#') && base == self.root {
+            return Ok(());
+        }
+        let (file, segments) = external_key(reference, base)?;
+        if file == self.root {
+            set_ref(value, local_ref(segments.iter().map(String::as_str)));
+            return Ok(());
+        }
+
+        let is_component =
+            matches!(path.as_slice(), [components, _, _] if components == "components");
+        if !is_component {
+            if let Some(local) = self.imported_ref(&file, &segments) {
+                set_ref(value, local);
+                return Ok(());
+            }
+        }
+
+        let target = self.load(&file, reference, &segments)?;
+        let section = component_section(&path.iter().map(String::as_str).collect::<Vec<_>>());
+        if let Some(section) = section.filter(|_| !is_component) {
+            let name = self.component_name(section, &file, &segments);
+            let local = local_ref(["components", section, &name]);
+            self.imported
+                .insert((file.clone(), segments), local.clone());
+
+            let mut target = target;
+            let mut component_path =
+                vec!["components".to_string(), section.to_string(), name.clone()];
+            self.walk(&mut target, &mut component_path, &file)?;
+            self.components.push((section.to_string(), name, target));
+            set_ref(value, local);
+        } else {
+            let id = format!(
+                "{}#{}",
+                file.display(),
+                crate::validation::json_pointer(segments.iter().map(String::as_str))
+            );
+            if self.inlining.contains(&id) {
+                let mut cycle = self.inlining.clone();
+                cycle.push(id);
+                return Err(BundleError::Ref {
+                    reference: reference.to_string(),
+                    path: base.to_path_buf(),
+                    error: RefError::Cycle(cycle),
+                });
+            }
+            let mut target = target;
+            self.inlining.push(id);
+            self.walk(&mut target, path, &file)?;
+            self.inlining.pop();
+            if let (Value::Object(object), Value::Object(target)) = (&*value, &mut target) {
+                for (key, sibling) in object.iter().filter(|(key, _)| *key != "$ref") {
+                    target.insert(key.clone(), sibling.clone());
+                }
+            }
+            *value = target;
+        }
+        Ok(())
+    }
+
+    /// Local reference of an object already moved into `components`, or inside one
+    fn imported_ref(&self, file: &Path, segments: &[String]) -> Option<String> {
+        if let Some(local) = self.imported.get(&(file.to_path_buf(), segments.to_vec())) {
+            return Some(local.clone());
+        }
+        let whole_file = self.imported.get(&(file.to_path_buf(), Vec::new()))?;
+        Some(format!(
+            "{}{}",
+            whole_file,
+            crate::validation::json_pointer(segments.iter().map(String::as_str))
+        ))
+    }
+
+    /// Copy of the object at `segments` in `file`
+    fn load(
+        &mut self,
+        file: &Path,
+        reference: &str,
+        segments: &[String],
+    ) -> Result<Value, BundleError> {
+        if !self.documents.contains_key(file) {
+            let document = read_document(file)?;
+            self.documents.insert(file.to_path_buf(), document);
+        }
+        let pointer = local_ref(segments.iter().map(String::as_str));
+        lookup_ref(&self.documents[file], &pointer)
+            .cloned()
+            .map_err(|_| BundleError::Ref {
+                reference: reference.to_string(),
+                path: file.to_path_buf(),
+                error: RefError::NotFound(pointer),
+            })
+    }
+
+    /// Unused component name for an object: the last path segment, or the file name
+    fn component_name(&mut self, section: &str, file: &Path, segments: &[String]) -> String {
+        let base = segments.last().cloned().unwrap_or_else(|| {
+            file.file_stem().map_or_else(
+                || section.to_string(),
+                |stem| stem.to_string_lossy().into_owned(),
+            )
+        });
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while self.taken.contains(&(section.to_string(), name.clone())) {
+            name = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        self.taken.insert((section.to_string(), name.clone()));
+        name
+    }
+}
+
+/// Components section an object referenced at `path` belongs in
+///
+/// `None` for objects without one (e.g. a channel), which are inlined.
+fn component_section(path: &[&str]) -> Option<&'static str> {
+    match path {
+        ["channels", _, "messages", _] => Some("messages"),
+        ["channels", _, "parameters", _] => Some("parameters"),
+        ["channels", _, "bindings"] => Some("channelBindings"),
+        ["servers", _, "bindings"] => Some("serverBindings"),
+        ["channels", _, "parameters", _, "schema", ..]
+        | ["components", "parameters", _, "schema", ..]
+        | ["components", "schemas", ..] => Some("schemas"),
+        ["channels", _, "messages", _, rest @ ..]
+        | ["components", "messages" | "messageTraits", _, rest @ ..] => message_section(rest),
+        ["operations", _, rest @ ..] | ["components", "operationTraits", _, rest @ ..] => {
+            operation_section(rest)
+        }
+        ["components", "replies", _, "address"] => Some("replyAddresses"),
+        _ => None,
+    }
+}
+
+/// Components section of an object referenced inside a message or message trait
+fn message_section(path: &[&str]) -> Option<&'static str> {
+    match path {
+        ["payload" | "headers", ..] => Some("schemas"),
+        ["traits", _] => Some("messageTraits"),
+        ["bindings"] => Some("messageBindings"),
+        _ => None,
+    }
+}
+
+/// Components section of an object referenced inside an operation or operation trait
+fn operation_section(path: &[&str]) -> Option<&'static str> {
+    match path {
+        ["traits", _] => Some("operationTraits"),
+        ["bindings"] => Some("operationBindings"),
+        ["reply"] => Some("replies"),
+        ["reply", "address"] => Some("replyAddresses"),
+        _ => None,
+    }
+}
+
+fn set_ref(value: &mut Value, reference: String) {
+    if let Some(object) = value.as_object_mut() {
+        object.insert("$ref".to_string(), Value::String(reference));
+    }
+}
+
+/// The object at `key` of `object`, created if missing
+fn section_map<'a>(object: &'a mut Map<String, Value>, key: &str) -> &'a mut Map<String, Value> {
+    let entry = object
+        .entry(key.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !entry.is_object() {
+        *entry = Value::Object(Map::new());
+    }
+    match entry {
+        Value::Object(map) => map,
+        _ => unreachable!("replaced with an object above"),
+    }
+}
+
+/// File and path segments an external (or external file's local) reference points to
+fn external_key(reference: &str, base: &Path) -> Result<(PathBuf, Vec<String>), BundleError> {
+    let (file, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+    if file.contains("://") {
+        return Err(BundleError::Remote(reference.to_string()));
+    }
+    let file = if file.is_empty() {
+        base.to_path_buf()
+    } else {
+        canonicalize(&base.parent().unwrap_or_else(|| Path::new("")).join(file))?
+    };
+    let segments = parse_ref(&format!("#{}", fragment)).map_err(|error| BundleError::Ref {
+        reference: reference.to_string(),
+        path: base.to_path_buf(),
+        error,
+    })?;
+    Ok((file, segments))
+}
+
+fn read_document(path: &Path) -> Result<Value, BundleError> {
+    let content = fs::read_to_string(path).map_err(|e| BundleError::Io {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    DocumentFormat::from_path(path)
+        .parse(&content)
+        .map_err(|message| BundleError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, BundleError> {
+    fs::canonicalize(path).map_err(|e| BundleError::Io {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
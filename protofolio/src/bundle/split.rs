@@ -0,0 +1,142 @@
+//! Splitting a specification into one file per component
+
+use super::DocumentFormat;
+use crate::error::BundleError;
+use crate::resolve::parse_ref;
+use crate::spec::AsyncApiSpec;
+use crate::validation::json_pointer;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Write `spec` to `dir` as a root document plus one file per component
+///
+/// Each component is written to `components/<section>/<name>.<ext>` and replaced
+/// by a `$ref` to that file in the root document `asyncapi.<ext>`. References
+/// inside component files are rewritten relative to the file. [`bundle`](crate::bundle())
+/// reads the result back into a single specification.
+///
+/// Returns the written files, root document first.
+///
+/// # Errors
+///
+/// Returns `BundleError` if the specification cannot be rendered or a file
+/// cannot be written
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use protofolio::DocumentFormat;
+///
+/// let spec = protofolio::bundle("asyncapi.yaml")?;
+/// let files = protofolio::split(&spec, "spec", DocumentFormat::Yaml)?;
+/// # Ok::<(), protofolio::BundleError>(())
+/// ```
+pub fn split(
+    spec: &AsyncApiSpec,
+    dir: impl AsRef<Path>,
+    format: DocumentFormat,
+) -> Result<Vec<PathBuf>, BundleError> {
+    let dir = dir.as_ref();
+    let mut document =
+        serde_json::to_value(spec).map_err(|e| BundleError::InvalidSpec(e.to_string()))?;
+    let ext = format.extension();
+    let root_file = format!("asyncapi.{}", ext);
+
+    // Components moved to their own files: (section, name)
+    let mut split = HashSet::new();
+    if let Some(components) = document.get("components").and_then(Value::as_object) {
+        for (section, entries) in components {
+            if let Some(entries) = entries.as_object() {
+                split.extend(entries.keys().map(|name| (section.clone(), name.clone())));
+            }
+        }
+    }
+
+    let mut files = vec![(root_file.clone(), Value::Null)];
+    if let Some(components) = document
+        .get_mut("components")
+        .and_then(Value::as_object_mut)
+    {
+        for (section, entries) in components.iter_mut() {
+            let Some(entries) = entries.as_object_mut() else {
+                continue;
+            };
+            for (name, component) in entries.iter_mut() {
+                let file = component_file(section, name, ext);
+                let mut content =
+                    std::mem::replace(component, json!({"$ref": format!("./{}", file)}));
+                rewrite_refs(&mut content, &split, ext, &root_file);
+                files.push((file, content));
+            }
+        }
+    }
+    files[0].1 = document;
+
+    let mut written = Vec::with_capacity(files.len());
+    for (file, content) in files {
+        let path = dir.join(file);
+        let rendered = format.render(&content).map_err(BundleError::InvalidSpec)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| io_error(parent, &e))?;
+        }
+        fs::write(&path, rendered).map_err(|e| io_error(&path, &e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// File of a component, relative to the root document
+fn component_file(section: &str, name: &str, ext: &str) -> String {
+    format!(
+        "components/{}/{}.{}",
+        section,
+        name.replace(['/', '\\'], "_"),
+        ext
+    )
+}
+
+/// Point the local references of a component file at the other component files
+/// and the root document
+fn rewrite_refs(value: &mut Value, split: &HashSet<(String, String)>, ext: &str, root_file: &str) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Ok(segments) = parse_ref(reference) {
+                    *reference = match segments.as_slice() {
+                        [components, section, name, rest @ ..]
+                            if components == "components"
+                                && split.contains(&(section.clone(), name.clone())) =>
+                        {
+                            let file = component_file(section, name, ext);
+                            let mut target = format!("../../{}", file);
+                            if !rest.is_empty() {
+                                target.push('#');
+                                target.push_str(&json_pointer(rest.iter().map(String::as_str)));
+                            }
+                            target
+                        }
+                        _ => format!("../../{}{}", root_file, reference),
+                    };
+                }
+            }
+            for child in object.values_mut() {
+                rewrite_refs(child, split, ext, root_file);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_refs(item, split, ext, root_file);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn io_error(path: &Path, error: &std::io::Error) -> BundleError {
+    BundleError::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    }
+}
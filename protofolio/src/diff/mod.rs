@@ -0,0 +1,672 @@
+//! Breaking-change detection between two versions of a specification
+//!
+//! [`diff`](crate::diff()) compares an old and a new [`AsyncApiSpec`] and
+//! classifies every change as breaking or non-breaking, so incompatible event
+//! schema changes can be blocked before they ship.
+
+mod schema;
+
+use crate::resolve::ref_segments;
+use crate::spec::{AsyncApiSpec, Message, MessageOrRef};
+use crate::types::OperationAction;
+use crate::validation::json_pointer;
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+use std::fmt;
+
+use schema::SchemaDiff;
+
+/// Whether a change breaks existing clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Existing producers or consumers may stop working
+    Breaking,
+    /// Existing producers and consumers keep working
+    NonBreaking,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Breaking => f.write_str("breaking"),
+            Self::NonBreaking => f.write_str("non-breaking"),
+        }
+    }
+}
+
+/// A single difference between two specifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Whether the change is breaking
+    pub kind: ChangeKind,
+
+    /// JSON Pointer to the changed node (into the old spec for removals)
+    pub pointer: String,
+
+    /// What changed
+    pub description: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.kind, self.pointer, self.description)
+    }
+}
+
+/// Every change between two specifications
+///
+/// Returned by [`diff`](crate::diff()). Changes are kept in the order they were
+/// found: servers, channels, operations, then component messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecDiff {
+    changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    /// All changes, in the order they were found
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Changes with [`ChangeKind::Breaking`]
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ChangeKind::Breaking)
+    }
+
+    /// Changes with [`ChangeKind::NonBreaking`]
+    pub fn non_breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ChangeKind::NonBreaking)
+    }
+
+    /// Whether any change is breaking
+    pub fn has_breaking_changes(&self) -> bool {
+        self.breaking().next().is_some()
+    }
+
+    /// Whether the specifications are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, kind: ChangeKind, pointer: String, description: String) {
+        // A schema shared by several messages is reached once per message; keep
+        // one entry, breaking if it breaks any of them
+        if let Some(existing) = self
+            .changes
+            .iter_mut()
+            .find(|change| change.pointer == pointer && change.description == description)
+        {
+            if kind == ChangeKind::Breaking {
+                existing.kind = kind;
+            }
+            return;
+        }
+        self.changes.push(Change {
+            kind,
+            pointer,
+            description,
+        });
+    }
+
+    fn breaking_change(&mut self, pointer: String, description: String) {
+        self.push(ChangeKind::Breaking, pointer, description);
+    }
+
+    fn non_breaking_change(&mut self, pointer: String, description: String) {
+        self.push(ChangeKind::NonBreaking, pointer, description);
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        write!(
+            f,
+            "{} breaking change(s), {} non-breaking change(s)",
+            self.breaking().count(),
+            self.non_breaking().count()
+        )
+    }
+}
+
+/// Who is affected by changes to a message
+#[derive(Debug, Clone, Copy)]
+pub struct Audience {
+    /// The application sends the message: others consume it
+    pub(crate) consumers: bool,
+    /// The application receives the message: others produce it
+    pub(crate) producers: bool,
+}
+
+impl Audience {
+    /// Derive the audience from the operations (of either spec) referencing any of `refs`
+    fn of(refs: &[String], old: &AsyncApiSpec, new: &AsyncApiSpec) -> Self {
+        let mut audience = Self {
+            consumers: false,
+            producers: false,
+        };
+        let operations = [old, new]
+            .into_iter()
+            .filter_map(|spec| spec.operations.as_ref())
+            .flat_map(|operations| operations.values());
+        for operation in operations {
+            if !operation
+                .messages
+                .iter()
+                .any(|message| refs.contains(&message.ref_path))
+            {
+                continue;
+            }
+            match OperationAction::try_from(operation.action.as_str()) {
+                Ok(OperationAction::Send) => audience.consumers = true,
+                Ok(OperationAction::Receive) => audience.producers = true,
+                Err(_) => {}
+            }
+        }
+        if !audience.consumers && !audience.producers {
+            // Unknown direction: assume the worst
+            audience.consumers = true;
+            audience.producers = true;
+        }
+        audience
+    }
+}
+
+/// Compare two versions of a specification
+///
+/// Breaking changes are:
+///
+/// - Removed servers, channels, operations and messages
+/// - Changed server protocols, channel addresses, operation actions and channels
+/// - Changed message IDs and content types
+/// - Payload and header schema changes that break the other side of a message:
+///   - messages the application **sends** break their consumers when a required
+///     property is removed or a type, enum or variant list is widened
+///   - messages the application **receives** break their producers when a
+///     property becomes required or a type, enum or variant list is narrowed
+///
+/// The direction of a message is taken from the operations referencing it. A
+/// message without operations is treated as both sent and received.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use protofolio::{AsyncApi, diff};
+/// # use protofolio_derive::AsyncApi;
+/// #
+/// # #[derive(AsyncApi)]
+/// # #[asyncapi(info(title = "Test", version = "1.0.0"), channels("events"), messages())]
+/// # struct MyApi;
+/// # let published = MyApi::asyncapi();
+/// let changes = diff(&published, &MyApi::asyncapi());
+/// if changes.has_breaking_changes() {
+///     eprintln!("{}", changes);
+/// }
+/// ```
+pub fn diff(old: &AsyncApiSpec, new: &AsyncApiSpec) -> SpecDiff {
+    let mut diff = SpecDiff::default();
+    diff_servers(old, new, &mut diff);
+    diff_channels(old, new, &mut diff);
+    diff_operations(old, new, &mut diff);
+    diff_component_messages(old, new, &mut diff);
+    diff
+}
+
+fn diff_servers(old: &AsyncApiSpec, new: &AsyncApiSpec, diff: &mut SpecDiff) {
+    let empty = IndexMap::default();
+    let old_servers = old.servers.as_ref().unwrap_or(&empty);
+    let new_servers = new.servers.as_ref().unwrap_or(&empty);
+    for (name, old_server, new_server) in
+        compare_maps("Server", &["servers"], old_servers, new_servers, diff)
+    {
+        if old_server.protocol != new_server.protocol {
+            diff.breaking_change(
+                json_pointer(["servers", name, "protocol"]),
+                format!(
+                    "Server '{}' protocol changed from '{}' to '{}'",
+                    name, old_server.protocol, new_server.protocol
+                ),
+            );
+        }
+    }
+}
+
+fn diff_channels(old: &AsyncApiSpec, new: &AsyncApiSpec, diff: &mut SpecDiff) {
+    for (name, old_channel, new_channel) in
+        compare_maps("Channel", &["channels"], &old.channels, &new.channels, diff)
+    {
+        if old_channel.address != new_channel.address {
+            diff.breaking_change(
+                json_pointer(["channels", name, "address"]),
+                format!(
+                    "Channel '{}' address changed from '{}' to '{}'",
+                    name, old_channel.address, new_channel.address
+                ),
+            );
+        }
+
+        for (message_name, old_message, new_message) in compare_maps(
+            "Message",
+            &["channels", name, "messages"],
+            &old_channel.messages,
+            &new_channel.messages,
+            diff,
+        ) {
+            // Unchanged component refs are compared with the other components
+            if let (MessageOrRef::Ref(old_ref), MessageOrRef::Ref(new_ref)) =
+                (old_message, new_message)
+            {
+                if old_ref.ref_path == new_ref.ref_path {
+                    continue;
+                }
+            }
+            let (Some(old_message), Some(new_message)) = (
+                resolve_message(old, old_message),
+                resolve_message(new, new_message),
+            ) else {
+                continue;
+            };
+            let pointer = json_pointer(["channels", name, "messages", message_name]);
+            let audience = Audience::of(&[format!("#{}", pointer)], old, new);
+            diff_messages(
+                old,
+                new,
+                message_name,
+                &pointer,
+                (old_message, new_message),
+                audience,
+                diff,
+            );
+        }
+    }
+}
+
+fn diff_operations(old: &AsyncApiSpec, new: &AsyncApiSpec, diff: &mut SpecDiff) {
+    let empty = IndexMap::default();
+    let old_operations = old.operations.as_ref().unwrap_or(&empty);
+    let new_operations = new.operations.as_ref().unwrap_or(&empty);
+    for (id, old_operation, new_operation) in compare_maps(
+        "Operation",
+        &["operations"],
+        old_operations,
+        new_operations,
+        diff,
+    ) {
+        if old_operation.action != new_operation.action {
+            diff.breaking_change(
+                json_pointer(["operations", id, "action"]),
+                format!(
+                    "Operation '{}' action changed from '{}' to '{}'",
+                    id, old_operation.action, new_operation.action
+                ),
+            );
+        }
+        if old_operation.channel.ref_path != new_operation.channel.ref_path {
+            diff.breaking_change(
+                json_pointer(["operations", id, "channel"]),
+                format!(
+                    "Operation '{}' channel changed from '{}' to '{}'",
+                    id, old_operation.channel.ref_path, new_operation.channel.ref_path
+                ),
+            );
+        }
+
+        let messages_pointer = json_pointer(["operations", id, "messages"]);
+        let old_refs: Vec<&str> = old_operation
+            .messages
+            .iter()
+            .map(|message| message.ref_path.as_str())
+            .collect();
+        let new_refs: Vec<&str> = new_operation
+            .messages
+            .iter()
+            .map(|message| message.ref_path.as_str())
+            .collect();
+        for removed in old_refs.iter().filter(|r| !new_refs.contains(r)) {
+            diff.breaking_change(
+                messages_pointer.clone(),
+                format!("Operation '{}' no longer uses message '{}'", id, removed),
+            );
+        }
+        for added in new_refs.iter().filter(|r| !old_refs.contains(r)) {
+            diff.non_breaking_change(
+                messages_pointer.clone(),
+                format!("Operation '{}' now also uses message '{}'", id, added),
+            );
+        }
+    }
+}
+
+fn diff_component_messages(old: &AsyncApiSpec, new: &AsyncApiSpec, diff: &mut SpecDiff) {
+    let empty = IndexMap::default();
+    let old_messages = component_messages(old).unwrap_or(&empty);
+    let new_messages = component_messages(new).unwrap_or(&empty);
+    for (name, old_message, new_message) in compare_maps(
+        "Component message",
+        &["components", "messages"],
+        old_messages,
+        new_messages,
+        diff,
+    ) {
+        let pointer = json_pointer(["components", "messages", name]);
+        let component_ref = format!("#{}", pointer);
+
+        // Operations reference component messages through the channels using them
+        let mut refs = vec![component_ref.clone()];
+        for spec in [old, new] {
+            for (channel_name, channel) in &spec.channels {
+                for (message_name, message) in &channel.messages {
+                    if matches!(message, MessageOrRef::Ref(r) if r.ref_path == component_ref) {
+                        refs.push(format!(
+                            "#{}",
+                            json_pointer(["channels", channel_name, "messages", message_name])
+                        ));
+                    }
+                }
+            }
+        }
+
+        let audience = Audience::of(&refs, old, new);
+        diff_messages(
+            old,
+            new,
+            name,
+            &pointer,
+            (old_message, new_message),
+            audience,
+            diff,
+        );
+    }
+}
+
+fn diff_messages(
+    old_spec: &AsyncApiSpec,
+    new_spec: &AsyncApiSpec,
+    name: &str,
+    pointer: &str,
+    (old, new): (&Message, &Message),
+    audience: Audience,
+    diff: &mut SpecDiff,
+) {
+    if old.message_id != new.message_id {
+        diff.breaking_change(
+            format!("{}/messageId", pointer),
+            format!(
+                "Message '{}' messageId changed from {} to {}",
+                name,
+                quoted(old.message_id.as_deref()),
+                quoted(new.message_id.as_deref())
+            ),
+        );
+    }
+    if old.content_type != new.content_type {
+        diff.breaking_change(
+            format!("{}/contentType", pointer),
+            format!(
+                "Message '{}' contentType changed from {} to {}",
+                name,
+                quoted(old.content_type.as_deref()),
+                quoted(new.content_type.as_deref())
+            ),
+        );
+    }
+
+    SchemaDiff::new(old_spec, new_spec, audience).compare(
+        &old.payload.schema,
+        &new.payload.schema,
+        format!("{}/payload", pointer),
+        diff,
+    );
+
+    // A missing headers schema accepts any headers
+    let any = Value::Object(Map::new());
+    if old.headers.is_some() || new.headers.is_some() {
+        SchemaDiff::new(old_spec, new_spec, audience).compare(
+            old.headers.as_ref().map_or(&any, |headers| &headers.schema),
+            new.headers.as_ref().map_or(&any, |headers| &headers.schema),
+            format!("{}/headers", pointer),
+            diff,
+        );
+    }
+}
+
+/// Report removed and added entries, returning the entries present in both maps
+fn compare_maps<'a, V>(
+    what: &str,
+    segments: &[&str],
+    old: &'a IndexMap<String, V>,
+    new: &'a IndexMap<String, V>,
+    diff: &mut SpecDiff,
+) -> Vec<(&'a str, &'a V, &'a V)> {
+    let pointer = |name: &str| json_pointer(segments.iter().copied().chain([name]));
+    let mut common = Vec::new();
+    for (name, old_value) in old {
+        match new.get(name) {
+            Some(new_value) => common.push((name.as_str(), old_value, new_value)),
+            None => {
+                diff.breaking_change(pointer(name), format!("{} '{}' was removed", what, name));
+            }
+        }
+    }
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        diff.non_breaking_change(pointer(name), format!("{} '{}' was added", what, name));
+    }
+    common
+}
+
+fn component_messages(spec: &AsyncApiSpec) -> Option<&IndexMap<String, Message>> {
+    spec.components
+        .as_ref()
+        .and_then(|components| components.messages.as_ref())
+}
+
+/// Resolve a channel message, following a reference to `components.messages`
+fn resolve_message<'a>(spec: &'a AsyncApiSpec, message: &'a MessageOrRef) -> Option<&'a Message> {
+    match message {
+        MessageOrRef::Message(message) => Some(message),
+        MessageOrRef::Ref(reference) => match ref_segments(&reference.ref_path)?.as_slice() {
+            [components, messages, name]
+                if components == "components" && messages == "messages" =>
+            {
+                component_messages(spec)?.get(name)
+            }
+            _ => None,
+        },
+    }
+}
+
+fn quoted(value: Option<&str>) -> String {
+    value.map_or_else(|| "none".to_string(), |value| format!("'{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use serde_json::json;
+
+    /// A spec with one channel carrying `payload`, sent or received by one operation
+    fn user_spec(action: &str, payload: &Value) -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "servers": {"production": {"host": "nats.example.com", "protocol": "nats"}},
+            "channels": {
+                "users": {
+                    "address": "users",
+                    "messages": {"UserCreated": {"messageId": "user-created-v1", "payload": payload}}
+                }
+            },
+            "operations": {
+                "userCreated": {
+                    "operationId": "userCreated",
+                    "action": action,
+                    "channel": {"$ref": "#/channels/users"},
+                    "messages": [{"$ref": "#/channels/users/messages/UserCreated"}]
+                }
+            }
+        }))
+    }
+
+    fn user_payload() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "email": {"type": "string"},
+                "nickname": {"type": ["string", "null"]}
+            },
+            "required": ["id", "email"]
+        })
+    }
+
+    fn descriptions(diff: &SpecDiff, kind: ChangeKind) -> Vec<String> {
+        diff.changes()
+            .iter()
+            .filter(|change| change.kind == kind)
+            .map(|change| format!("{}: {}", change.pointer, change.description))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_specs() {
+        let spec = user_spec("send", &user_payload());
+        assert!(diff(&spec, &spec).is_empty());
+    }
+
+    #[test]
+    fn test_removed_and_added_entries() {
+        let old = user_spec("send", &user_payload());
+        let mut new = old.clone();
+        new.channels.shift_remove("users");
+        new.operations = None;
+        new.servers.as_mut().unwrap()["production"].protocol = "kafka".to_string();
+        new.channels
+            .insert("orders".to_string(), old.channels["users"].clone());
+
+        let changes = diff(&old, &new);
+        assert!(changes.has_breaking_changes());
+        assert_eq!(
+            descriptions(&changes, ChangeKind::Breaking),
+            vec![
+                "/servers/production/protocol: Server 'production' protocol changed from 'nats' to 'kafka'",
+                "/channels/users: Channel 'users' was removed",
+                "/operations/userCreated: Operation 'userCreated' was removed",
+            ]
+        );
+        assert_eq!(
+            descriptions(&changes, ChangeKind::NonBreaking),
+            vec!["/channels/orders: Channel 'orders' was added"]
+        );
+    }
+
+    #[test]
+    fn test_changed_message_id() {
+        let old = user_spec("send", &user_payload());
+        let mut new = old.clone();
+        if let MessageOrRef::Message(ref mut message) =
+            new.channels["users"].messages["UserCreated"]
+        {
+            message.message_id = Some("user-created-v2".to_string());
+        }
+
+        assert_eq!(
+            descriptions(&diff(&old, &new), ChangeKind::Breaking),
+            vec!["/channels/users/messages/UserCreated/messageId: Message 'UserCreated' messageId changed from 'user-created-v1' to 'user-created-v2'"]
+        );
+    }
+
+    #[test]
+    fn test_sent_message_payload_changes() {
+        let old = user_spec("send", &user_payload());
+        let new = user_spec(
+            "send",
+            &json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "number"},
+                    "nickname": {"type": "string"},
+                    "name": {"type": "string"}
+                },
+                "required": ["id", "name"]
+            }),
+        );
+
+        let changes = diff(&old, &new);
+        let payload = "/channels/users/messages/UserCreated/payload";
+        assert_eq!(
+            descriptions(&changes, ChangeKind::Breaking),
+            vec![
+                format!("{}: Required property 'email' was removed", payload),
+                format!(
+                    "{}/properties/id: Type widened from `integer` to `number`",
+                    payload
+                ),
+            ]
+        );
+        assert_eq!(
+            descriptions(&changes, ChangeKind::NonBreaking),
+            vec![
+                format!("{}: Required property 'name' was added", payload),
+                format!(
+                    "{}/properties/nickname: Type narrowed from `null | string` to `string`",
+                    payload
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_received_message_payload_changes() {
+        let old = user_spec("receive", &user_payload());
+        let mut payload = user_payload();
+        payload["required"] = json!(["id", "email", "nickname"]);
+        payload["properties"]["nickname"]["type"] = json!("string");
+        let new = user_spec("receive", &payload);
+
+        let pointer = "/channels/users/messages/UserCreated/payload";
+        assert_eq!(
+            descriptions(&diff(&old, &new), ChangeKind::Breaking),
+            vec![
+                format!("{}: Property 'nickname' is now required", pointer),
+                format!(
+                    "{}/properties/nickname: Type narrowed from `null | string` to `string`",
+                    pointer
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_component_schema_changes_follow_refs() {
+        let old_payload = json!({"$ref": "#/components/schemas/User"});
+        let mut old = user_spec("send", &old_payload);
+        old.components =
+            Some(serde_json::from_value(json!({"schemas": {"User": user_payload()}})).unwrap());
+        let mut new = old.clone();
+        new.components.as_mut().unwrap().schemas.as_mut().unwrap()["User"]["properties"]["email"]
+            ["enum"] = json!(["a@example.com"]);
+
+        let changes = diff(&old, &new);
+        assert!(!changes.has_breaking_changes());
+        assert_eq!(
+            descriptions(&changes, ChangeKind::NonBreaking),
+            vec!["/components/schemas/User/properties/email: Values restricted to an enum"]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let old = user_spec("send", &user_payload());
+        let mut new = old.clone();
+        new.channels.shift_remove("users");
+
+        let text = diff(&old, &new).to_string();
+        assert!(text.starts_with("breaking at /channels/users: Channel 'users' was removed\n"));
+        assert!(text.ends_with("1 breaking change(s), 0 non-breaking change(s)"));
+    }
+}
@@ -0,0 +1,329 @@
+//! Compatibility rules for payload and header schemas
+//!
+//! A change either narrows a schema (it accepts fewer values, which breaks
+//! producers) or widens it (it may yield values consumers do not expect). Which
+//! of the two is breaking depends on the [`Audience`] of the message.
+
+use super::{Audience, ChangeKind, SpecDiff};
+use crate::schema::definitions::COMPONENT_SCHEMAS_PREFIX;
+use crate::spec::AsyncApiSpec;
+use crate::validation::json_pointer;
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashSet};
+
+/// Keywords holding alternative subschemas
+const VARIANT_KEYWORDS: [&str; 2] = ["oneOf", "anyOf"];
+
+/// Longest `$ref` chain followed before giving up
+const MAX_REF_DEPTH: usize = 32;
+
+/// Compares an old and a new schema of the same message
+pub(super) struct SchemaDiff<'a> {
+    old_schemas: Option<&'a IndexMap<String, Value>>,
+    new_schemas: Option<&'a IndexMap<String, Value>>,
+    audience: Audience,
+    /// Pairs of component schemas already compared, so recursive schemas terminate
+    visited: HashSet<(&'a str, &'a str)>,
+}
+
+impl<'a> SchemaDiff<'a> {
+    pub(super) fn new(old: &'a AsyncApiSpec, new: &'a AsyncApiSpec, audience: Audience) -> Self {
+        Self {
+            old_schemas: component_schemas(old),
+            new_schemas: component_schemas(new),
+            audience,
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Compare two schemas, reporting changes at `pointer`
+    ///
+    /// When both schemas refer to the same component schema, changes are
+    /// reported at the component (e.g. `/components/schemas/User`) instead.
+    pub(super) fn compare(
+        &mut self,
+        old: &'a Value,
+        new: &'a Value,
+        mut pointer: String,
+        diff: &mut SpecDiff,
+    ) {
+        if let (Some(old_name), Some(new_name)) = (component_ref(old), component_ref(new)) {
+            if !self.visited.insert((old_name, new_name)) {
+                return;
+            }
+            if old_name == new_name {
+                pointer = json_pointer(["components", "schemas", new_name]);
+            }
+        }
+        let old = resolve(self.old_schemas, old);
+        let new = resolve(self.new_schemas, new);
+        if old == new {
+            return;
+        }
+
+        let (Some(old), Some(new)) = (old.as_object(), new.as_object()) else {
+            diff.breaking_change(pointer, "Schema changed".to_string());
+            return;
+        };
+
+        if !self.compare_types(old, new, &pointer, diff) {
+            // Nothing below an incompatible type change is meaningful
+            return;
+        }
+        self.compare_enums(old, new, &pointer, diff);
+        self.compare_properties(old, new, &pointer, diff);
+
+        if let (Some(old_items), Some(new_items)) = (old.get("items"), new.get("items")) {
+            self.compare(old_items, new_items, format!("{}/items", pointer), diff);
+        }
+
+        for keyword in VARIANT_KEYWORDS {
+            let (Some(old_variants), Some(new_variants)) = (
+                old.get(keyword).and_then(Value::as_array),
+                new.get(keyword).and_then(Value::as_array),
+            ) else {
+                continue;
+            };
+            if old_variants.len() == new_variants.len() {
+                for (index, (old_variant, new_variant)) in
+                    old_variants.iter().zip(new_variants).enumerate()
+                {
+                    self.compare(
+                        old_variant,
+                        new_variant,
+                        format!("{}/{}/{}", pointer, keyword, index),
+                        diff,
+                    );
+                }
+            } else {
+                let description = format!(
+                    "Number of `{}` variants changed from {} to {}",
+                    keyword,
+                    old_variants.len(),
+                    new_variants.len()
+                );
+                if new_variants.len() < old_variants.len() {
+                    self.narrowed(pointer.clone(), description, diff);
+                } else {
+                    self.widened(pointer.clone(), description, diff);
+                }
+            }
+        }
+    }
+
+    /// Compare `type`; returns `false` if the types are incompatible
+    fn compare_types(
+        &self,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+        pointer: &str,
+        diff: &mut SpecDiff,
+    ) -> bool {
+        match (types(old), types(new)) {
+            (None, None) => {}
+            (None, Some(new_types)) => self.narrowed(
+                pointer.to_string(),
+                format!("Type restricted to `{}`", format_types(&new_types)),
+                diff,
+            ),
+            (Some(old_types), None) => self.widened(
+                pointer.to_string(),
+                format!("Type `{}` is no longer enforced", format_types(&old_types)),
+                diff,
+            ),
+            (Some(old_types), Some(new_types)) if old_types == new_types => {}
+            (Some(old_types), Some(new_types)) => {
+                let description = |verb: &str| {
+                    format!(
+                        "Type {} from `{}` to `{}`",
+                        verb,
+                        format_types(&old_types),
+                        format_types(&new_types)
+                    )
+                };
+                if is_subset(&new_types, &old_types) {
+                    self.narrowed(pointer.to_string(), description("narrowed"), diff);
+                } else if is_subset(&old_types, &new_types) {
+                    self.widened(pointer.to_string(), description("widened"), diff);
+                } else {
+                    diff.breaking_change(pointer.to_string(), description("changed"));
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn compare_enums(
+        &self,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+        pointer: &str,
+        diff: &mut SpecDiff,
+    ) {
+        match (
+            old.get("enum").and_then(Value::as_array),
+            new.get("enum").and_then(Value::as_array),
+        ) {
+            (Some(old_values), Some(new_values)) => {
+                for removed in old_values.iter().filter(|v| !new_values.contains(v)) {
+                    self.narrowed(
+                        pointer.to_string(),
+                        format!("Enum value {} was removed", removed),
+                        diff,
+                    );
+                }
+                for added in new_values.iter().filter(|v| !old_values.contains(v)) {
+                    self.widened(
+                        pointer.to_string(),
+                        format!("Enum value {} was added", added),
+                        diff,
+                    );
+                }
+            }
+            (None, Some(_)) => self.narrowed(
+                pointer.to_string(),
+                "Values restricted to an enum".to_string(),
+                diff,
+            ),
+            (Some(_), None) => self.widened(
+                pointer.to_string(),
+                "Enum restriction was removed".to_string(),
+                diff,
+            ),
+            (None, None) => {}
+        }
+    }
+
+    /// Compare `required` and `properties`, recursing into properties present in both
+    fn compare_properties(
+        &mut self,
+        old: &'a Map<String, Value>,
+        new: &'a Map<String, Value>,
+        pointer: &str,
+        diff: &mut SpecDiff,
+    ) {
+        let empty = Map::new();
+        let old_properties = old.get("properties").and_then(Value::as_object);
+        let new_properties = new.get("properties").and_then(Value::as_object);
+        let old_required = required(old);
+        let new_required = required(new);
+        let in_old = |name: &str| old_properties.unwrap_or(&empty).contains_key(name);
+        let in_new = |name: &str| new_properties.unwrap_or(&empty).contains_key(name);
+
+        for name in old_required.iter().filter(|n| !new_required.contains(n)) {
+            let description = if in_new(name) {
+                format!("Property '{}' is no longer required", name)
+            } else {
+                format!("Required property '{}' was removed", name)
+            };
+            self.widened(pointer.to_string(), description, diff);
+        }
+        for name in new_required.iter().filter(|n| !old_required.contains(n)) {
+            let description = if in_old(name) {
+                format!("Property '{}' is now required", name)
+            } else {
+                format!("Required property '{}' was added", name)
+            };
+            self.narrowed(pointer.to_string(), description, diff);
+        }
+
+        for (name, old_property) in old_properties.into_iter().flatten() {
+            match new_properties.and_then(|properties| properties.get(name)) {
+                Some(new_property) => self.compare(
+                    old_property,
+                    new_property,
+                    format!("{}{}", pointer, json_pointer(["properties", name])),
+                    diff,
+                ),
+                None if !old_required.contains(&name.as_str()) => diff.non_breaking_change(
+                    pointer.to_string(),
+                    format!("Optional property '{}' was removed", name),
+                ),
+                None => {}
+            }
+        }
+        for name in new_properties.into_iter().flatten().map(|(name, _)| name) {
+            if !in_old(name) && !new_required.contains(&name.as_str()) {
+                diff.non_breaking_change(
+                    pointer.to_string(),
+                    format!("Optional property '{}' was added", name),
+                );
+            }
+        }
+    }
+
+    /// The schema accepts fewer values: breaks producers of the message
+    fn narrowed(&self, pointer: String, description: String, diff: &mut SpecDiff) {
+        let kind = if self.audience.producers {
+            ChangeKind::Breaking
+        } else {
+            ChangeKind::NonBreaking
+        };
+        diff.push(kind, pointer, description);
+    }
+
+    /// The schema accepts more values: breaks consumers of the message
+    fn widened(&self, pointer: String, description: String, diff: &mut SpecDiff) {
+        let kind = if self.audience.consumers {
+            ChangeKind::Breaking
+        } else {
+            ChangeKind::NonBreaking
+        };
+        diff.push(kind, pointer, description);
+    }
+}
+
+fn component_schemas(spec: &AsyncApiSpec) -> Option<&IndexMap<String, Value>> {
+    spec.components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref())
+}
+
+/// Name of the component schema a `{"$ref": "#/components/schemas/Name"}` schema points to
+fn component_ref(schema: &Value) -> Option<&str> {
+    schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix(COMPONENT_SCHEMAS_PREFIX))
+}
+
+/// Follow component schema refs; dangling refs resolve to the ref itself
+fn resolve<'a>(schemas: Option<&'a IndexMap<String, Value>>, mut schema: &'a Value) -> &'a Value {
+    for _ in 0..MAX_REF_DEPTH {
+        match component_ref(schema).and_then(|name| schemas?.get(name)) {
+            Some(target) => schema = target,
+            None => break,
+        }
+    }
+    schema
+}
+
+/// The set of types a schema allows, or `None` if it does not restrict the type
+fn types(schema: &Map<String, Value>) -> Option<BTreeSet<&str>> {
+    match schema.get("type")? {
+        Value::String(name) => Some(BTreeSet::from([name.as_str()])),
+        Value::Array(names) => Some(names.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+/// Whether every type in `types` is accepted by `by` (`number` accepts `integer`)
+fn is_subset(types: &BTreeSet<&str>, by: &BTreeSet<&str>) -> bool {
+    types
+        .iter()
+        .all(|name| by.contains(name) || (*name == "integer" && by.contains("number")))
+}
+
+fn format_types(types: &BTreeSet<&str>) -> String {
+    types.iter().copied().collect::<Vec<_>>().join(" | ")
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
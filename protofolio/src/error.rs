@@ -2,6 +2,10 @@
 //!
 //! This module provides centralized error types used throughout the crate.
 
+use crate::merge::MergeConflict;
+use crate::schema::SchemaViolation;
+use std::fmt;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Error type for schema generation
@@ -18,44 +22,471 @@ impl From<serde_json::Error> for SchemaError {
 }
 
 /// Error type for AsyncAPI specification validation
+///
+/// Displays as the [`message`](Self::message) followed by the [`hint`](Self::hint).
 #[derive(Debug, Error, Clone, PartialEq)]
 pub enum ValidationError {
-    #[error("Missing required field: {0}\n\nHint: Add the missing field to your #[asyncapi] attribute. For info fields, use info(title = \"...\", version = \"...\")")]
     MissingRequiredField(String),
 
-    #[error("Invalid AsyncAPI version: {0}. Expected 3.0.0\n\nHint: This library only supports AsyncAPI 3.0.0. The version field is automatically set and should not be modified")]
     InvalidAsyncApiVersion(String),
 
-    #[error("Channel '{0}' referenced in message but not defined in channels\n\nHint: Add '{0}' to the channels(...) list in your #[asyncapi] attribute")]
     InvalidChannelReference(String),
 
-    #[error("Server '{0}' referenced in channel but not defined in servers\n\nHint: Add the server to servers(...) in your #[asyncapi] attribute, or remove the server reference from the channel")]
     InvalidServerReference(String),
 
-    #[error("Invalid schema: {0}\n\nHint: Ensure all message types implement JsonSchema and have valid schemas")]
+    /// A server with an invalid host or pathname
+    InvalidServer(String),
+
     InvalidSchema(String),
 
-    #[error("Empty channels: specification must have at least one channel\n\nHint: Add at least one channel to channels(...) in your #[asyncapi] attribute")]
     EmptyChannels,
 
-    #[error("Channel '{0}' has no messages\n\nHint: Add at least one message type to messages(...) in your #[asyncapi] attribute that uses this channel")]
     ChannelWithoutMessages(String),
 
-    #[error("Duplicate message ID: {0}\n\nHint: Each message must have a unique messageId. Update one of the conflicting messages to use a different messageId")]
     DuplicateMessageId(String),
 
-    #[error("Unsupported protocol: {protocol}\n\nHint: Supported protocols: {supported:?}. Enable the corresponding feature flag (e.g., `features = [\"nats\"]`) in your Cargo.toml")]
     UnsupportedProtocol {
         protocol: String,
         supported: Vec<String>,
     },
 
-    #[error("Invalid protocol: {0}\n\nHint: Check that the protocol name matches exactly (case-sensitive) and the corresponding feature flag is enabled")]
     InvalidProtocol(String),
 
-    #[error("Schema generation failed for type '{0}': {1}\n\nHint: Ensure the type implements JsonSchema (usually via #[derive(JsonSchema)]) and all nested types also implement JsonSchema")]
     SchemaGenerationFailed(String, String),
 
-    #[error("Message '{message}' not found in channel '{channel}'\n\nHint: Ensure the message type is included in messages(...) in your #[asyncapi] attribute and uses the correct channel")]
-    MessageNotFound { channel: String, message: String },
+    MessageNotFound {
+        channel: String,
+        message: String,
+    },
+
+    /// An operation reply with an unknown channel or message, or an invalid address
+    InvalidOperationReply(String),
+
+    /// An operation that lists no messages
+    OperationWithoutMessages(String),
+
+    /// An operation whose channel does not exist
+    OperationChannelNotFound {
+        /// The operation name
+        operation: String,
+        /// The referenced channel
+        channel: String,
+    },
+
+    /// An operation message reference that points to nothing
+    OperationMessageNotFound {
+        /// The operation name
+        operation: String,
+        /// The message reference as written
+        reference: String,
+    },
+
+    /// An operation message that belongs to a different channel
+    OperationMessageNotInChannel {
+        /// The operation name
+        operation: String,
+        /// The message reference as written
+        message: String,
+        /// The operation's channel
+        channel: String,
+    },
+
+    /// An operation trait reference that points to nothing
+    OperationTraitNotFound {
+        /// The operation name
+        operation: String,
+        /// The trait reference as written
+        reference: String,
+    },
+
+    /// A message trait reference that points to nothing
+    MessageTraitNotFound {
+        /// The message name
+        message: String,
+        /// The trait reference as written
+        reference: String,
+    },
+
+    /// A server security requirement naming an undefined scheme
+    SecuritySchemeNotFound {
+        /// The server name
+        server: String,
+        /// The missing scheme name
+        scheme: String,
+    },
+
+    /// A server security requirement with a scope no OAuth2 flow declares
+    UndeclaredScope {
+        /// The server name
+        server: String,
+        /// The security scheme name
+        scheme: String,
+        /// The undeclared scope
+        scope: String,
+    },
+
+    /// A security scheme without the fields its type requires
+    InvalidSecurityScheme(String),
+
+    /// A channel address with malformed placeholders
+    InvalidChannelAddress(String),
+
+    /// A channel parameter that does not match the address or is inconsistent
+    InvalidChannelParameter(String),
+
+    /// A server variable that does not match the host or pathname, or is inconsistent
+    InvalidServerVariable(String),
+
+    /// A message example that does not match the payload or headers schema
+    InvalidExample {
+        /// The message name
+        message: String,
+        /// JSON Pointer to the offending value within the example
+        path: String,
+        /// The schema violation
+        error: String,
+    },
+
+    /// A specification extension whose name does not start with `x-`
+    InvalidExtension(String),
+}
+
+impl ValidationError {
+    /// What is wrong with the specification, without the hint
+    pub fn message(&self) -> String {
+        match self {
+            Self::MissingRequiredField(field) => format!("Missing required field: {}", field),
+            Self::InvalidAsyncApiVersion(version) => {
+                format!("Invalid AsyncAPI version: {}. Expected 3.0.0", version)
+            }
+            Self::InvalidChannelReference(channel) => format!(
+                "Channel '{}' referenced in message but not defined in channels",
+                channel
+            ),
+            Self::InvalidServerReference(server) => format!(
+                "Server '{}' referenced in channel but not defined in servers",
+                server
+            ),
+            Self::InvalidServer(reason) => format!("Invalid server: {}", reason),
+            Self::InvalidSchema(reason) => format!("Invalid schema: {}", reason),
+            Self::EmptyChannels => {
+                "Empty channels: specification must have at least one channel".to_string()
+            }
+            Self::ChannelWithoutMessages(channel) => {
+                format!("Channel '{}' has no messages", channel)
+            }
+            Self::DuplicateMessageId(id) => format!("Duplicate message ID: {}", id),
+            Self::UnsupportedProtocol { protocol, .. } => {
+                format!("Unsupported protocol: {}", protocol)
+            }
+            Self::InvalidProtocol(protocol) => format!("Invalid protocol: {}", protocol),
+            Self::SchemaGenerationFailed(type_name, reason) => format!(
+                "Schema generation failed for type '{}': {}",
+                type_name, reason
+            ),
+            Self::MessageNotFound { channel, message } => format!(
+                "Message '{}' not found in channel '{}'",
+                message, channel
+            ),
+            Self::InvalidOperationReply(reason) => {
+                format!("Invalid operation reply: {}", reason)
+            }
+            Self::OperationWithoutMessages(operation) => format!(
+                "Operation '{}' lists no messages, so it applies to every message of its channel",
+                operation
+            ),
+            Self::OperationChannelNotFound { operation, channel } => format!(
+                "Operation '{}' references channel '{}', which is not defined in channels",
+                operation, channel
+            ),
+            Self::OperationMessageNotFound {
+                operation,
+                reference,
+            } => format!(
+                "Operation '{}' references message '{}', which does not exist",
+                operation, reference
+            ),
+            Self::OperationMessageNotInChannel {
+                operation,
+                message,
+                channel,
+            } => format!(
+                "Operation '{}' lists message '{}', which does not belong to its channel '{}'",
+                operation, message, channel
+            ),
+            Self::OperationTraitNotFound {
+                operation,
+                reference,
+            } => format!(
+                "Operation '{}' references trait '{}', which does not exist in components.operationTraits",
+                operation, reference
+            ),
+            Self::MessageTraitNotFound { message, reference } => format!(
+                "Message '{}' references trait '{}', which does not exist in components.messageTraits",
+                message, reference
+            ),
+            Self::SecuritySchemeNotFound { server, scheme } => format!(
+                "Server '{}' requires security scheme '{}', which is not defined in components.securitySchemes",
+                server, scheme
+            ),
+            Self::UndeclaredScope {
+                server,
+                scheme,
+                scope,
+            } => format!(
+                "Server '{}' requests scope '{}' of security scheme '{}', which none of its OAuth2 flows declare",
+                server, scope, scheme
+            ),
+            Self::InvalidSecurityScheme(reason) => {
+                format!("Invalid security scheme: {}", reason)
+            }
+            Self::InvalidChannelAddress(reason) => {
+                format!("Invalid channel address: {}", reason)
+            }
+            Self::InvalidChannelParameter(reason) => {
+                format!("Invalid channel parameter: {}", reason)
+            }
+            Self::InvalidServerVariable(reason) => {
+                format!("Invalid server variable: {}", reason)
+            }
+            Self::InvalidExample {
+                message,
+                path,
+                error,
+            } => format!(
+                "Example of message '{}' does not match its schema at '{}': {}",
+                message, path, error
+            ),
+            Self::InvalidExtension(name) => format!(
+                "Extension '{}' does not start with 'x-', so it clashes with the fields of its object",
+                name
+            ),
+        }
+    }
+
+    /// How to fix the specification
+    #[allow(clippy::literal_string_with_formatting_args)] // Hints show `{placeholder}` syntax
+    pub fn hint(&self) -> String {
+        match self {
+            Self::MissingRequiredField(_) => "Add the missing field to your #[asyncapi] attribute. For info fields, use info(title = \"...\", version = \"...\")".to_string(),
+            Self::InvalidAsyncApiVersion(_) => "This library only supports AsyncAPI 3.0.0. The version field is automatically set and should not be modified".to_string(),
+            Self::InvalidChannelReference(channel) => format!(
+                "Add '{}' to the channels(...) list in your #[asyncapi] attribute",
+                channel
+            ),
+            Self::InvalidServerReference(_) => "Add the server to servers(...) in your #[asyncapi] attribute, or remove the server reference from the channel".to_string(),
+            Self::InvalidServer(_) => "AsyncAPI 3.0 servers use host = \"example.com:1234\" (without scheme) and an optional pathname starting with '/'. In servers(...), url = \"...\" is split automatically".to_string(),
+            Self::InvalidSchema(_) => {
+                "Ensure all message types implement JsonSchema and have valid schemas".to_string()
+            }
+            Self::EmptyChannels => {
+                "Add at least one channel to channels(...) in your #[asyncapi] attribute".to_string()
+            }
+            Self::ChannelWithoutMessages(_) => "Add at least one message type to messages(...) in your #[asyncapi] attribute that uses this channel".to_string(),
+            Self::DuplicateMessageId(_) => "Each message must have a unique messageId. Update one of the conflicting messages to use a different messageId".to_string(),
+            Self::UnsupportedProtocol { supported, .. } => format!(
+                "Supported protocols: {:?}. Enable the corresponding feature flag (e.g., `features = [\"nats\"]`) in your Cargo.toml, or register the protocol with `protofolio::register_protocol`",
+                supported
+            ),
+            Self::InvalidProtocol(_) => "Check that the protocol name matches exactly (case-sensitive) and the corresponding feature flag is enabled".to_string(),
+            Self::SchemaGenerationFailed(..) => "Ensure the type implements JsonSchema (usually via #[derive(JsonSchema)]) and all nested types also implement JsonSchema".to_string(),
+            Self::MessageNotFound { .. } => "Ensure the message type is included in messages(...) in your #[asyncapi] attribute and uses the correct channel".to_string(),
+            Self::InvalidOperationReply(_) => "Reply channels must be listed in channels(...), reply messages must belong to the reply channel, and reply addresses use a runtime expression such as \"$message.header#/replyTo\"".to_string(),
+            Self::OperationWithoutMessages(_) => {
+                "List the handled message types in messages(...) to make the intent explicit"
+                    .to_string()
+            }
+            Self::OperationChannelNotFound { .. } => "Add the channel to channels(...) in your #[asyncapi] attribute, or fix the operation's channel".to_string(),
+            Self::OperationMessageNotFound { .. } => "Operation messages look like '#/channels/{channel}/messages/{message}' and must name a message listed in that channel".to_string(),
+            Self::OperationMessageNotInChannel { channel, .. } => format!(
+                "AsyncAPI 3.0 requires an operation's messages to be messages of its channel. Reference them as '#/channels/{}/messages/...', or add the message to the channel",
+                channel
+            ),
+            Self::OperationTraitNotFound { .. } => {
+                "Operation trait references look like '#/components/operationTraits/Name'"
+                    .to_string()
+            }
+            Self::MessageTraitNotFound { .. } => {
+                "Message trait references look like '#/components/messageTraits/Name'".to_string()
+            }
+            Self::SecuritySchemeNotFound { .. } => "Check the scheme name for typos, or define it in security_schemes(...) in your #[asyncapi] attribute".to_string(),
+            Self::UndeclaredScope { scheme, .. } => format!(
+                "Add the scope to the 'scopes' of a flow of '{}', or remove it from the server's security requirement",
+                scheme
+            ),
+            Self::InvalidSecurityScheme(_) => "See the AsyncAPI 3.0 Security Scheme Object for the fields each scheme type requires".to_string(),
+            Self::InvalidChannelAddress(_) => "Placeholders look like 'orders.{tenantId}.created', with each name in braces exactly once".to_string(),
+            Self::InvalidChannelParameter(_) => "Every {placeholder} in a channel address needs a parameter of the same name and vice versa. A parameter's default must be one of its enum values, and its location is a runtime expression such as \"$message.payload#/tenantId\"".to_string(),
+            Self::InvalidServerVariable(_) => "Every {placeholder} in a server host or pathname needs a variable of the same name and vice versa, e.g. variables = [(name = \"host\", default = \"localhost\")]. A variable's default must be one of its enum values".to_string(),
+            Self::InvalidExample { .. } => "Update the example to the current message type, or fix the schema if the example is right".to_string(),
+            Self::InvalidExtension(_) => "Specification extensions are named like 'x-owner'. Set the object's own fields directly instead of through extensions".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\n\nHint: {}", self.message(), self.hint())
+    }
+}
+
+/// Error type for `$ref` resolution
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RefError {
+    /// A reference to another document
+    #[error("Unsupported reference '{0}': only local references starting with '#' can be resolved\n\nHint: Bundle external documents into the specification before resolving references")]
+    External(String),
+
+    /// A reference whose fragment is not a valid JSON Pointer
+    #[error("Invalid reference '{0}': not a JSON Pointer fragment\n\nHint: References look like '#/components/messages/Name'. Escape '~' as '~0' and '/' as '~1' inside names")]
+    InvalidPointer(String),
+
+    /// A reference that points to nothing
+    #[error("Reference '{0}' does not point to anything in the specification\n\nHint: Check that the referenced component, channel or message exists")]
+    NotFound(String),
+
+    /// References that lead back to themselves, in the order they were followed
+    #[error("Reference cycle: {}\n\nHint: Specifications with recursive references cannot be fully dereferenced. Use `resolve` to follow references one at a time", .0.join(" -> "))]
+    Cycle(Vec<String>),
+
+    /// A reference whose target cannot be read as the expected type
+    #[error("Reference '{reference}' points to a value of the wrong type: {message}\n\nHint: Check that the reference points into the right section, e.g. '#/components/messages/...' for a message")]
+    InvalidTarget {
+        /// The reference that was followed
+        reference: String,
+        /// Why the target could not be read
+        message: String,
+    },
+}
+
+/// Error type for bundling and splitting multi-file specifications
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// A file that could not be read or written
+    #[error("Failed to read or write '{path}': {message}")]
+    Io {
+        /// The file
+        path: PathBuf,
+        /// The underlying I/O error
+        message: String,
+    },
+
+    /// A file that is not valid JSON or YAML
+    #[error("Failed to parse '{path}': {message}\n\nHint: Files ending in .json are parsed as JSON, all others as YAML")]
+    Parse {
+        /// The file
+        path: PathBuf,
+        /// The parser error
+        message: String,
+    },
+
+    /// A reference to a remote document
+    #[error("Unsupported reference '{0}': only files on the local file system can be bundled\n\nHint: Download remote documents and reference them by relative path, e.g. './common/headers.yaml#/Headers'")]
+    Remote(String),
+
+    /// A reference that cannot be followed
+    #[error("Reference '{reference}' in '{path}' cannot be resolved: {error}")]
+    Ref {
+        /// The reference as written
+        reference: String,
+        /// The file containing the reference
+        path: PathBuf,
+        /// Why the reference cannot be followed
+        error: RefError,
+    },
+
+    /// A bundled document that does not deserialize as a specification
+    #[error("Bundled document is not a valid AsyncAPI specification: {0}\n\nHint: Check that external references point at objects of the right kind, e.g. a message for a channel message")]
+    InvalidSpec(String),
+}
+
+/// Error type for merging specifications
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    /// Definitions that exist on both sides with different content
+    #[error("{} conflicting definition(s):\n{}\n\nHint: Merge with MergeStrategy::PreferLeft to keep the existing definitions, or MergeStrategy::Namespace to keep both under a prefixed name", .0.len(), .0.iter().map(|conflict| format!("  {}", conflict)).collect::<Vec<_>>().join("\n"))]
+    Conflicts(Vec<MergeConflict>),
+
+    /// A merged document that does not deserialize as a specification
+    #[error("Merged document is not a valid AsyncAPI specification: {0}")]
+    InvalidSpec(String),
+}
+
+/// Error type for validating message payloads and headers
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PayloadError {
+    /// No channel with the given name
+    #[error("Channel '{0}' not found in the specification\n\nHint: Use the channel name (its key in channels), not its address")]
+    ChannelNotFound(String),
+
+    /// No message with the given name in the channel
+    #[error("Message '{message}' not found in channel '{channel}'\n\nHint: Use the message name (its key in the channel's messages)")]
+    MessageNotFound {
+        /// The channel name
+        channel: String,
+        /// The message name
+        message: String,
+    },
+
+    /// A message or schema reference that cannot be resolved
+    #[error("Cannot resolve the message schema: {0}")]
+    Ref(RefError),
+
+    /// A value that violates the schema, with every violation found
+    #[error("Value does not match the message schema, {} violation(s):\n{}", .0.len(), .0.iter().map(|violation| format!("  {}", violation)).collect::<Vec<_>>().join("\n"))]
+    Invalid(Vec<SchemaViolation>),
+}
+
+impl From<RefError> for PayloadError {
+    fn from(err: RefError) -> Self {
+        PayloadError::Ref(err)
+    }
+}
+
+/// Error type for resolving server URLs from their variables
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ServerUrlError {
+    /// A host or pathname with malformed placeholders
+    #[error("Invalid server URL template {0}\n\nHint: Placeholders look like '{{host}}:{{port}}', with each name in braces")]
+    InvalidTemplate(String),
+
+    /// A placeholder without a variable definition
+    #[error("Server URL placeholder '{{{0}}}' has no variable definition\n\nHint: Add the variable to the server's variables")]
+    UndefinedVariable(String),
+
+    /// A variable with neither a given value nor a default
+    #[error("No value for server variable '{0}', which has no default\n\nHint: Pass a value for the variable or give it a default")]
+    MissingValue(String),
+
+    /// A value outside the variable's enum
+    #[error("Value '{value}' is not allowed for server variable '{variable}'; expected one of {allowed:?}")]
+    NotAllowed {
+        /// The variable name
+        variable: String,
+        /// The rejected value
+        value: String,
+        /// The variable's enum values
+        allowed: Vec<String>,
+    },
+}
+
+/// Error type for loading lint rulesets
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LintError {
+    /// A ruleset file that could not be read
+    #[error("Failed to read '{path}': {message}")]
+    Io {
+        /// The ruleset file
+        path: PathBuf,
+        /// The underlying I/O error
+        message: String,
+    },
+
+    /// A ruleset that cannot be parsed
+    #[error("Invalid ruleset: {0}\n\nHint: A ruleset maps rule ids to \"off\", \"warning\" or \"error\" under a `rules` table, e.g. `[rules]\\nmessage-summary = \"error\"`")]
+    Parse(String),
+
+    /// A ruleset entry for a rule the linter does not know
+    #[error("Unknown lint rule '{0}'\n\nHint: Check the rule id against the built-in rules. Custom rules must be added with Linter::with_rule before the ruleset is applied")]
+    UnknownRule(String),
 }
@@ -63,42 +63,77 @@
 
 // Core modules
 mod builder;
+mod bundle;
+mod diff;
 mod error;
 mod internal;
+mod lint;
+mod merge;
 mod protocol;
+mod resolve;
 mod schema;
 mod spec;
+#[cfg(test)]
+mod test_utils;
 mod traits;
 mod types;
 mod validation;
 
 // Public API - carefully curated exports
 pub use builder::AsyncApiBuilder;
-pub use error::{SchemaError, ValidationError};
-pub use schema::{generate_schema, schema_for_type};
+pub use bundle::{bundle, bundle_document, split, DocumentFormat};
+pub use diff::{diff, Change, ChangeKind, SpecDiff};
+pub use error::{
+    BundleError, LintError, MergeError, PayloadError, RefError, SchemaError, ServerUrlError,
+    ValidationError,
+};
+pub use lint::{lint, LintIssue, LintReport, LintRule, LintViolation, Linter, RuleLevel, RuleSet};
+pub use merge::{MergeConflict, MergeStrategy};
+pub use resolve::{local_ref, RefResolver};
+pub use schema::{generate_schema, schema_for_type, SchemaViolation};
 pub use spec::*;
 pub use traits::{AsyncApi, AsyncApiOperation};
 pub use types::OperationAction;
-pub use validation::validate_spec;
+pub use validation::{
+    validate_spec, validate_spec_report, Severity, ValidationIssue, ValidationReport,
+};
+// Insertion-ordered map used for every map in the spec, so output is deterministic
+pub use indexmap::IndexMap;
 // Protocol exports (conditional on features)
-pub use protocol::Protocol;
+pub use protocol::{
+    find_protocol, register_protocol, registered_protocols, BindingValidator, ChannelBindings,
+    MessageBindings, OperationBindings, Protocol, ProtocolInfo, ServerBindings,
+};
 
 #[cfg(feature = "nats")]
 pub use protocol::{
-    NatsChannelBinding, NatsChannelConfig, NatsMessageBinding, NatsMessageConfig, NatsProtocol,
+    NatsChannelConfig, NatsMessageConfig, NatsOperationConfig, NatsProtocol, NatsServerConfig,
     NATS_DEFAULT_PORT, NATS_PROTOCOL,
 };
 
 #[cfg(feature = "kafka")]
 pub use protocol::{
-    KafkaChannelBinding, KafkaChannelConfig, KafkaMessageBinding, KafkaMessageConfig,
-    KafkaProtocol, KAFKA_DEFAULT_PORT, KAFKA_PROTOCOL,
+    KafkaChannelConfig, KafkaMessageConfig, KafkaOperationConfig, KafkaProtocol, KafkaServerConfig,
+    KafkaTopicConfiguration, KAFKA_DEFAULT_PORT, KAFKA_PROTOCOL,
+};
+
+#[cfg(feature = "amqp")]
+pub use protocol::{
+    AmqpChannelConfig, AmqpChannelIs, AmqpDeliveryMode, AmqpExchange, AmqpExchangeType,
+    AmqpMessageConfig, AmqpOperationConfig, AmqpProtocol, AmqpQueue, AmqpServerConfig,
+    AMQPS_PROTOCOL, AMQP_DEFAULT_PORT, AMQP_DEFAULT_SECURE_PORT, AMQP_PROTOCOL,
 };
 
 #[cfg(feature = "mqtt")]
 pub use protocol::{
-    MqttChannelBinding, MqttChannelConfig, MqttMessageBinding, MqttMessageConfig, MqttProtocol,
-    MqttQos, MQTT_DEFAULT_PORT, MQTT_DEFAULT_SECURE_PORT, MQTT_PROTOCOL,
+    MqttChannelConfig, MqttLastWill, MqttMessageConfig, MqttOperationConfig, MqttProtocol, MqttQos,
+    MqttServerConfig, MQTT_DEFAULT_PORT, MQTT_DEFAULT_SECURE_PORT, MQTT_PROTOCOL,
+};
+
+#[cfg(feature = "ws")]
+pub use protocol::{
+    WsChannelConfig, WsMessageConfig, WsMethod, WsOperationConfig, WsProtocol, WsServerConfig,
+    WSS_PROTOCOL, WS_DEFAULT_PORT, WS_DEFAULT_SECURE_PORT, WS_PROTOCOL,
 };
 
 /// Convert an AsyncAPI specification to YAML string
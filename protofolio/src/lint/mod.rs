@@ -0,0 +1,533 @@
+//! Convention checks for specifications
+//!
+//! Validation answers whether a specification is correct; linting whether it
+//! follows a team's conventions. A [`Linter`] runs a set of [`LintRule`]s over an
+//! [`AsyncApiSpec`] and collects their findings in a [`LintReport`]. The
+//! built-in rules are:
+//!
+//! | Rule | Checks |
+//! |------|--------|
+//! | `channel-address-dot-case` | Channel addresses are lowercase words separated by dots |
+//! | `message-id-version` | Message IDs end in a version suffix such as `-v1` |
+//! | `message-summary` | Every message has a summary |
+//! | `message-example` | Every message has at least one example |
+//! | `operation-id-camel-case` | Operation IDs are camelCase |
+//! | `tags-defined` | Tags used anywhere are declared in the root `tags` list |
+//!
+//! Custom rules implement [`LintRule`]; a [`RuleSet`], usually loaded from a
+//! TOML or YAML file, turns rules off or changes their severity.
+
+mod rules;
+
+use crate::error::LintError;
+use crate::spec::AsyncApiSpec;
+use crate::validation::Severity;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+
+/// A convention check over a specification
+///
+/// # Example
+///
+/// ```rust
+/// use protofolio::{AsyncApiSpec, LintRule, LintViolation, Linter, Severity};
+///
+/// struct ContactRequired;
+///
+/// impl LintRule for ContactRequired {
+///     fn id(&self) -> &str {
+///         "info-contact"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "The API has a contact"
+///     }
+///
+///     fn default_severity(&self) -> Severity {
+///         Severity::Error
+///     }
+///
+///     fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+///         if spec.info.contact.is_some() {
+///             Vec::new()
+///         } else {
+///             vec![LintViolation::new("/info", "Info has no contact")]
+///         }
+///     }
+/// }
+///
+/// let linter = Linter::new().with_rule(ContactRequired);
+/// ```
+pub trait LintRule {
+    /// Unique rule identifier, used in reports and rulesets (e.g. `message-summary`)
+    fn id(&self) -> &str;
+
+    /// What the rule checks
+    fn description(&self) -> &str;
+
+    /// Severity of the rule's findings unless a [`RuleSet`] overrides it
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Every place in `spec` that breaks the rule
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation>;
+}
+
+/// A place where a specification breaks a [`LintRule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintViolation {
+    /// JSON Pointer to the offending node
+    pub pointer: String,
+
+    /// What is wrong
+    pub message: String,
+}
+
+impl LintViolation {
+    /// Create a violation at the given JSON Pointer
+    pub fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// A single finding of a lint run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// Id of the rule that reported the issue
+    pub rule: String,
+
+    /// Issue severity
+    pub severity: Severity,
+
+    /// JSON Pointer to the offending node
+    pub pointer: String,
+
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(
+            f,
+            "{} at {}: {} [{}]",
+            self.severity, pointer, self.message, self.rule
+        )
+    }
+}
+
+/// Every finding of a lint run
+///
+/// Returned by [`Linter::lint`]. Issues are grouped by rule, in the order the
+/// rules were registered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+    issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    /// All issues
+    pub fn issues(&self) -> &[LintIssue] {
+        &self.issues
+    }
+
+    /// Issues with [`Severity::Error`]
+    pub fn errors(&self) -> impl Iterator<Item = &LintIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    /// Issues with [`Severity::Warning`]
+    pub fn warnings(&self) -> impl Iterator<Item = &LintIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    /// Whether any rule reported an error
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Whether no issues at all were found
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for LintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{}", issue)?;
+        }
+        write!(
+            f,
+            "{} error(s), {} warning(s)",
+            self.errors().count(),
+            self.warnings().count()
+        )
+    }
+}
+
+/// How a [`RuleSet`] configures a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// Do not run the rule
+    Off,
+    /// Report the rule's findings as warnings
+    #[serde(alias = "warn")]
+    Warning,
+    /// Report the rule's findings as errors
+    Error,
+}
+
+/// Per-rule configuration of a [`Linter`]
+///
+/// Rules not mentioned keep their default severity. In a file, the levels live
+/// under a `rules` table:
+///
+/// ```toml
+/// [rules]
+/// channel-address-dot-case = "error"
+/// message-example = "off"
+/// ```
+///
+/// or, in YAML:
+///
+/// ```yaml
+/// rules:
+///   channel-address-dot-case: error
+///   message-example: off
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSet {
+    #[serde(default)]
+    rules: IndexMap<String, RuleLevel>,
+}
+
+impl RuleSet {
+    /// Create an empty ruleset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the level of a rule
+    #[must_use]
+    pub fn rule(mut self, id: impl Into<String>, level: RuleLevel) -> Self {
+        self.rules.insert(id.into(), level);
+        self
+    }
+
+    /// The configured level of a rule, if any
+    pub fn level(&self, id: &str) -> Option<RuleLevel> {
+        self.rules.get(id).copied()
+    }
+
+    /// Parse a TOML ruleset
+    ///
+    /// Requires the `toml` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintError::Parse`] if `source` is not a valid ruleset
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, LintError> {
+        toml::from_str(source).map_err(|e| LintError::Parse(e.to_string()))
+    }
+
+    /// Parse a YAML ruleset
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintError::Parse`] if `source` is not a valid ruleset
+    pub fn from_yaml(source: &str) -> Result<Self, LintError> {
+        // An empty document is an empty ruleset
+        if source.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_yaml_ng::from_str(source).map_err(|e| LintError::Parse(e.to_string()))
+    }
+
+    /// Read a ruleset file: TOML if it ends in `.toml`, YAML otherwise
+    ///
+    /// TOML files need the `toml` feature; without it they are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintError::Io`] if the file cannot be read and
+    /// [`LintError::Parse`] if it is not a valid ruleset
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LintError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| LintError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+        {
+            #[cfg(feature = "toml")]
+            return Self::from_toml(&source);
+            #[cfg(not(feature = "toml"))]
+            return Err(LintError::Parse(
+                "TOML rulesets require the `toml` feature of protofolio; use a YAML ruleset instead"
+                    .to_string(),
+            ));
+        }
+        Self::from_yaml(&source)
+    }
+}
+
+/// Runs lint rules over specifications
+///
+/// # Example
+///
+/// ```rust
+/// use protofolio::{Linter, RuleLevel, RuleSet};
+/// # let spec: protofolio::AsyncApiSpec = serde_json::from_value(serde_json::json!({
+/// #     "asyncapi": "3.0.0",
+/// #     "info": {"title": "Orders", "version": "1.0.0"},
+/// #     "channels": {}
+/// # })).unwrap();
+///
+/// let ruleset = RuleSet::new()
+///     .rule("message-example", RuleLevel::Off)
+///     .rule("channel-address-dot-case", RuleLevel::Error);
+/// let report = Linter::new().with_ruleset(ruleset)?.lint(&spec);
+/// for issue in report.issues() {
+///     println!("{}", issue);
+/// }
+/// # Ok::<(), protofolio::LintError>(())
+/// ```
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    ruleset: RuleSet,
+}
+
+impl Linter {
+    /// Create a linter running the built-in rules
+    pub fn new() -> Self {
+        Self {
+            rules: rules::builtin(),
+            ruleset: RuleSet::default(),
+        }
+    }
+
+    /// Create a linter without any rules
+    pub fn empty() -> Self {
+        Self {
+            rules: Vec::new(),
+            ruleset: RuleSet::default(),
+        }
+    }
+
+    /// Add a rule, replacing any rule with the same id
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl LintRule + 'static) -> Self {
+        self.rules.retain(|existing| existing.id() != rule.id());
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Configure the rules with `ruleset`
+    ///
+    /// Add custom rules with [`with_rule`](Self::with_rule) first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintError::UnknownRule`] if the ruleset mentions a rule the
+    /// linter does not have
+    pub fn with_ruleset(mut self, ruleset: RuleSet) -> Result<Self, LintError> {
+        if let Some(unknown) = ruleset
+            .rules
+            .keys()
+            .find(|id| !self.rules.iter().any(|rule| rule.id() == id.as_str()))
+        {
+            return Err(LintError::UnknownRule(unknown.clone()));
+        }
+        self.ruleset = ruleset;
+        Ok(self)
+    }
+
+    /// The registered rules
+    pub fn rules(&self) -> impl Iterator<Item = &dyn LintRule> {
+        self.rules.iter().map(AsRef::as_ref)
+    }
+
+    /// Run every enabled rule over `spec`
+    pub fn lint(&self, spec: &AsyncApiSpec) -> LintReport {
+        let mut report = LintReport::default();
+        for rule in &self.rules {
+            let severity = match self.ruleset.level(rule.id()) {
+                Some(RuleLevel::Off) => continue,
+                Some(RuleLevel::Warning) => Severity::Warning,
+                Some(RuleLevel::Error) => Severity::Error,
+                None => rule.default_severity(),
+            };
+            report
+                .issues
+                .extend(rule.check(spec).into_iter().map(|violation| LintIssue {
+                    rule: rule.id().to_string(),
+                    severity,
+                    pointer: violation.pointer,
+                    message: violation.message,
+                }));
+        }
+        report
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Linter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Linter")
+            .field(
+                "rules",
+                &self.rules.iter().map(|rule| rule.id()).collect::<Vec<_>>(),
+            )
+            .field("ruleset", &self.ruleset)
+            .finish()
+    }
+}
+
+/// Lint `spec` with the built-in rules at their default severity
+///
+/// Shorthand for `Linter::new().lint(spec)`.
+pub fn lint(spec: &AsyncApiSpec) -> LintReport {
+    Linter::new().lint(spec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    fn spec() -> AsyncApiSpec {
+        test_utils::spec(serde_json::json!({
+            "channels": {
+                "orders": {
+                    "address": "Orders_Created",
+                    "messages": {"Created": {"payload": {"type": "object"}}}
+                }
+            }
+        }))
+    }
+
+    struct NoOrders;
+
+    impl LintRule for NoOrders {
+        fn id(&self) -> &'static str {
+            "no-orders"
+        }
+
+        fn description(&self) -> &'static str {
+            "No channel is called orders"
+        }
+
+        fn default_severity(&self) -> Severity {
+            Severity::Error
+        }
+
+        fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+            spec.channels
+                .keys()
+                .filter(|name| *name == "orders")
+                .map(|_| LintViolation::new("/channels/orders", "Channel is called orders"))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_ruleset_levels() {
+        let ruleset =
+            RuleSet::from_yaml("rules:\n  message-summary: error\n  message-example: off\n")
+                .unwrap();
+        let report = Linter::new().with_ruleset(ruleset).unwrap().lint(&spec());
+
+        let rules: Vec<_> = report
+            .issues()
+            .iter()
+            .map(|issue| (issue.rule.as_str(), issue.severity))
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                ("channel-address-dot-case", Severity::Warning),
+                ("message-summary", Severity::Error),
+            ]
+        );
+        assert!(report.has_errors());
+        assert_eq!(
+            report.issues()[1].to_string(),
+            "error at /channels/orders/messages/Created: Message 'Created' has no summary [message-summary]"
+        );
+    }
+
+    #[test]
+    fn test_yaml_ruleset() {
+        let ruleset =
+            RuleSet::from_yaml("rules:\n  message-summary: warn\n  tags-defined: off\n").unwrap();
+        assert_eq!(ruleset.level("message-summary"), Some(RuleLevel::Warning));
+        assert_eq!(ruleset.level("tags-defined"), Some(RuleLevel::Off));
+        assert_eq!(ruleset.level("message-example"), None);
+
+        assert!(matches!(
+            RuleSet::from_yaml("rules:\n  message-summary: loud\n"),
+            Err(LintError::Parse(_))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_ruleset() {
+        let ruleset =
+            RuleSet::from_toml("[rules]\nmessage-summary = \"warn\"\ntags-defined = \"off\"\n")
+                .unwrap();
+        assert_eq!(ruleset.level("message-summary"), Some(RuleLevel::Warning));
+        assert_eq!(ruleset.level("tags-defined"), Some(RuleLevel::Off));
+
+        assert!(matches!(
+            RuleSet::from_toml("severity = \"error\"\n"),
+            Err(LintError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let ruleset = RuleSet::new().rule("no-orders", RuleLevel::Warning);
+        assert_eq!(
+            Linter::new().with_ruleset(ruleset.clone()).unwrap_err(),
+            LintError::UnknownRule("no-orders".to_string())
+        );
+
+        let report = Linter::empty().with_rule(NoOrders).lint(&spec());
+        assert_eq!(report.issues().len(), 1);
+        assert_eq!(report.issues()[0].severity, Severity::Error);
+
+        let report = Linter::empty()
+            .with_rule(NoOrders)
+            .with_ruleset(ruleset)
+            .unwrap()
+            .lint(&spec());
+        assert_eq!(report.warnings().count(), 1);
+    }
+}
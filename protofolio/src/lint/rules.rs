@@ -0,0 +1,393 @@
+//! Built-in lint rules
+
+use super::{LintRule, LintViolation};
+use crate::resolve::LazyResolver;
+use crate::spec::{
+    AsyncApiSpec, Message, MessageOrRef, MessageTrait, MessageTraitOrRef, OperationTraitOrRef, Tag,
+};
+use crate::validation::json_pointer;
+use std::collections::HashSet;
+
+/// The rules a [`Linter::new`](super::Linter::new) starts with
+pub fn builtin() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(ChannelAddressDotCase),
+        Box::new(MessageIdVersion),
+        Box::new(MessageSummary),
+        Box::new(MessageExample),
+        Box::new(OperationIdCamelCase),
+        Box::new(TagsDefined),
+    ]
+}
+
+/// Every message defined in the spec with its name and location: inline channel
+/// messages first, then component messages
+///
+/// Referenced channel messages are skipped; they are visited where they are defined.
+fn messages(spec: &AsyncApiSpec) -> Vec<(&str, &Message, Vec<&str>)> {
+    let mut messages = Vec::new();
+    for (channel_name, channel) in &spec.channels {
+        for (message_name, message) in &channel.messages {
+            if let MessageOrRef::Message(message) = message {
+                messages.push((
+                    message_name.as_str(),
+                    message,
+                    vec!["channels", channel_name, "messages", message_name],
+                ));
+            }
+        }
+    }
+    let components = spec
+        .components
+        .as_ref()
+        .and_then(|components| components.messages.as_ref());
+    for (message_name, message) in components.into_iter().flatten() {
+        messages.push((
+            message_name.as_str(),
+            message,
+            vec!["components", "messages", message_name],
+        ));
+    }
+    messages
+}
+
+/// The traits applied to a message; references that cannot be resolved are skipped
+fn message_traits(resolver: &LazyResolver<'_>, message: &Message) -> Vec<MessageTrait> {
+    message
+        .traits
+        .iter()
+        .flatten()
+        .filter_map(|message_trait| match message_trait {
+            MessageTraitOrRef::Trait(message_trait) => Some(message_trait.clone()),
+            MessageTraitOrRef::Ref(reference) => resolver.resolve(&reference.ref_path).ok(),
+        })
+        .collect()
+}
+
+/// Channel addresses are lowercase words separated by dots, e.g. `orders.created`
+///
+/// Parameter placeholders such as `{orderId}` count as a word.
+struct ChannelAddressDotCase;
+
+fn is_dot_case(address: &str) -> bool {
+    address.split('.').all(|segment| {
+        let is_placeholder =
+            segment.len() > 2 && segment.starts_with('{') && segment.ends_with('}');
+        is_placeholder
+            || (!segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()))
+    })
+}
+
+impl LintRule for ChannelAddressDotCase {
+    fn id(&self) -> &'static str {
+        "channel-address-dot-case"
+    }
+
+    fn description(&self) -> &'static str {
+        "Channel addresses are lowercase words separated by dots"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        spec.channels
+            .iter()
+            .filter(|(_, channel)| !is_dot_case(&channel.address))
+            .map(|(channel_name, channel)| {
+                LintViolation::new(
+                    json_pointer(["channels", channel_name, "address"]),
+                    format!(
+                        "Channel address '{}' is not dot.case (e.g. 'orders.created')",
+                        channel.address
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Message IDs end in a version suffix, e.g. `order-created-v1`
+struct MessageIdVersion;
+
+fn has_version_suffix(message_id: &str) -> bool {
+    message_id.rsplit_once("-v").is_some_and(|(name, version)| {
+        !name.is_empty() && !version.is_empty() && version.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+impl LintRule for MessageIdVersion {
+    fn id(&self) -> &'static str {
+        "message-id-version"
+    }
+
+    fn description(&self) -> &'static str {
+        "Message IDs end in a version suffix such as -v1"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        let mut violations = Vec::new();
+        for (_, message, location) in messages(spec) {
+            if let Some(message_id) = &message.message_id {
+                if !has_version_suffix(message_id) {
+                    violations.push(LintViolation::new(
+                        json_pointer(location.into_iter().chain(["messageId"])),
+                        format!(
+                            "Message ID '{}' does not end in a version suffix (e.g. '{}-v1')",
+                            message_id, message_id
+                        ),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Every message has a summary, on the message or one of its traits
+struct MessageSummary;
+
+impl LintRule for MessageSummary {
+    fn id(&self) -> &'static str {
+        "message-summary"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every message has a summary"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        let resolver = LazyResolver::new(spec);
+        messages(spec)
+            .into_iter()
+            .filter(|(_, message, _)| {
+                message.summary.is_none()
+                    && message_traits(&resolver, message)
+                        .iter()
+                        .all(|message_trait| message_trait.summary.is_none())
+            })
+            .map(|(message_name, _, location)| {
+                LintViolation::new(
+                    json_pointer(location),
+                    format!("Message '{}' has no summary", message_name),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Every message has at least one example, on the message or one of its traits
+struct MessageExample;
+
+impl LintRule for MessageExample {
+    fn id(&self) -> &'static str {
+        "message-example"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every message has at least one example"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        let has_examples = |examples: &Option<Vec<serde_json::Value>>| {
+            examples
+                .as_ref()
+                .is_some_and(|examples| !examples.is_empty())
+        };
+        let resolver = LazyResolver::new(spec);
+        messages(spec)
+            .into_iter()
+            .filter(|(_, message, _)| {
+                !has_examples(&message.examples)
+                    && !message_traits(&resolver, message)
+                        .iter()
+                        .any(|message_trait| has_examples(&message_trait.examples))
+            })
+            .map(|(message_name, _, location)| {
+                LintViolation::new(
+                    json_pointer(location),
+                    format!("Message '{}' has no examples", message_name),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Operation IDs are camelCase, e.g. `publishOrderCreated`
+struct OperationIdCamelCase;
+
+fn is_camel_case(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase()) && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+impl LintRule for OperationIdCamelCase {
+    fn id(&self) -> &'static str {
+        "operation-id-camel-case"
+    }
+
+    fn description(&self) -> &'static str {
+        "Operation IDs are camelCase"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        spec.operations
+            .iter()
+            .flatten()
+            .filter(|(_, operation)| !is_camel_case(&operation.operation_id))
+            .map(|(operation_name, operation)| {
+                LintViolation::new(
+                    json_pointer(["operations", operation_name, "operationId"]),
+                    format!(
+                        "Operation ID '{}' is not camelCase (e.g. 'publishOrderCreated')",
+                        operation.operation_id
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Tags used on messages, operations, servers and traits are declared in the
+/// root `tags` list
+struct TagsDefined;
+
+impl TagsDefined {
+    fn check_tags(
+        declared: &HashSet<&str>,
+        tags: Option<&Vec<Tag>>,
+        location: &[&str],
+        violations: &mut Vec<LintViolation>,
+    ) {
+        for (index, tag) in tags.into_iter().flatten().enumerate() {
+            if !declared.contains(tag.name.as_str()) {
+                let index = index.to_string();
+                violations.push(LintViolation::new(
+                    json_pointer(location.iter().copied().chain(["tags", index.as_str()])),
+                    format!("Tag '{}' is not declared in the root tags", tag.name),
+                ));
+            }
+        }
+    }
+}
+
+impl LintRule for TagsDefined {
+    fn id(&self) -> &'static str {
+        "tags-defined"
+    }
+
+    fn description(&self) -> &'static str {
+        "Tags are declared in the root tags list"
+    }
+
+    fn check(&self, spec: &AsyncApiSpec) -> Vec<LintViolation> {
+        let declared: HashSet<&str> = spec
+            .tags
+            .iter()
+            .flatten()
+            .map(|tag| tag.name.as_str())
+            .collect();
+        let mut violations = Vec::new();
+
+        for (server_name, server) in spec.servers.iter().flatten() {
+            let location = ["servers", server_name.as_str()];
+            Self::check_tags(&declared, server.tags.as_ref(), &location, &mut violations);
+        }
+        for (_, message, location) in messages(spec) {
+            Self::check_tags(&declared, message.tags.as_ref(), &location, &mut violations);
+            for (index, message_trait) in message.traits.iter().flatten().enumerate() {
+                if let MessageTraitOrRef::Trait(message_trait) = message_trait {
+                    let index = index.to_string();
+                    let mut location = location.clone();
+                    location.extend(["traits", index.as_str()]);
+                    Self::check_tags(
+                        &declared,
+                        message_trait.tags.as_ref(),
+                        &location,
+                        &mut violations,
+                    );
+                }
+            }
+        }
+        for (operation_name, operation) in spec.operations.iter().flatten() {
+            let location = ["operations", operation_name.as_str()];
+            Self::check_tags(
+                &declared,
+                operation.tags.as_ref(),
+                &location,
+                &mut violations,
+            );
+            for (index, operation_trait) in operation.traits.iter().flatten().enumerate() {
+                if let OperationTraitOrRef::Trait(operation_trait) = operation_trait {
+                    let index = index.to_string();
+                    let location = ["operations", operation_name.as_str(), "traits", &index];
+                    Self::check_tags(
+                        &declared,
+                        operation_trait.tags.as_ref(),
+                        &location,
+                        &mut violations,
+                    );
+                }
+            }
+        }
+        if let Some(components) = &spec.components {
+            for (trait_name, message_trait) in components.message_traits.iter().flatten() {
+                let location = ["components", "messageTraits", trait_name.as_str()];
+                Self::check_tags(
+                    &declared,
+                    message_trait.tags.as_ref(),
+                    &location,
+                    &mut violations,
+                );
+            }
+            for (trait_name, operation_trait) in components.operation_traits.iter().flatten() {
+                let location = ["components", "operationTraits", trait_name.as_str()];
+                Self::check_tags(
+                    &declared,
+                    operation_trait.tags.as_ref(),
+                    &location,
+                    &mut violations,
+                );
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_case() {
+        assert!(is_dot_case("orders"));
+        assert!(is_dot_case("orders.v2.created"));
+        assert!(is_dot_case("orders.{orderId}.created"));
+        assert!(!is_dot_case("Orders.created"));
+        assert!(!is_dot_case("orders/created"));
+        assert!(!is_dot_case("orders..created"));
+        assert!(!is_dot_case("order_created"));
+        assert!(!is_dot_case(""));
+    }
+
+    #[test]
+    fn test_version_suffix() {
+        assert!(has_version_suffix("order-created-v1"));
+        assert!(has_version_suffix("order-v12"));
+        assert!(!has_version_suffix("order-created"));
+        assert!(!has_version_suffix("order-created-v"));
+        assert!(!has_version_suffix("-v1"));
+        assert!(!has_version_suffix("order-created-v1beta"));
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert!(is_camel_case("publishOrder"));
+        assert!(is_camel_case("publish"));
+        assert!(is_camel_case("publishOrderV2"));
+        assert!(!is_camel_case("PublishOrder"));
+        assert!(!is_camel_case("publish_order"));
+        assert!(!is_camel_case("publish-order"));
+        assert!(!is_camel_case(""));
+    }
+}
@@ -0,0 +1,984 @@
+//! Merging the specifications of several services into one document
+//!
+//! [`AsyncApiSpec::merge`] adds the servers, channels, operations and components
+//! of another specification, detecting definitions that conflict with the
+//! existing ones and resolving them with a [`MergeStrategy`].
+
+use crate::error::MergeError;
+use crate::resolve::{local_ref, ref_segments};
+use crate::spec::{AsyncApiSpec, Components, Message, MessageOrRef};
+use crate::validation::json_pointer;
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Top-level sections merged by name, besides the sections of `components`
+const SECTIONS: [&str; 3] = ["servers", "channels", "operations"];
+
+/// How [`AsyncApiSpec::merge`] resolves conflicting definitions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Return [`MergeError::Conflicts`] and leave the specification unchanged
+    Fail,
+    /// Keep the existing definition; references of the merged specification
+    /// point at it
+    PreferLeft,
+    /// Keep both, renaming the merged definition to `<namespace>.<name>`
+    ///
+    /// Message IDs and operation IDs of renamed definitions get the same
+    /// prefix. Channel addresses are never changed.
+    Namespace(String),
+}
+
+/// A definition of a merged specification that conflicts with an existing one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// JSON Pointer to the conflicting definition in the merged specification
+    pub pointer: String,
+
+    /// JSON Pointer to the existing definition
+    pub existing: String,
+
+    /// What conflicts
+    pub description: String,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "conflict at {} (existing {}): {}",
+            self.pointer, self.existing, self.description
+        )
+    }
+}
+
+/// What a conflict is about, for resolving it
+enum Clash {
+    /// The entry at this path exists with another definition
+    Entry(Vec<String>),
+    /// The channel has the address of the existing channel, with other messages
+    Address { channel: String, existing: String },
+    /// The message at `path` has the ID of the existing message, with another payload
+    MessageId {
+        path: Vec<String>,
+        existing: Vec<String>,
+    },
+}
+
+impl AsyncApiSpec {
+    /// Add the servers, channels, operations, components and tags of `other`
+    ///
+    /// Entries with the same name and definition are kept once. The following
+    /// conflicts are resolved with `strategy`:
+    ///
+    /// - a server, channel, operation or component with the name of an existing
+    ///   one but a different definition
+    /// - a channel with the address of an existing channel but different messages
+    /// - a message with the ID of an existing message but a different payload
+    ///
+    /// `info` and the AsyncAPI version of `self` are kept. Returns the resolved
+    /// conflicts; with [`MergeStrategy::Fail`] any conflict is an error and
+    /// `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MergeError::Conflicts`] for conflicts with [`MergeStrategy::Fail`],
+    /// and [`MergeError::InvalidSpec`] if a specification cannot be converted
+    /// to or from JSON
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use protofolio::{AsyncApi, MergeStrategy};
+    /// # use protofolio_derive::AsyncApi;
+    /// #
+    /// # #[derive(AsyncApi)]
+    /// # #[asyncapi(info(title = "Orders", version = "1.0.0"), channels("orders"), messages())]
+    /// # struct OrdersApi;
+    /// # #[derive(AsyncApi)]
+    /// # #[asyncapi(info(title = "Billing", version = "1.0.0"), channels("invoices"), messages())]
+    /// # struct BillingApi;
+    ///
+    /// let mut spec = OrdersApi::asyncapi();
+    /// let conflicts = spec.merge(
+    ///     BillingApi::asyncapi(),
+    ///     MergeStrategy::Namespace("billing".to_string()),
+    /// )?;
+    /// for conflict in &conflicts {
+    ///     eprintln!("{}", conflict);
+    /// }
+    /// # Ok::<(), protofolio::MergeError>(())
+    /// ```
+    #[allow(clippy::needless_pass_by_value)] // A strategy value reads better at call sites
+    pub fn merge(
+        &mut self,
+        mut other: AsyncApiSpec,
+        strategy: MergeStrategy,
+    ) -> Result<Vec<MergeConflict>, MergeError> {
+        let invalid = |e: serde_json::Error| MergeError::InvalidSpec(e.to_string());
+        let left = serde_json::to_value(&*self).map_err(invalid)?;
+
+        // Resolving conflicts rewrites references, which can make entries that
+        // were identical differ; repeat until no conflicts are left
+        let mut conflicts = Vec::new();
+        let mut renamed = HashSet::new();
+        loop {
+            let right = serde_json::to_value(&other).map_err(invalid)?;
+            let (found, clashes): (Vec<_>, Vec<_>) =
+                find_conflicts(&left, &right, &renamed).into_iter().unzip();
+            if found.is_empty() {
+                break;
+            }
+            match &strategy {
+                MergeStrategy::Fail => return Err(MergeError::Conflicts(found)),
+                MergeStrategy::PreferLeft => prefer_left(self, &mut other, &clashes)?,
+                MergeStrategy::Namespace(namespace) => {
+                    renamed.extend(rename(&mut other, &clashes, namespace)?);
+                }
+            }
+            conflicts.extend(found);
+        }
+
+        self.union(other);
+        Ok(conflicts)
+    }
+
+    /// Add the entries of `other` that `self` does not define
+    fn union(&mut self, other: AsyncApiSpec) {
+        add_entries(&mut self.servers, other.servers);
+        insert_missing(&mut self.channels, other.channels);
+        add_entries(&mut self.operations, other.operations);
+
+        if let Some(new) = other.components {
+            let components = self.components.get_or_insert_with(Components::default);
+            add_entries(&mut components.messages, new.messages);
+            add_entries(&mut components.schemas, new.schemas);
+            add_entries(&mut components.security_schemes, new.security_schemes);
+            add_entries(&mut components.parameters, new.parameters);
+            add_entries(&mut components.channel_bindings, new.channel_bindings);
+            add_entries(&mut components.message_bindings, new.message_bindings);
+            add_entries(&mut components.operation_bindings, new.operation_bindings);
+            add_entries(&mut components.server_bindings, new.server_bindings);
+            add_entries(&mut components.operation_traits, new.operation_traits);
+            add_entries(&mut components.message_traits, new.message_traits);
+            add_entries(&mut components.replies, new.replies);
+            add_entries(&mut components.reply_addresses, new.reply_addresses);
+            insert_missing(&mut components.extensions, new.extensions);
+        }
+
+        if let Some(new) = other.tags {
+            let tags = self.tags.get_or_insert_with(Vec::new);
+            for tag in new {
+                if !tags.iter().any(|existing| existing.name == tag.name) {
+                    tags.push(tag);
+                }
+            }
+        }
+        insert_missing(&mut self.extensions, other.extensions);
+    }
+}
+
+/// Every conflict between `left` and `right`, in document order of `right`
+///
+/// Channels in `renamed` were already moved away from a conflicting name.
+fn find_conflicts(
+    left: &Value,
+    right: &Value,
+    renamed: &HashSet<Vec<String>>,
+) -> Vec<(MergeConflict, Clash)> {
+    let mut conflicts = Vec::new();
+    // Entries of `right` that `left` defines too; their messages are compared with them
+    let mut shared = HashSet::new();
+
+    for section in sections(right) {
+        let (Some(existing), Some(entries)) = (entries(left, &section), entries(right, &section))
+        else {
+            continue;
+        };
+        for (name, value) in entries {
+            let Some(existing) = existing.get(name) else {
+                continue;
+            };
+            let path = child(&section, name);
+            if existing != value {
+                conflicts.push((
+                    conflict(
+                        &path,
+                        &path,
+                        format!("{} is defined differently", describe(&section, name)),
+                    ),
+                    Clash::Entry(path.clone()),
+                ));
+            }
+            shared.insert(path);
+        }
+    }
+
+    let channels = vec!["channels".to_string()];
+    if let (Some(existing), Some(entries)) = (entries(left, &channels), entries(right, &channels)) {
+        for (name, channel) in entries
+            .iter()
+            .filter(|(name, _)| !existing.contains_key(*name))
+        {
+            let path = child(&channels, name);
+            if renamed.contains(&path) {
+                continue;
+            }
+            let Some(address) = channel.get("address").and_then(Value::as_str) else {
+                continue;
+            };
+            let Some((existing_name, _)) = existing.iter().find(|(_, existing)| {
+                existing.get("address").and_then(Value::as_str) == Some(address)
+                    && existing.get("messages") != channel.get("messages")
+            }) else {
+                continue;
+            };
+            conflicts.push((
+                conflict(
+                    &path,
+                    &child(&channels, existing_name),
+                    format!(
+                        "Channel '{}' has the address '{}' of channel '{}' with different messages",
+                        name, address, existing_name
+                    ),
+                ),
+                Clash::Address {
+                    channel: name.clone(),
+                    existing: existing_name.clone(),
+                },
+            ));
+            shared.insert(path);
+        }
+    }
+
+    let existing_ids: HashMap<&str, (Vec<String>, &Value)> = messages(left)
+        .into_iter()
+        .filter_map(|(path, message)| Some((message_id(message)?, (path, message))))
+        .collect();
+    for (path, message) in messages(right) {
+        let container = if path[0] == "channels" {
+            &path[..2]
+        } else {
+            &path[..]
+        };
+        let Some(id) = message_id(message).filter(|_| !shared.contains(container)) else {
+            continue;
+        };
+        let Some((existing, existing_message)) = existing_ids.get(id) else {
+            continue;
+        };
+        if existing_message.get("payload") != message.get("payload") {
+            conflicts.push((
+                conflict(
+                    &path,
+                    existing,
+                    format!("Message ID '{}' is used with a different payload", id),
+                ),
+                Clash::MessageId {
+                    path: path.clone(),
+                    existing: existing.clone(),
+                },
+            ));
+        }
+    }
+
+    conflicts
+}
+
+/// Keep the definitions of `left`: drop the conflicting ones of `right`
+fn prefer_left(
+    left: &AsyncApiSpec,
+    right: &mut AsyncApiSpec,
+    clashes: &[Clash],
+) -> Result<(), MergeError> {
+    let mut renames = HashMap::new();
+    for clash in clashes {
+        match clash {
+            // `left` defines the entry already
+            Clash::Entry(path) => {
+                remove_entry(right, path);
+            }
+            Clash::Address { channel, existing } => {
+                right.channels.shift_remove(channel);
+                renames.insert(
+                    vec!["channels".to_string(), channel.clone()],
+                    existing.clone(),
+                );
+            }
+            Clash::MessageId { path, existing } => {
+                if let (Some(message), Some(existing)) =
+                    (message_mut(right, path), message(left, existing))
+                {
+                    *message = existing.clone();
+                }
+            }
+        }
+    }
+    rewrite_refs(right, &renames)
+}
+
+/// Keep both definitions: move the conflicting ones of `right` to `<namespace>.<name>`
+///
+/// Returns the new paths of the moved entries.
+fn rename(
+    right: &mut AsyncApiSpec,
+    clashes: &[Clash],
+    namespace: &str,
+) -> Result<Vec<Vec<String>>, MergeError> {
+    let prefixed = |id: &str| format!("{}.{}", namespace, id);
+    let mut renames = HashMap::new();
+    for clash in clashes {
+        let path = match clash {
+            Clash::Entry(path) => path.clone(),
+            Clash::Address { channel, .. } => vec!["channels".to_string(), channel.clone()],
+            Clash::MessageId { path, .. } => {
+                if let Some(message) = message_mut(right, path) {
+                    message.message_id = message.message_id.as_deref().map(prefixed);
+                }
+                continue;
+            }
+        };
+        let Some((name, section)) = path.split_last() else {
+            continue;
+        };
+
+        // IDs must stay unique as well
+        match section {
+            [section] if section == "operations" => {
+                if let Some(operation) = right
+                    .operations
+                    .as_mut()
+                    .and_then(|operations| operations.get_mut(name))
+                {
+                    operation.operation_id = prefixed(&operation.operation_id);
+                }
+            }
+            [section] if section == "channels" => {
+                let messages = right
+                    .channels
+                    .get_mut(name)
+                    .map(|channel| &mut channel.messages);
+                for message in messages
+                    .into_iter()
+                    .flat_map(|messages| messages.values_mut())
+                {
+                    if let MessageOrRef::Message(message) = message {
+                        message.message_id = message.message_id.as_deref().map(prefixed);
+                    }
+                }
+            }
+            // Component messages; other components have no IDs
+            _ => {
+                if let Some(message) = message_mut(right, &path) {
+                    message.message_id = message.message_id.as_deref().map(prefixed);
+                }
+            }
+        }
+
+        let new_name = prefixed(name);
+        if rename_entry(right, &path, new_name.clone()) {
+            renames.insert(path, new_name);
+        }
+    }
+    rewrite_refs(right, &renames)?;
+    rename_servers(right, &renames);
+    Ok(renames
+        .into_iter()
+        .map(|(path, name)| child(&path[..path.len() - 1], &name))
+        .collect())
+}
+
+fn add_entries<T>(entries: &mut Option<IndexMap<String, T>>, new: Option<IndexMap<String, T>>) {
+    if let Some(new) = new {
+        insert_missing(entries.get_or_insert_with(IndexMap::new), new);
+    }
+}
+
+fn insert_missing<T>(entries: &mut IndexMap<String, T>, new: IndexMap<String, T>) {
+    for (name, entry) in new {
+        entries.entry(name).or_insert(entry);
+    }
+}
+
+/// Point references to renamed entries at their new names
+fn rewrite_refs(
+    spec: &mut AsyncApiSpec,
+    renames: &HashMap<Vec<String>, String>,
+) -> Result<(), MergeError> {
+    if renames.is_empty() {
+        return Ok(());
+    }
+    for (_, entries) in sections_mut(spec) {
+        entries.rewrite_refs(renames)?;
+    }
+    spec.extensions.rewrite_refs(renames)?;
+    if let Some(components) = spec.components.as_mut() {
+        components.extensions.rewrite_refs(renames)?;
+    }
+    Ok(())
+}
+
+/// Point the references in `value` to renamed entries at their new names,
+/// returning whether any changed
+fn rewrite_value_refs(value: &mut Value, renames: &HashMap<Vec<String>, String>) -> bool {
+    match value {
+        Value::Object(object) => {
+            let mut changed = false;
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Some(mut segments) = ref_segments(reference) {
+                    // Entries are `<section>/<name>` or `components/<section>/<name>`
+                    for len in [3, 2] {
+                        if let Some(name) = segments.get(..len).and_then(|path| renames.get(path)) {
+                            segments[len - 1].clone_from(name);
+                            *reference = local_ref(segments.iter().map(String::as_str));
+                            changed = true;
+                            break;
+                        }
+                    }
+                }
+            }
+            for child in object.values_mut() {
+                changed |= rewrite_value_refs(child, renames);
+            }
+            changed
+        }
+        Value::Array(items) => {
+            let mut changed = false;
+            for item in items {
+                changed |= rewrite_value_refs(item, renames);
+            }
+            changed
+        }
+        _ => false,
+    }
+}
+
+/// Point the server lists of channels at renamed servers
+fn rename_servers(spec: &mut AsyncApiSpec, renames: &HashMap<Vec<String>, String>) {
+    let servers: HashMap<&str, &str> = renames
+        .iter()
+        .filter_map(|(path, name)| match path.as_slice() {
+            [section, server] if section == "servers" => Some((server.as_str(), name.as_str())),
+            _ => None,
+        })
+        .collect();
+    if servers.is_empty() {
+        return;
+    }
+    for channel in spec.channels.values_mut() {
+        for name in channel.servers.iter_mut().flatten() {
+            if let Some(renamed) = servers.get(name.as_str()) {
+                *name = (*renamed).to_string();
+            }
+        }
+    }
+}
+
+/// A section of named entries, whatever their type
+trait Entries {
+    /// Remove the entry `name`, keeping the order of the others
+    fn remove(&mut self, name: &str) -> bool;
+
+    /// Rename the entry `name` in place
+    fn rename(&mut self, name: &str, new_name: String) -> bool;
+
+    /// Point references to renamed entries at their new names
+    fn rewrite_refs(&mut self, renames: &HashMap<Vec<String>, String>) -> Result<(), MergeError>;
+}
+
+impl<T: Serialize + DeserializeOwned> Entries for IndexMap<String, T> {
+    fn remove(&mut self, name: &str) -> bool {
+        self.shift_remove(name).is_some()
+    }
+
+    fn rename(&mut self, name: &str, new_name: String) -> bool {
+        let Some((index, _, entry)) = self.shift_remove_full(name) else {
+            return false;
+        };
+        self.shift_insert(index, new_name, entry);
+        true
+    }
+
+    fn rewrite_refs(&mut self, renames: &HashMap<Vec<String>, String>) -> Result<(), MergeError> {
+        let invalid = |e: serde_json::Error| MergeError::InvalidSpec(e.to_string());
+        for entry in self.values_mut() {
+            // References can be anywhere in an entry, including its schemas
+            let mut value = serde_json::to_value(&*entry).map_err(invalid)?;
+            if rewrite_value_refs(&mut value, renames) {
+                *entry = serde_json::from_value(value).map_err(invalid)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The sections of `spec` merged by name, with their paths
+fn sections_mut(spec: &mut AsyncApiSpec) -> Vec<(&'static [&'static str], &mut dyn Entries)> {
+    let mut sections: Vec<(&'static [&'static str], &mut dyn Entries)> = Vec::new();
+    push_section(&mut sections, &["servers"], spec.servers.as_mut());
+    push_section(&mut sections, &["channels"], Some(&mut spec.channels));
+    push_section(&mut sections, &["operations"], spec.operations.as_mut());
+    if let Some(components) = spec.components.as_mut() {
+        push_section(
+            &mut sections,
+            &["components", "messages"],
+            components.messages.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "schemas"],
+            components.schemas.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "securitySchemes"],
+            components.security_schemes.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "parameters"],
+            components.parameters.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "channelBindings"],
+            components.channel_bindings.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "messageBindings"],
+            components.message_bindings.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "operationBindings"],
+            components.operation_bindings.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "serverBindings"],
+            components.server_bindings.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "operationTraits"],
+            components.operation_traits.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "messageTraits"],
+            components.message_traits.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "replies"],
+            components.replies.as_mut(),
+        );
+        push_section(
+            &mut sections,
+            &["components", "replyAddresses"],
+            components.reply_addresses.as_mut(),
+        );
+    }
+    sections
+}
+
+fn push_section<'a, T: Serialize + DeserializeOwned>(
+    sections: &mut Vec<(&'static [&'static str], &'a mut dyn Entries)>,
+    path: &'static [&'static str],
+    entries: Option<&'a mut IndexMap<String, T>>,
+) {
+    if let Some(entries) = entries {
+        sections.push((path, entries));
+    }
+}
+
+/// Remove the entry at `path` of `spec`, returning whether it was there
+fn remove_entry(spec: &mut AsyncApiSpec, path: &[String]) -> bool {
+    entry_section(spec, path).is_some_and(|(entries, name)| entries.remove(name))
+}
+
+/// Rename the entry at `path` of `spec`, returning whether it was there
+fn rename_entry(spec: &mut AsyncApiSpec, path: &[String], new_name: String) -> bool {
+    entry_section(spec, path).is_some_and(|(entries, name)| entries.rename(name, new_name))
+}
+
+/// The section of the entry at `path`, and the entry's name
+fn entry_section<'a, 'p>(
+    spec: &'a mut AsyncApiSpec,
+    path: &'p [String],
+) -> Option<(&'a mut dyn Entries, &'p str)> {
+    let (name, section) = path.split_last()?;
+    let (_, entries) = sections_mut(spec).into_iter().find(|(candidate, _)| {
+        candidate
+            .iter()
+            .copied()
+            .eq(section.iter().map(String::as_str))
+    })?;
+    Some((entries, name))
+}
+
+/// The inline message at `path`, `channels/<channel>/messages/<name>` or
+/// `components/messages/<name>`
+fn message<'a>(spec: &'a AsyncApiSpec, path: &[String]) -> Option<&'a Message> {
+    match path {
+        [channels, channel, messages, name] if channels == "channels" && messages == "messages" => {
+            match spec.channels.get(channel)?.messages.get(name)? {
+                MessageOrRef::Message(message) => Some(message),
+                MessageOrRef::Ref(_) => None,
+            }
+        }
+        [components, messages, name] if components == "components" && messages == "messages" => {
+            spec.components.as_ref()?.messages.as_ref()?.get(name)
+        }
+        _ => None,
+    }
+}
+
+/// Mutable version of [`message`]
+fn message_mut<'a>(spec: &'a mut AsyncApiSpec, path: &[String]) -> Option<&'a mut Message> {
+    match path {
+        [channels, channel, messages, name] if channels == "channels" && messages == "messages" => {
+            match spec.channels.get_mut(channel)?.messages.get_mut(name)? {
+                MessageOrRef::Message(message) => Some(message),
+                MessageOrRef::Ref(_) => None,
+            }
+        }
+        [components, messages, name] if components == "components" && messages == "messages" => {
+            spec.components.as_mut()?.messages.as_mut()?.get_mut(name)
+        }
+        _ => None,
+    }
+}
+
+/// Paths of the sections merged by name present in `document`
+fn sections(document: &Value) -> Vec<Vec<String>> {
+    let mut sections: Vec<Vec<String>> = SECTIONS.iter().map(|s| vec![(*s).to_string()]).collect();
+    if let Some(components) = document.get("components").and_then(Value::as_object) {
+        sections.extend(
+            components
+                .iter()
+                .filter(|(section, entries)| !section.starts_with("x-") && entries.is_object())
+                .map(|(section, _)| vec!["components".to_string(), section.clone()]),
+        );
+    }
+    sections
+}
+
+/// Inline messages of channels and `components.messages`, with their paths
+fn messages(document: &Value) -> Vec<(Vec<String>, &Value)> {
+    let mut messages = Vec::new();
+    if let Some(channels) = document.get("channels").and_then(Value::as_object) {
+        for (channel, value) in channels {
+            let entries = value.get("messages").and_then(Value::as_object);
+            for (name, message) in entries.into_iter().flatten() {
+                if message.get("$ref").is_none() {
+                    let path = ["channels", channel, "messages", name];
+                    messages.push((path.map(String::from).to_vec(), message));
+                }
+            }
+        }
+    }
+    let components = vec!["components".to_string(), "messages".to_string()];
+    for (name, message) in entries(document, &components).into_iter().flatten() {
+        messages.push((child(&components, name), message));
+    }
+    messages
+}
+
+fn message_id(message: &Value) -> Option<&str> {
+    message.get("messageId").and_then(Value::as_str)
+}
+
+/// What the entry `name` of `section` is, for conflict descriptions
+fn describe(section: &[String], name: &str) -> String {
+    match section {
+        [section] if section == "servers" => format!("Server '{}'", name),
+        [section] if section == "channels" => format!("Channel '{}'", name),
+        [section] if section == "operations" => format!("Operation '{}'", name),
+        [_, section] => format!("Component '{}' in components.{}", name, section),
+        _ => format!("'{}'", name),
+    }
+}
+
+fn conflict(path: &[String], existing: &[String], description: String) -> MergeConflict {
+    MergeConflict {
+        pointer: json_pointer(path.iter().map(String::as_str)),
+        existing: json_pointer(existing.iter().map(String::as_str)),
+        description,
+    }
+}
+
+fn child(path: &[String], name: &str) -> Vec<String> {
+    let mut path = path.to_vec();
+    path.push(name.to_string());
+    path
+}
+
+fn entries<'a>(document: &'a Value, path: &[String]) -> Option<&'a Map<String, Value>> {
+    get(document, path)?.as_object()
+}
+
+fn get<'a>(document: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(document, |value, segment| value.get(segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{MessageOrRef, OperationReplyOrRef};
+    use crate::test_utils;
+    use serde_json::json;
+
+    fn service(title: &str, channel: &str, address: &str, payload: &Value) -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "info": {"title": title, "version": "1.0.0"},
+            "servers": {"production": {"host": "nats.example.com", "protocol": "nats"}},
+            "channels": {
+                channel: {
+                    "address": address,
+                    "servers": ["production"],
+                    "messages": {"OrderPlaced": {"$ref": "#/components/messages/OrderPlaced"}}
+                }
+            },
+            "operations": {
+                title: {
+                    "operationId": title,
+                    "action": "send",
+                    "channel": {"$ref": format!("#/channels/{}", channel)},
+                    "messages": [{"$ref": format!("#/channels/{}/messages/OrderPlaced", channel)}]
+                }
+            },
+            "components": {
+                "messages": {
+                    "OrderPlaced": {"messageId": "order-placed-v1", "payload": payload}
+                }
+            },
+            "tags": [{"name": "orders"}]
+        }))
+    }
+
+    fn order() -> Value {
+        json!({"type": "object", "properties": {"id": {"type": "string"}}})
+    }
+
+    #[test]
+    fn test_merge_unions_sections() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let conflicts = spec
+            .merge(
+                service("billing", "orders", "orders", &order()),
+                MergeStrategy::Fail,
+            )
+            .unwrap();
+
+        assert!(conflicts.is_empty());
+        assert_eq!(spec.info.title, "orders");
+        assert_eq!(spec.servers.as_ref().unwrap().len(), 1);
+        assert_eq!(spec.channels.len(), 1);
+        assert_eq!(
+            spec.operations.as_ref().unwrap().keys().collect::<Vec<_>>(),
+            ["orders", "billing"]
+        );
+        assert_eq!(spec.tags.as_ref().unwrap().len(), 1);
+        assert!(crate::validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn test_merge_fail_reports_conflicts() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let mut other = service("billing", "orders", "orders", &json!({"type": "string"}));
+        other.servers.as_mut().unwrap()["production"].host = "kafka.example.com".to_string();
+        other.channels.insert(
+            "orderEvents".to_string(),
+            serde_json::from_value(json!({
+                "address": "orders",
+                "messages": {"Other": {"messageId": "order-placed-v1", "payload": {"type": "integer"}}}
+            }))
+            .unwrap(),
+        );
+
+        let Err(MergeError::Conflicts(conflicts)) = spec.merge(other, MergeStrategy::Fail) else {
+            panic!("expected conflicts");
+        };
+        let pointers: Vec<_> = conflicts.iter().map(|c| c.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            [
+                "/servers/production",
+                "/components/messages/OrderPlaced",
+                "/channels/orderEvents",
+            ]
+        );
+        assert_eq!(
+            conflicts[2].description,
+            "Channel 'orderEvents' has the address 'orders' of channel 'orders' with different messages"
+        );
+        // Unchanged on failure
+        assert_eq!(spec.operations.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_message_id_conflict() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let mut other = service("billing", "invoices", "invoices", &order());
+        other.components = None;
+        other.channels["invoices"].messages.insert(
+            "OrderPlaced".to_string(),
+            MessageOrRef::Message(
+                serde_json::from_value(json!({
+                    "messageId": "order-placed-v1",
+                    "payload": {"type": "string"}
+                }))
+                .unwrap(),
+            ),
+        );
+
+        let conflicts = spec
+            .clone()
+            .merge(other.clone(), MergeStrategy::PreferLeft)
+            .unwrap();
+        assert_eq!(
+            conflicts,
+            [MergeConflict {
+                pointer: "/channels/invoices/messages/OrderPlaced".to_string(),
+                existing: "/components/messages/OrderPlaced".to_string(),
+                description: "Message ID 'order-placed-v1' is used with a different payload"
+                    .to_string(),
+            }]
+        );
+
+        spec.merge(other, MergeStrategy::Namespace("billing".to_string()))
+            .unwrap();
+        let MessageOrRef::Message(message) = &spec.channels["invoices"].messages["OrderPlaced"]
+        else {
+            panic!("message is inline");
+        };
+        assert_eq!(
+            message.message_id.as_deref(),
+            Some("billing.order-placed-v1")
+        );
+    }
+
+    #[test]
+    fn test_merge_prefer_left() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let mut other = service(
+            "billing",
+            "orderEvents",
+            "orders",
+            &json!({"type": "string"}),
+        );
+        other.channels["orderEvents"].messages.insert(
+            "OrderRefunded".to_string(),
+            MessageOrRef::component_ref("OrderRefunded"),
+        );
+
+        let conflicts = spec.merge(other, MergeStrategy::PreferLeft).unwrap();
+        let pointers: Vec<_> = conflicts.iter().map(|c| c.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            ["/components/messages/OrderPlaced", "/channels/orderEvents"]
+        );
+
+        // The existing component and channel are used
+        let messages = spec.components.as_ref().unwrap().messages.as_ref().unwrap();
+        assert_eq!(messages["OrderPlaced"].payload.schema, order());
+        assert_eq!(spec.channels.keys().collect::<Vec<_>>(), ["orders"]);
+        let billing = &spec.operations.as_ref().unwrap()["billing"];
+        assert_eq!(billing.channel.ref_path, "#/channels/orders");
+        assert_eq!(
+            billing.messages[0].ref_path,
+            "#/channels/orders/messages/OrderPlaced"
+        );
+        assert!(crate::validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn test_merge_namespace() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let mut other = service("billing", "orders", "orders", &json!({"type": "string"}));
+        other.servers.as_mut().unwrap()["production"].host = "kafka.example.com".to_string();
+        other.operations.as_mut().unwrap()["billing"].reply = Some(
+            serde_json::from_value(json!({"channel": {"$ref": "#/channels/orders"}})).unwrap(),
+        );
+
+        let conflicts = spec
+            .merge(other, MergeStrategy::Namespace("billing".to_string()))
+            .unwrap();
+        let pointers: Vec<_> = conflicts.iter().map(|c| c.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            [
+                "/servers/production",
+                "/components/messages/OrderPlaced",
+                "/channels/orders"
+            ]
+        );
+        assert_eq!(
+            spec.servers.as_ref().unwrap().keys().collect::<Vec<_>>(),
+            ["production", "billing.production"]
+        );
+
+        let messages = spec.components.as_ref().unwrap().messages.as_ref().unwrap();
+        assert_eq!(
+            messages.keys().collect::<Vec<_>>(),
+            ["OrderPlaced", "billing.OrderPlaced"]
+        );
+        assert_eq!(
+            messages["billing.OrderPlaced"].message_id.as_deref(),
+            Some("billing.order-placed-v1")
+        );
+
+        // The channel became different when its message reference was pointed at the renamed component
+        assert_eq!(
+            spec.channels.keys().collect::<Vec<_>>(),
+            ["orders", "billing.orders"]
+        );
+        let MessageOrRef::Ref(reference) = &spec.channels["billing.orders"].messages["OrderPlaced"]
+        else {
+            panic!("message is a reference");
+        };
+        assert_eq!(
+            reference.ref_path,
+            "#/components/messages/billing.OrderPlaced"
+        );
+        let billing = &spec.operations.as_ref().unwrap()["billing"];
+        assert_eq!(billing.channel.ref_path, "#/channels/billing.orders");
+        let Some(OperationReplyOrRef::Reply(reply)) = &billing.reply else {
+            panic!("reply is inline");
+        };
+        assert_eq!(
+            reply.channel.as_ref().unwrap().ref_path,
+            "#/channels/billing.orders"
+        );
+        assert_eq!(
+            spec.channels["billing.orders"].servers.as_deref(),
+            Some(&["billing.production".to_string()][..])
+        );
+        assert_eq!(billing.operation_id, "billing");
+        assert!(crate::validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn test_merge_namespace_keeps_order() {
+        let mut spec = service("orders", "orders", "orders", &order());
+        let mut other = service("billing", "invoices", "invoices", &order());
+        let servers = other.servers.as_mut().unwrap();
+        servers["production"].host = "kafka.example.com".to_string();
+        servers.shift_insert(0, "audit".to_string(), servers["production"].clone());
+        servers.insert("staging".to_string(), servers["production"].clone());
+
+        spec.merge(other, MergeStrategy::Namespace("billing".to_string()))
+            .unwrap();
+        assert_eq!(
+            spec.servers.as_ref().unwrap().keys().collect::<Vec<_>>(),
+            ["production", "audit", "billing.production", "staging"]
+        );
+    }
+}
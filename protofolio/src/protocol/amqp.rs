@@ -0,0 +1,205 @@
+//! AMQP 0-9-1 protocol support (e.g. RabbitMQ)
+
+use super::bindings::{
+    AmqpChannelConfig, AmqpChannelIs, AmqpExchange, AmqpMessageConfig, AmqpOperationConfig,
+    AmqpQueue, ChannelBindings, MessageBindings, OperationBindings,
+};
+use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use crate::validation::require_channel_binding;
+
+/// AMQP protocol identifier
+pub const PROTOCOL: &str = "amqp";
+
+/// AMQP over TLS protocol identifier
+pub const SECURE_PROTOCOL: &str = "amqps";
+
+/// Default AMQP port
+pub const DEFAULT_PORT: u16 = 5672;
+
+/// Default AMQP secure port (TLS)
+pub const DEFAULT_SECURE_PORT: u16 = 5671;
+
+/// Version of the AMQP bindings produced by the helpers
+pub const BINDING_VERSION: &str = "0.3.0";
+
+/// AMQP delivery modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmqpDeliveryMode {
+    /// The message may be lost if the broker restarts (1)
+    Transient = 1,
+    /// The message is persisted to disk (2)
+    Persistent = 2,
+}
+
+impl AmqpDeliveryMode {
+    /// Get delivery mode as u8
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Create delivery mode from u8, returns None if invalid
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(AmqpDeliveryMode::Transient),
+            2 => Some(AmqpDeliveryMode::Persistent),
+            _ => None,
+        }
+    }
+}
+
+/// AMQP protocol implementation
+#[derive(Debug)]
+pub struct AmqpProtocol;
+
+impl Protocol for AmqpProtocol {
+    fn name() -> &'static str {
+        "AMQP 0-9-1"
+    }
+
+    fn identifier() -> &'static str {
+        PROTOCOL
+    }
+
+    fn identifiers() -> Vec<&'static str> {
+        vec![PROTOCOL, SECURE_PROTOCOL]
+    }
+
+    fn default_port() -> Option<u16> {
+        Some(DEFAULT_PORT)
+    }
+
+    fn default_secure_port() -> Option<u16> {
+        Some(DEFAULT_SECURE_PORT)
+    }
+
+    fn validate_channel_bindings(
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        require_channel_binding(bindings, PROTOCOL, "AMQP", channel_name)
+    }
+}
+
+/// Helper functions for AMQP-specific configurations
+impl AmqpProtocol {
+    /// Create an AMQP channel binding
+    ///
+    /// Pass the `exchange` for a routing key channel and the `queue` for a queue channel.
+    pub fn channel_binding(
+        is: AmqpChannelIs,
+        exchange: Option<AmqpExchange>,
+        queue: Option<AmqpQueue>,
+    ) -> ChannelBindings {
+        ChannelBindings {
+            amqp: Some(AmqpChannelConfig {
+                is: Some(is),
+                exchange,
+                queue,
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Create an AMQP operation binding
+    pub fn operation_binding(
+        delivery_mode: Option<AmqpDeliveryMode>,
+        priority: Option<u8>,
+        ack: Option<bool>,
+    ) -> OperationBindings {
+        OperationBindings {
+            amqp: Some(AmqpOperationConfig {
+                delivery_mode: delivery_mode.map(AmqpDeliveryMode::as_u8),
+                priority,
+                ack,
+                binding_version: Some(BINDING_VERSION.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Create an AMQP message binding
+    pub fn message_binding(
+        content_encoding: Option<String>,
+        message_type: Option<String>,
+    ) -> MessageBindings {
+        MessageBindings {
+            amqp: Some(AmqpMessageConfig {
+                content_encoding,
+                message_type,
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::AmqpExchangeType;
+
+    #[test]
+    fn test_amqp_protocol() {
+        assert_eq!(AmqpProtocol::identifier(), "amqp");
+        assert_eq!(AmqpProtocol::name(), "AMQP 0-9-1");
+    }
+
+    #[test]
+    fn test_amqp_delivery_mode() {
+        assert_eq!(AmqpDeliveryMode::Persistent.as_u8(), 2);
+        assert_eq!(
+            AmqpDeliveryMode::from_u8(1),
+            Some(AmqpDeliveryMode::Transient)
+        );
+        assert_eq!(AmqpDeliveryMode::from_u8(3), None);
+    }
+
+    #[test]
+    fn test_amqp_channel_binding() {
+        let binding = AmqpProtocol::channel_binding(
+            AmqpChannelIs::RoutingKey,
+            Some(AmqpExchange {
+                name: Some("billing".to_string()),
+                r#type: Some(AmqpExchangeType::Topic),
+                durable: Some(true),
+                auto_delete: Some(false),
+                vhost: None,
+            }),
+            None,
+        );
+
+        assert_eq!(
+            serde_json::to_value(binding).unwrap(),
+            serde_json::json!({
+                "amqp": {
+                    "is": "routingKey",
+                    "exchange": {"name": "billing", "type": "topic", "durable": true, "autoDelete": false},
+                    "bindingVersion": "0.3.0"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_amqp_operation_and_message_bindings() {
+        let operation = AmqpProtocol::operation_binding(
+            Some(AmqpDeliveryMode::Persistent),
+            Some(5),
+            Some(true),
+        );
+        let operation = operation.amqp.unwrap();
+        assert_eq!(operation.delivery_mode, Some(2));
+        assert_eq!(operation.priority, Some(5));
+        assert_eq!(operation.ack, Some(true));
+
+        let message = AmqpProtocol::message_binding(Some("gzip".to_string()), None);
+        assert_eq!(
+            message.amqp.unwrap().content_encoding.as_deref(),
+            Some("gzip")
+        );
+    }
+}
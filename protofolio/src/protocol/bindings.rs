@@ -1,49 +1,171 @@
 //! Protocol-specific bindings for AsyncAPI
 //!
 //! This module provides type-safe bindings for different messaging protocols
-//! including NATS, Kafka, and MQTT.
+//! including NATS, Kafka, MQTT, AMQP 0-9-1, and WebSockets.
+//!
+//! [`ChannelBindings`], [`OperationBindings`], [`MessageBindings`] and
+//! [`ServerBindings`] are the AsyncAPI bindings objects: one optional field per
+//! protocol, each holding that protocol's binding object. Field names follow the
+//! official [bindings](https://github.com/asyncapi/bindings) (`bindingVersion`,
+//! `topicConfiguration`, ...). Unknown protocols or fields are rejected, so
+//! bindings that do not fit the typed model deserialize into the `Custom` variant
+//! of the `*BindingsOrRef` enums instead of being silently dropped.
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-/// NATS channel binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct NatsChannelBinding {
-    #[serde(rename = "nats")]
-    pub config: NatsChannelConfig,
+/// Channel bindings object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChannelBindings {
+    /// NATS channel binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nats: Option<NatsChannelConfig>,
+    /// Kafka channel binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kafka: Option<KafkaChannelConfig>,
+    /// MQTT channel binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttChannelConfig>,
+    /// AMQP channel binding
+    #[cfg(feature = "amqp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amqp: Option<AmqpChannelConfig>,
+    /// WebSockets channel binding
+    #[cfg(feature = "ws")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ws: Option<WsChannelConfig>,
+}
+
+/// Operation bindings object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OperationBindings {
+    /// NATS operation binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nats: Option<NatsOperationConfig>,
+    /// Kafka operation binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kafka: Option<KafkaOperationConfig>,
+    /// MQTT operation binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttOperationConfig>,
+    /// AMQP operation binding
+    #[cfg(feature = "amqp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amqp: Option<AmqpOperationConfig>,
+    /// WebSockets operation binding
+    #[cfg(feature = "ws")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ws: Option<WsOperationConfig>,
+}
+
+/// Message bindings object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MessageBindings {
+    /// NATS message binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nats: Option<NatsMessageConfig>,
+    /// Kafka message binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kafka: Option<KafkaMessageConfig>,
+    /// MQTT message binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttMessageConfig>,
+    /// AMQP message binding
+    #[cfg(feature = "amqp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amqp: Option<AmqpMessageConfig>,
+    /// WebSockets message binding
+    #[cfg(feature = "ws")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ws: Option<WsMessageConfig>,
+}
+
+/// Server bindings object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerBindings {
+    /// NATS server binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nats: Option<NatsServerConfig>,
+    /// Kafka server binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kafka: Option<KafkaServerConfig>,
+    /// MQTT server binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttServerConfig>,
+    /// AMQP server binding
+    #[cfg(feature = "amqp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amqp: Option<AmqpServerConfig>,
+    /// WebSockets server binding
+    #[cfg(feature = "ws")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ws: Option<WsServerConfig>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// NATS server binding
+///
+/// Reserved by the NATS bindings; it has no fields and serializes as `{}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NatsServerConfig {}
+
+/// NATS channel binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct NatsChannelConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub queue: Option<String>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
 
 /// NATS message binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct NatsMessageBinding {
-    #[serde(rename = "nats")]
-    pub config: NatsMessageConfig,
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NatsMessageConfig {
+    /// Header values, by name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<IndexMap<String, String>>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct NatsMessageConfig {
+/// NATS operation binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NatsOperationConfig {
+    /// Queue group the operation's subscribers join
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub headers: Option<HashMap<String, String>>,
+    pub queue: Option<String>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
 
-/// Kafka channel binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KafkaChannelBinding {
-    #[serde(rename = "kafka")]
-    pub config: KafkaChannelConfig,
+/// Kafka server binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct KafkaServerConfig {
+    /// URL of the schema registry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry_url: Option<String>,
+    /// Vendor of the schema registry (e.g. `confluent`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry_vendor: Option<String>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Kafka channel binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct KafkaChannelConfig {
     /// Kafka topic name
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,21 +176,65 @@ pub struct KafkaChannelConfig {
     /// Replication factor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub replicas: Option<u32>,
-    /// Topic configuration
+    /// Topic configuration properties
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub topic_configuration: Option<HashMap<String, String>>,
+    pub topic_configuration: Option<KafkaTopicConfiguration>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
 
-/// Kafka message binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KafkaMessageBinding {
-    #[serde(rename = "kafka")]
-    pub config: KafkaMessageConfig,
+/// Kafka topic configuration properties
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaTopicConfiguration {
+    /// `cleanup.policy`: `delete` and/or `compact`
+    #[serde(rename = "cleanup.policy", skip_serializing_if = "Option::is_none")]
+    pub cleanup_policy: Option<Vec<String>>,
+    /// `retention.ms`
+    #[serde(rename = "retention.ms", skip_serializing_if = "Option::is_none")]
+    pub retention_ms: Option<i64>,
+    /// `retention.bytes`
+    #[serde(rename = "retention.bytes", skip_serializing_if = "Option::is_none")]
+    pub retention_bytes: Option<i64>,
+    /// `delete.retention.ms`
+    #[serde(
+        rename = "delete.retention.ms",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delete_retention_ms: Option<i64>,
+    /// `max.message.bytes`
+    #[serde(rename = "max.message.bytes", skip_serializing_if = "Option::is_none")]
+    pub max_message_bytes: Option<i32>,
+    /// `confluent.key.schema.validation`
+    #[serde(
+        rename = "confluent.key.schema.validation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub confluent_key_schema_validation: Option<bool>,
+    /// `confluent.key.subject.name.strategy`
+    #[serde(
+        rename = "confluent.key.subject.name.strategy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub confluent_key_subject_name_strategy: Option<String>,
+    /// `confluent.value.schema.validation`
+    #[serde(
+        rename = "confluent.value.schema.validation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub confluent_value_schema_validation: Option<bool>,
+    /// `confluent.value.subject.name.strategy`
+    #[serde(
+        rename = "confluent.value.subject.name.strategy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub confluent_value_subject_name_strategy: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Kafka message binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct KafkaMessageConfig {
     /// Message key schema
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -82,18 +248,74 @@ pub struct KafkaMessageConfig {
     /// Schema lookup strategy
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_lookup_strategy: Option<String>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
 
-/// MQTT channel binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MqttChannelBinding {
-    #[serde(rename = "mqtt")]
-    pub config: MqttChannelConfig,
+/// Kafka operation binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct KafkaOperationConfig {
+    /// Consumer group ID schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<serde_json::Value>,
+    /// Client ID schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<serde_json::Value>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// MQTT server binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MqttServerConfig {
+    /// Client identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Whether to create a persistent session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clean_session: Option<bool>,
+    /// Last Will and Testament configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_will: Option<MqttLastWill>,
+    /// Keep alive interval in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_alive: Option<u32>,
+    /// Session expiry interval in seconds, or a schema for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_expiry_interval: Option<serde_json::Value>,
+    /// Maximum packet size in bytes, or a schema for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_packet_size: Option<serde_json::Value>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// MQTT Last Will and Testament configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MqttLastWill {
+    /// Topic the Last Will message is sent to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// QoS level (0, 1, or 2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<u8>,
+    /// Last Will message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Retain flag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// MQTT channel binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct MqttChannelConfig {
     /// QoS level (0, 1, or 2)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,18 +326,14 @@ pub struct MqttChannelConfig {
     /// Topic name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
 
 /// MQTT message binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MqttMessageBinding {
-    #[serde(rename = "mqtt")]
-    pub config: MqttMessageConfig,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct MqttMessageConfig {
     /// QoS level
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,6 +341,345 @@ pub struct MqttMessageConfig {
     /// Retain flag
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retain: Option<bool>,
+    /// Payload format indicator: 0 for unspecified bytes, 1 for UTF-8 character data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format_indicator: Option<u8>,
+    /// Schema of the correlation data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_data: Option<serde_json::Value>,
+    /// Content type of the application message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Response topic, or a schema for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_topic: Option<serde_json::Value>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// MQTT operation binding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MqttOperationConfig {
+    /// QoS level (0, 1, or 2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<u8>,
+    /// Retain flag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain: Option<bool>,
+    /// Message expiry interval in seconds, or a schema for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_expiry_interval: Option<serde_json::Value>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// AMQP server binding
+///
+/// Reserved by the AMQP bindings; it has no fields and serializes as `{}`.
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AmqpServerConfig {}
+
+/// AMQP channel binding
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AmqpChannelConfig {
+    /// Whether the channel is a routing key (on an exchange) or a queue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is: Option<AmqpChannelIs>,
+    /// Exchange the channel publishes to, when `is` is `routingKey`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<AmqpExchange>,
+    /// Queue the channel consumes from, when `is` is `queue`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<AmqpQueue>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// What an AMQP channel represents
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AmqpChannelIs {
+    /// A routing key on an exchange
+    RoutingKey,
+    /// A queue
+    Queue,
+}
+
+#[cfg(feature = "amqp")]
+impl TryFrom<&str> for AmqpChannelIs {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "routingKey" => Ok(Self::RoutingKey),
+            "queue" => Ok(Self::Queue),
+            _ => Err(format!(
+                "Invalid AMQP channel kind: {}. Expected 'routingKey' or 'queue'",
+                value
+            )),
+        }
+    }
+}
+
+/// AMQP exchange
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AmqpExchange {
+    /// Exchange name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Exchange type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AmqpExchangeType>,
+    /// Whether the exchange survives broker restarts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durable: Option<bool>,
+    /// Whether the exchange is deleted when the last queue is unbound from it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_delete: Option<bool>,
+    /// Virtual host of the exchange (default: `/`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+}
+
+/// AMQP exchange type
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AmqpExchangeType {
+    /// Routes on routing key patterns
+    Topic,
+    /// Routes on exact routing keys
+    Direct,
+    /// Routes to every bound queue
+    Fanout,
+    /// The default (nameless, direct) exchange
+    Default,
+    /// Routes on message headers
+    Headers,
+}
+
+#[cfg(feature = "amqp")]
+impl TryFrom<&str> for AmqpExchangeType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "topic" => Ok(Self::Topic),
+            "direct" => Ok(Self::Direct),
+            "fanout" => Ok(Self::Fanout),
+            "default" => Ok(Self::Default),
+            "headers" => Ok(Self::Headers),
+            _ => Err(format!(
+                "Invalid AMQP exchange type: {}. Expected one of: topic, direct, fanout, default, headers",
+                value
+            )),
+        }
+    }
+}
+
+/// AMQP queue
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AmqpQueue {
+    /// Queue name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the queue survives broker restarts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durable: Option<bool>,
+    /// Whether the queue is used by only one connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive: Option<bool>,
+    /// Whether the queue is deleted when the last consumer unsubscribes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_delete: Option<bool>,
+    /// Virtual host of the queue (default: `/`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+}
+
+/// AMQP operation binding
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AmqpOperationConfig {
+    /// TTL of the message in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+    /// Identifies the user who has sent the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Routing keys the message should be routed to, besides the channel's
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc: Option<Vec<String>>,
+    /// Priority of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    /// Delivery mode: 1 (transient) or 2 (persistent)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_mode: Option<u8>,
+    /// Whether the message is mandatory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandatory: Option<bool>,
+    /// Like `cc`, but consumers do not receive this information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bcc: Option<Vec<String>>,
+    /// Name of the queue where the consumer should send the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+    /// Whether the message should include a timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<bool>,
+    /// Whether the consumer should ack the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack: Option<bool>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// AMQP message binding
+#[cfg(feature = "amqp")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AmqpMessageConfig {
+    /// MIME encoding of the message content (e.g. `gzip`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+    /// Application-specific message type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+    /// Version of the binding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binding_version: Option<String>,
 }
+
+/// WebSockets server binding
+///
+/// Reserved by the WebSockets bindings; it has no fields and serializes as `{}`.
+#[cfg(feature = "ws")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WsServerConfig {}
+
+/// WebSockets channel binding
+///
+/// Describes the HTTP request that opens the WebSocket connection.
+#[cfg(feature = "ws")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct WsChannelConfig {
+    /// HTTP method of the handshake request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<WsMethod>,
+    /// Schema of the query parameters; must be an object schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<serde_json::Value>,
+    /// Schema of the HTTP headers; must be an object schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<serde_json::Value>,
+    /// Version of the binding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_version: Option<String>,
+}
+
+/// HTTP method of a WebSocket handshake
+#[cfg(feature = "ws")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WsMethod {
+    /// `GET`
+    Get,
+    /// `POST`
+    Post,
+}
+
+#[cfg(feature = "ws")]
+impl TryFrom<&str> for WsMethod {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "GET" => Ok(Self::Get),
+            "POST" => Ok(Self::Post),
+            _ => Err(format!(
+                "Invalid WebSocket method: {}. Expected 'GET' or 'POST'",
+                value
+            )),
+        }
+    }
+}
+
+/// WebSockets operation binding
+///
+/// Reserved by the WebSockets bindings; it has no fields and serializes as `{}`.
+#[cfg(feature = "ws")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WsOperationConfig {}
+
+/// WebSockets message binding
+///
+/// Reserved by the WebSockets bindings; it has no fields and serializes as `{}`.
+#[cfg(feature = "ws")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WsMessageConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_bindings_use_camel_case() {
+        let bindings = ChannelBindings {
+            kafka: Some(KafkaChannelConfig {
+                topic: Some("orders".to_string()),
+                topic_configuration: Some(KafkaTopicConfiguration {
+                    cleanup_policy: Some(vec!["compact".to_string()]),
+                    retention_ms: Some(604_800_000),
+                    ..Default::default()
+                }),
+                binding_version: Some("0.4.0".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&bindings).unwrap(),
+            json!({
+                "kafka": {
+                    "topic": "orders",
+                    "topicConfiguration": {"cleanup.policy": ["compact"], "retention.ms": 604_800_000},
+                    "bindingVersion": "0.4.0"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_bindings_reject_unknown_fields() {
+        assert!(serde_json::from_value::<ServerBindings>(json!({
+            "mqtt": {"clientId": "sensor-1", "lastWill": {"topic": "offline", "qos": 1}}
+        }))
+        .is_ok());
+        assert!(serde_json::from_value::<ChannelBindings>(json!({"nats": {}})).is_ok());
+        assert!(serde_json::from_value::<ChannelBindings>(json!({"ibmmq": {}})).is_err());
+        assert!(serde_json::from_value::<ChannelBindings>(json!({"kafka": {"bogus": 1}})).is_err());
+        assert!(serde_json::from_value::<ChannelBindings>(json!({"$ref": "#/x"})).is_err());
+    }
+}
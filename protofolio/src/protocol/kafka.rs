@@ -1,9 +1,10 @@
 //! Kafka protocol support
 
-use super::bindings::{
-    KafkaChannelBinding, KafkaChannelConfig, KafkaMessageBinding, KafkaMessageConfig,
-};
+use super::bindings::{ChannelBindings, KafkaChannelConfig, KafkaMessageConfig, MessageBindings};
 use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use crate::validation::require_channel_binding;
 
 /// Kafka protocol identifier
 pub const PROTOCOL: &str = "kafka";
@@ -11,6 +12,9 @@ pub const PROTOCOL: &str = "kafka";
 /// Default Kafka port
 pub const DEFAULT_PORT: u16 = 9092;
 
+/// Version of the Kafka bindings produced by the helpers
+pub const BINDING_VERSION: &str = "0.4.0";
+
 /// Kafka protocol implementation
 pub struct KafkaProtocol;
 
@@ -22,6 +26,17 @@ impl Protocol for KafkaProtocol {
     fn identifier() -> &'static str {
         PROTOCOL
     }
+
+    fn default_port() -> Option<u16> {
+        Some(DEFAULT_PORT)
+    }
+
+    fn validate_channel_bindings(
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        require_channel_binding(bindings, PROTOCOL, "Kafka", channel_name)
+    }
 }
 
 /// Helper functions for Kafka-specific configurations
@@ -31,31 +46,31 @@ impl KafkaProtocol {
         topic: Option<String>,
         partitions: Option<u32>,
         replicas: Option<u32>,
-    ) -> serde_json::Value {
-        serde_json::to_value(KafkaChannelBinding {
-            config: KafkaChannelConfig {
+    ) -> ChannelBindings {
+        ChannelBindings {
+            kafka: Some(KafkaChannelConfig {
                 topic,
                 partitions,
                 replicas,
                 topic_configuration: None,
-                binding_version: Some("0.4.0".to_string()),
-            },
-        })
-        .unwrap_or_else(|_| serde_json::json!({}))
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
     }
 
     /// Create a Kafka message binding with key
-    pub fn message_binding(key_schema: Option<serde_json::Value>) -> serde_json::Value {
-        serde_json::to_value(KafkaMessageBinding {
-            config: KafkaMessageConfig {
+    pub fn message_binding(key_schema: Option<serde_json::Value>) -> MessageBindings {
+        MessageBindings {
+            kafka: Some(KafkaMessageConfig {
                 key: key_schema,
                 schema_id_location: None,
                 schema_id_payload_encoding: None,
                 schema_lookup_strategy: None,
-                binding_version: Some("0.4.0".to_string()),
-            },
-        })
-        .unwrap_or_else(|_| serde_json::json!({}))
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
     }
 }
 
@@ -73,11 +88,11 @@ mod tests {
     fn test_kafka_channel_binding() {
         let binding =
             KafkaProtocol::channel_binding(Some("test-topic".to_string()), Some(3), Some(2));
+        let kafka = binding.kafka.unwrap();
 
-        assert!(binding["kafka"]["topic"].as_str().is_some());
-        assert_eq!(binding["kafka"]["topic"], "test-topic");
-        assert_eq!(binding["kafka"]["partitions"], 3);
-        assert_eq!(binding["kafka"]["replicas"], 2);
+        assert_eq!(kafka.topic.as_deref(), Some("test-topic"));
+        assert_eq!(kafka.partitions, Some(3));
+        assert_eq!(kafka.replicas, Some(2));
     }
 
     #[test]
@@ -85,7 +100,6 @@ mod tests {
         let key_schema = serde_json::json!({"type": "string"});
         let binding = KafkaProtocol::message_binding(Some(key_schema.clone()));
 
-        assert!(!binding["kafka"]["key"].is_null());
-        assert_eq!(binding["kafka"]["key"], key_schema);
+        assert_eq!(binding.kafka.unwrap().key, Some(key_schema));
     }
 }
@@ -6,6 +6,8 @@
 //! - **NATS**: Lightweight, high-performance messaging system
 //! - **Kafka**: Distributed event streaming platform
 //! - **MQTT**: IoT messaging protocol
+//! - **AMQP 0-9-1**: Broker-based messaging with exchanges and queues (e.g. RabbitMQ)
+//! - **WebSocket**: Bidirectional streams, e.g. browser-facing gateways
 //!
 //! # Usage
 //!
@@ -25,21 +27,36 @@
 //! For programmatic access, use the protocol constants and types exported from
 //! this module. See individual protocol modules for protocol-specific bindings
 //! and configuration options.
+//!
+//! Other protocols can be added at runtime by implementing [`Protocol`] and
+//! calling [`register_protocol`].
 
 mod bindings;
+mod registry;
 mod traits;
 
+#[cfg(feature = "amqp")]
+pub mod amqp;
 #[cfg(feature = "kafka")]
 pub mod kafka;
 #[cfg(feature = "mqtt")]
 pub mod mqtt;
 #[cfg(feature = "nats")]
 pub mod nats;
+#[cfg(feature = "ws")]
+pub mod ws;
 
 pub use bindings::*;
+pub use registry::*;
 pub use traits::*;
 
 // Re-exports for convenience (conditional on features)
+#[cfg(feature = "amqp")]
+pub use amqp::{
+    AmqpDeliveryMode, AmqpProtocol, DEFAULT_PORT as AMQP_DEFAULT_PORT,
+    DEFAULT_SECURE_PORT as AMQP_DEFAULT_SECURE_PORT, PROTOCOL as AMQP_PROTOCOL,
+    SECURE_PROTOCOL as AMQPS_PROTOCOL,
+};
 #[cfg(feature = "kafka")]
 pub use kafka::{KafkaProtocol, DEFAULT_PORT as KAFKA_DEFAULT_PORT, PROTOCOL as KAFKA_PROTOCOL};
 #[cfg(feature = "mqtt")]
@@ -49,37 +66,22 @@ pub use mqtt::{
 };
 #[cfg(feature = "nats")]
 pub use nats::{NatsProtocol, DEFAULT_PORT as NATS_DEFAULT_PORT, PROTOCOL as NATS_PROTOCOL};
+#[cfg(feature = "ws")]
+pub use ws::{
+    WsProtocol, DEFAULT_PORT as WS_DEFAULT_PORT, DEFAULT_SECURE_PORT as WS_DEFAULT_SECURE_PORT,
+    PROTOCOL as WS_PROTOCOL, SECURE_PROTOCOL as WSS_PROTOCOL,
+};
 
-/// Validate protocol identifier
+/// Validate protocol identifier against the registered protocols
 pub fn validate_protocol(protocol: &str) -> Result<(), crate::error::ValidationError> {
-    let mut supported = Vec::new();
-
-    #[cfg(feature = "nats")]
-    {
-        if protocol == NATS_PROTOCOL {
-            return Ok(());
-        }
-        supported.push(NATS_PROTOCOL.to_string());
+    if find_protocol(protocol).is_some() {
+        return Ok(());
     }
-
-    #[cfg(feature = "kafka")]
-    {
-        if protocol == KAFKA_PROTOCOL {
-            return Ok(());
-        }
-        supported.push(KAFKA_PROTOCOL.to_string());
-    }
-
-    #[cfg(feature = "mqtt")]
-    {
-        if protocol == MQTT_PROTOCOL {
-            return Ok(());
-        }
-        supported.push(MQTT_PROTOCOL.to_string());
-    }
-
     Err(crate::error::ValidationError::UnsupportedProtocol {
         protocol: protocol.to_string(),
-        supported,
+        supported: registered_protocols()
+            .iter()
+            .flat_map(|protocol| protocol.identifiers.iter().map(|id| (*id).to_string()))
+            .collect(),
     })
 }
@@ -1,9 +1,10 @@
 //! MQTT protocol support
 
-use super::bindings::{
-    MqttChannelBinding, MqttChannelConfig, MqttMessageBinding, MqttMessageConfig,
-};
+use super::bindings::{ChannelBindings, MessageBindings, MqttChannelConfig, MqttMessageConfig};
 use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use crate::validation::require_channel_binding;
 
 /// MQTT protocol identifier
 pub const PROTOCOL: &str = "mqtt";
@@ -14,6 +15,9 @@ pub const DEFAULT_PORT: u16 = 1883;
 /// Default MQTT secure port (TLS)
 pub const DEFAULT_SECURE_PORT: u16 = 8883;
 
+/// Version of the MQTT bindings produced by the helpers
+pub const BINDING_VERSION: &str = "0.2.0";
+
 /// MQTT QoS levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MqttQos {
@@ -53,6 +57,21 @@ impl Protocol for MqttProtocol {
     fn identifier() -> &'static str {
         PROTOCOL
     }
+
+    fn default_port() -> Option<u16> {
+        Some(DEFAULT_PORT)
+    }
+
+    fn default_secure_port() -> Option<u16> {
+        Some(DEFAULT_SECURE_PORT)
+    }
+
+    fn validate_channel_bindings(
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        require_channel_binding(bindings, PROTOCOL, "MQTT", channel_name)
+    }
 }
 
 /// Helper functions for MQTT-specific configurations
@@ -62,28 +81,29 @@ impl MqttProtocol {
         topic: Option<String>,
         qos: Option<MqttQos>,
         retain: Option<bool>,
-    ) -> serde_json::Value {
-        serde_json::to_value(MqttChannelBinding {
-            config: MqttChannelConfig {
+    ) -> ChannelBindings {
+        ChannelBindings {
+            mqtt: Some(MqttChannelConfig {
                 qos: qos.map(|q| q.as_u8()),
                 retain,
                 topic,
-                binding_version: Some("0.2.0".to_string()),
-            },
-        })
-        .unwrap_or_else(|_| serde_json::json!({}))
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
     }
 
     /// Create an MQTT message binding
-    pub fn message_binding(qos: Option<MqttQos>, retain: Option<bool>) -> serde_json::Value {
-        serde_json::to_value(MqttMessageBinding {
-            config: MqttMessageConfig {
+    pub fn message_binding(qos: Option<MqttQos>, retain: Option<bool>) -> MessageBindings {
+        MessageBindings {
+            mqtt: Some(MqttMessageConfig {
                 qos: qos.map(|q| q.as_u8()),
                 retain,
-                binding_version: Some("0.2.0".to_string()),
-            },
-        })
-        .unwrap_or_else(|_| serde_json::json!({}))
+                binding_version: Some(BINDING_VERSION.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
     }
 }
 
@@ -117,16 +137,20 @@ mod tests {
             Some(false),
         );
 
-        assert_eq!(binding["mqtt"]["topic"], "test/topic");
-        assert_eq!(binding["mqtt"]["qos"], 1);
-        assert_eq!(binding["mqtt"]["retain"], false);
+        let mqtt = binding.mqtt.unwrap();
+
+        assert_eq!(mqtt.topic.as_deref(), Some("test/topic"));
+        assert_eq!(mqtt.qos, Some(1));
+        assert_eq!(mqtt.retain, Some(false));
     }
 
     #[test]
     fn test_mqtt_message_binding() {
         let binding = MqttProtocol::message_binding(Some(MqttQos::ExactlyOnce), Some(true));
 
-        assert_eq!(binding["mqtt"]["qos"], 2);
-        assert_eq!(binding["mqtt"]["retain"], true);
+        let mqtt = binding.mqtt.unwrap();
+
+        assert_eq!(mqtt.qos, Some(2));
+        assert_eq!(mqtt.retain, Some(true));
     }
 }
@@ -1,6 +1,9 @@
 //! NATS protocol support
 
 use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use crate::validation::require_channel_binding;
 
 /// NATS protocol identifier
 pub const PROTOCOL: &str = "nats";
@@ -19,4 +22,15 @@ impl Protocol for NatsProtocol {
     fn identifier() -> &'static str {
         PROTOCOL
     }
+
+    fn default_port() -> Option<u16> {
+        Some(DEFAULT_PORT)
+    }
+
+    fn validate_channel_bindings(
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        require_channel_binding(bindings, PROTOCOL, "NATS", channel_name)
+    }
 }
@@ -0,0 +1,219 @@
+//! Runtime protocol registry
+//!
+//! Server protocols and channel bindings are validated against the protocols
+//! registered here. The built-in protocols enabled by feature flags are
+//! registered up front; others are added with [`register_protocol`].
+
+use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+/// Callback validating the bindings of a channel: `(bindings, channel_name)`
+pub type BindingValidator = fn(&ChannelBindingsOrRef, &str) -> Result<(), ValidationError>;
+
+/// A protocol known to the registry
+#[derive(Debug, Clone)]
+pub struct ProtocolInfo {
+    /// Protocol name
+    pub name: &'static str,
+    /// Identifiers accepted in a server's `protocol` field
+    pub identifiers: Vec<&'static str>,
+    /// Default port, if any
+    pub default_port: Option<u16>,
+    /// Default port when using TLS, if any
+    pub default_secure_port: Option<u16>,
+    validator: BindingValidator,
+}
+
+impl ProtocolInfo {
+    /// Describe a [`Protocol`] implementation
+    pub fn of<P: Protocol>() -> Self {
+        Self {
+            name: P::name(),
+            identifiers: P::identifiers(),
+            default_port: P::default_port(),
+            default_secure_port: P::default_secure_port(),
+            validator: P::validate_channel_bindings,
+        }
+    }
+
+    /// Whether `identifier` names this protocol
+    pub fn matches(&self, identifier: &str) -> bool {
+        self.identifiers.contains(&identifier)
+    }
+
+    /// Validate the bindings of a channel served by this protocol
+    ///
+    /// # Errors
+    ///
+    /// Returns the protocol's `ValidationError` for invalid bindings
+    pub fn validate_channel_bindings(
+        &self,
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        (self.validator)(bindings, channel_name)
+    }
+}
+
+/// Registered protocols, in registration order
+static REGISTRY: LazyLock<RwLock<Vec<ProtocolInfo>>> =
+    LazyLock::new(|| RwLock::new(builtin_protocols()));
+
+/// Protocols enabled by feature flags
+fn builtin_protocols() -> Vec<ProtocolInfo> {
+    vec![
+        #[cfg(feature = "nats")]
+        ProtocolInfo::of::<super::NatsProtocol>(),
+        #[cfg(feature = "kafka")]
+        ProtocolInfo::of::<super::KafkaProtocol>(),
+        #[cfg(feature = "mqtt")]
+        ProtocolInfo::of::<super::MqttProtocol>(),
+        #[cfg(feature = "amqp")]
+        ProtocolInfo::of::<super::AmqpProtocol>(),
+        #[cfg(feature = "ws")]
+        ProtocolInfo::of::<super::WsProtocol>(),
+    ]
+}
+
+/// Register a protocol so specs using it pass validation
+///
+/// The protocol replaces any registered protocol sharing one of its identifiers,
+/// so a built-in protocol's binding checks can be overridden.
+///
+/// # Example
+///
+/// ```rust
+/// use protofolio::{register_protocol, Protocol};
+///
+/// struct RedisStreams;
+///
+/// impl Protocol for RedisStreams {
+///     fn name() -> &'static str {
+///         "Redis Streams"
+///     }
+///
+///     fn identifier() -> &'static str {
+///         "redis"
+///     }
+///
+///     fn identifiers() -> Vec<&'static str> {
+///         vec!["redis", "rediss"]
+///     }
+///
+///     fn default_port() -> Option<u16> {
+///         Some(6379)
+///     }
+/// }
+///
+/// register_protocol::<RedisStreams>();
+/// assert!(protofolio::find_protocol("rediss").is_some());
+/// ```
+pub fn register_protocol<P: Protocol>() {
+    let protocol = ProtocolInfo::of::<P>();
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    registry.retain(|existing| {
+        !existing
+            .identifiers
+            .iter()
+            .any(|identifier| protocol.matches(identifier))
+    });
+    registry.push(protocol);
+}
+
+/// Look up the registered protocol for an identifier
+pub fn find_protocol(identifier: &str) -> Option<ProtocolInfo> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|protocol| protocol.matches(identifier))
+        .cloned()
+}
+
+/// All registered protocols, built-in ones first
+pub fn registered_protocols() -> Vec<ProtocolInfo> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct InternalBroker;
+
+    impl Protocol for InternalBroker {
+        fn name() -> &'static str {
+            "Internal Broker"
+        }
+
+        fn identifier() -> &'static str {
+            "internal-broker"
+        }
+
+        fn default_port() -> Option<u16> {
+            Some(7000)
+        }
+
+        fn validate_channel_bindings(
+            bindings: &ChannelBindingsOrRef,
+            channel_name: &str,
+        ) -> Result<(), ValidationError> {
+            match bindings {
+                ChannelBindingsOrRef::Custom(value) if value.get("internal-broker").is_some() => {
+                    Ok(())
+                }
+                _ => Err(ValidationError::InvalidSchema(format!(
+                    "Channel '{}': missing internal-broker binding",
+                    channel_name
+                ))),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "nats")]
+    fn test_builtin_protocols_registered() {
+        let nats = find_protocol("nats").unwrap();
+        assert_eq!(nats.name, "NATS");
+        assert_eq!(nats.default_port, Some(4222));
+        assert!(registered_protocols()
+            .iter()
+            .any(|protocol| protocol.matches("nats")));
+    }
+
+    #[test]
+    fn test_register_protocol() {
+        assert!(find_protocol("internal-broker").is_none());
+        register_protocol::<InternalBroker>();
+
+        let protocol = find_protocol("internal-broker").unwrap();
+        assert_eq!(protocol.name, "Internal Broker");
+        assert_eq!(protocol.identifiers, vec!["internal-broker"]);
+        assert_eq!(protocol.default_port, Some(7000));
+        assert_eq!(protocol.default_secure_port, None);
+
+        let bindings = ChannelBindingsOrRef::custom(serde_json::json!({"internal-broker": {}}));
+        assert!(protocol
+            .validate_channel_bindings(&bindings, "events")
+            .is_ok());
+        let bindings = ChannelBindingsOrRef::custom(serde_json::json!({"nats": {}}));
+        assert!(protocol
+            .validate_channel_bindings(&bindings, "events")
+            .is_err());
+
+        // Registering again replaces the existing entry
+        register_protocol::<InternalBroker>();
+        assert_eq!(
+            registered_protocols()
+                .iter()
+                .filter(|protocol| protocol.matches("internal-broker"))
+                .count(),
+            1
+        );
+    }
+}
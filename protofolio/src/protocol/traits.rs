@@ -1,10 +1,46 @@
 //! Protocol trait definitions
 
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+
 /// Protocol trait for extensibility
+///
+/// Protocols registered with [`register_protocol`](crate::register_protocol) are
+/// accepted by [`validate_spec`](crate::validate_spec), which also runs their
+/// binding validator on the channels they serve.
 pub trait Protocol {
     /// Protocol name
     fn name() -> &'static str;
 
     /// Protocol identifier
     fn identifier() -> &'static str;
+
+    /// All identifiers accepted in a server's `protocol` field, e.g. a TLS variant
+    fn identifiers() -> Vec<&'static str> {
+        vec![Self::identifier()]
+    }
+
+    /// Default port, if the protocol has one
+    fn default_port() -> Option<u16> {
+        None
+    }
+
+    /// Default port when using TLS, if the protocol has one
+    fn default_secure_port() -> Option<u16> {
+        None
+    }
+
+    /// Validate the bindings of a channel served by this protocol
+    ///
+    /// The default accepts any bindings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the bindings are invalid for this protocol
+    fn validate_channel_bindings(
+        _bindings: &ChannelBindingsOrRef,
+        _channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        Ok(())
+    }
 }
@@ -0,0 +1,104 @@
+//! WebSocket protocol support
+
+use super::bindings::{ChannelBindings, WsChannelConfig, WsMethod};
+use super::Protocol;
+use crate::error::ValidationError;
+use crate::spec::ChannelBindingsOrRef;
+use crate::validation::{require_channel_binding, validate_ws_schemas};
+
+/// WebSocket protocol identifier
+pub const PROTOCOL: &str = "ws";
+
+/// WebSocket over TLS protocol identifier
+pub const SECURE_PROTOCOL: &str = "wss";
+
+/// Default WebSocket port
+pub const DEFAULT_PORT: u16 = 80;
+
+/// Default WebSocket secure port (TLS)
+pub const DEFAULT_SECURE_PORT: u16 = 443;
+
+/// Version of the WebSockets bindings produced by the helpers
+pub const BINDING_VERSION: &str = "0.1.0";
+
+/// WebSocket protocol implementation
+#[derive(Debug)]
+pub struct WsProtocol;
+
+impl Protocol for WsProtocol {
+    fn name() -> &'static str {
+        "WebSocket"
+    }
+
+    fn identifier() -> &'static str {
+        PROTOCOL
+    }
+
+    fn identifiers() -> Vec<&'static str> {
+        vec![PROTOCOL, SECURE_PROTOCOL]
+    }
+
+    fn default_port() -> Option<u16> {
+        Some(DEFAULT_PORT)
+    }
+
+    fn default_secure_port() -> Option<u16> {
+        Some(DEFAULT_SECURE_PORT)
+    }
+
+    fn validate_channel_bindings(
+        bindings: &ChannelBindingsOrRef,
+        channel_name: &str,
+    ) -> Result<(), ValidationError> {
+        require_channel_binding(bindings, PROTOCOL, "WebSocket", channel_name)?;
+        validate_ws_schemas(bindings, channel_name)
+    }
+}
+
+/// Helper functions for WebSocket-specific configurations
+impl WsProtocol {
+    /// Create a WebSockets channel binding
+    ///
+    /// `query` and `headers` are object schemas, e.g. from
+    /// [`schema_for_type`](crate::schema_for_type).
+    pub fn channel_binding(
+        method: Option<WsMethod>,
+        query: Option<serde_json::Value>,
+        headers: Option<serde_json::Value>,
+    ) -> ChannelBindings {
+        ChannelBindings {
+            ws: Some(WsChannelConfig {
+                method,
+                query,
+                headers,
+                binding_version: Some(BINDING_VERSION.to_string()),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ws_protocol() {
+        assert_eq!(WsProtocol::identifier(), "ws");
+        assert_eq!(WsProtocol::name(), "WebSocket");
+    }
+
+    #[test]
+    fn test_ws_channel_binding() {
+        let query = serde_json::json!({
+            "type": "object",
+            "properties": {"token": {"type": "string"}}
+        });
+        let binding = WsProtocol::channel_binding(Some(WsMethod::Get), Some(query.clone()), None);
+
+        assert_eq!(
+            serde_json::to_value(binding).unwrap(),
+            serde_json::json!({"ws": {"method": "GET", "query": query, "bindingVersion": "0.1.0"}})
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Inlining of local references for [`AsyncApiSpec::dereferenced`](crate::AsyncApiSpec::dereferenced)
+
+use super::{local_reference, RefResolver};
+use crate::error::RefError;
+use serde_json::Value;
+
+/// Replace every local reference in `value` with its (recursively inlined) target
+///
+/// `path` is the location of `value` in the document and `stack` the references
+/// being inlined around it, used to detect cycles.
+pub(super) fn inline_refs(
+    resolver: &RefResolver,
+    value: &mut Value,
+    path: &mut Vec<String>,
+    stack: &mut Vec<String>,
+) -> Result<(), RefError> {
+    if let Some(reference) = local_reference(value).filter(|_| !is_identity_ref(path)) {
+        let reference = reference.to_string();
+        if let Some(start) = stack.iter().position(|seen| *seen == reference) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(reference);
+            return Err(RefError::Cycle(cycle));
+        }
+
+        let mut target = resolver.lookup(&reference)?.clone();
+        stack.push(reference);
+        inline_refs(resolver, &mut target, path, stack)?;
+        stack.pop();
+
+        // Keywords next to `$ref` (allowed in schemas) override the target's
+        if let (Value::Object(object), Value::Object(target)) = (&*value, &mut target) {
+            for (key, sibling) in object.iter().filter(|(key, _)| *key != "$ref") {
+                target.insert(key.clone(), sibling.clone());
+            }
+        }
+        *value = target;
+        return Ok(());
+    }
+
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                path.push(key.clone());
+                let result = inline_refs(resolver, child, path, stack);
+                path.pop();
+                result?;
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                path.push(index.to_string());
+                let result = inline_refs(resolver, item, path, stack);
+                path.pop();
+                result?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Whether the reference at `path` identifies a channel or message instead of describing it
+///
+/// Operations and replies point at their channel and messages; these are
+/// always references in the specification model.
+fn is_identity_ref(path: &[String]) -> bool {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    matches!(
+        path.as_slice(),
+        ["operations", _, "channel"]
+            | ["operations", _, "messages", _]
+            | ["operations", _, "reply", "channel"]
+            | ["operations", _, "reply", "messages", _]
+            | ["components", "replies", _, "channel"]
+            | ["components", "replies", _, "messages", _]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::RefError;
+    use crate::spec::{AsyncApiSpec, MessageOrRef, OperationReplyOrRef, OperationTraitOrRef};
+    use crate::test_utils;
+    use serde_json::json;
+
+    fn spec(components: serde_json::Value) -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "channels": {
+                "users": {
+                    "address": "users",
+                    "messages": {
+                        "UserSignedUp": {"$ref": "#/components/messages/UserSignedUp"},
+                        "Alias": {"$ref": "#/channels/users/messages/UserSignedUp"}
+                    }
+                },
+                "replies": {
+                    "address": "replies",
+                    "messages": {"Ack": {"payload": {"type": "object"}}}
+                }
+            },
+            "operations": {
+                "onUserSignedUp": {
+                    "operationId": "onUserSignedUp",
+                    "action": "receive",
+                    "channel": {"$ref": "#/channels/users"},
+                    "messages": [{"$ref": "#/channels/users/messages/UserSignedUp"}],
+                    "traits": [{"$ref": "#/components/operationTraits/Traced"}],
+                    "reply": {"$ref": "#/components/replies/Ack"}
+                }
+            },
+            "components": components
+        }))
+    }
+
+    fn components() -> serde_json::Value {
+        json!({
+            "messages": {
+                "UserSignedUp": {
+                    "name": "UserSignedUp",
+                    "payload": {"$ref": "#/components/schemas/User"}
+                }
+            },
+            "schemas": {
+                "User": {
+                    "type": "object",
+                    "properties": {"address": {"$ref": "#/components/schemas/Address", "description": "Home"}}
+                },
+                "Address": {"type": "object", "description": "Postal address"}
+            },
+            "operationTraits": {"Traced": {"summary": "Traced operation"}},
+            "replies": {
+                "Ack": {
+                    "channel": {"$ref": "#/channels/replies"},
+                    "messages": [{"$ref": "#/channels/replies/messages/Ack"}]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_dereferenced_inlines_refs() {
+        let spec = spec(components()).dereferenced().unwrap();
+
+        let channel = &spec.channels["users"];
+        for name in ["UserSignedUp", "Alias"] {
+            let MessageOrRef::Message(message) = &channel.messages[name] else {
+                panic!("{} was not inlined", name);
+            };
+            assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+            assert_eq!(
+                message.payload.schema,
+                json!({
+                    "type": "object",
+                    "properties": {"address": {"type": "object", "description": "Home"}}
+                })
+            );
+        }
+
+        let operation = &spec.operations.as_ref().unwrap()["onUserSignedUp"];
+        assert!(matches!(
+            operation.traits.as_deref(),
+            Some([OperationTraitOrRef::Trait(trait_)]) if trait_.summary.as_deref() == Some("Traced operation")
+        ));
+        let Some(OperationReplyOrRef::Reply(reply)) = &operation.reply else {
+            panic!("reply was not inlined");
+        };
+
+        // Channel and message identities stay references
+        assert_eq!(operation.channel.ref_path, "#/channels/users");
+        assert_eq!(
+            operation.messages[0].ref_path,
+            "#/channels/users/messages/UserSignedUp"
+        );
+        assert_eq!(
+            reply.channel.as_ref().unwrap().ref_path,
+            "#/channels/replies"
+        );
+        assert_eq!(
+            reply.messages.as_ref().unwrap()[0].ref_path,
+            "#/channels/replies/messages/Ack"
+        );
+    }
+
+    #[test]
+    fn test_dereferenced_detects_cycles() {
+        let mut components = components();
+        components["schemas"]["Address"] = json!({
+            "type": "object",
+            "properties": {"resident": {"$ref": "#/components/schemas/User"}}
+        });
+
+        assert_eq!(
+            spec(components).dereferenced().unwrap_err(),
+            RefError::Cycle(vec![
+                "#/components/schemas/User".to_string(),
+                "#/components/schemas/Address".to_string(),
+                "#/components/schemas/User".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dereferenced_missing_target() {
+        let mut components = components();
+        components["operationTraits"] = json!({});
+
+        assert_eq!(
+            spec(components).dereferenced().unwrap_err(),
+            RefError::NotFound("#/components/operationTraits/Traced".to_string())
+        );
+    }
+}
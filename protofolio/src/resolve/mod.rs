@@ -0,0 +1,406 @@
+//! `$ref` resolution
+//!
+//! References are local JSON Pointer fragments (`#/components/messages/UserSignedUp`).
+//! Names inside a reference escape `~` as `~0` and `/` as `~1`, and the fragment may
+//! be percent-encoded, so a channel named `user/signedup` is referenced as
+//! `#/channels/user~1signedup`. Use [`local_ref`] to build references and
+//! [`AsyncApiSpec::resolve`] or a [`RefResolver`] to follow them.
+
+mod dereference;
+
+use crate::error::RefError;
+use crate::spec::AsyncApiSpec;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::cell::OnceCell;
+
+/// Build a local reference (`#/...`) from path segments, escaping each segment
+///
+/// # Example
+///
+/// ```rust
+/// use protofolio::local_ref;
+///
+/// assert_eq!(local_ref(["components", "messages", "UserSignedUp"]), "#/components/messages/UserSignedUp");
+/// assert_eq!(local_ref(["channels", "user/signedup"]), "#/channels/user~1signedup");
+/// ```
+pub fn local_ref<'a>(segments: impl IntoIterator<Item = &'a str>) -> String {
+    format!("#{}", crate::validation::json_pointer(segments))
+}
+
+/// Split a local reference into its unescaped path segments
+///
+/// `#` (the whole document) has no segments.
+pub fn parse_ref(reference: &str) -> Result<Vec<String>, RefError> {
+    let invalid = || RefError::InvalidPointer(reference.to_string());
+    let Some(fragment) = reference.strip_prefix('#') else {
+        return Err(RefError::External(reference.to_string()));
+    };
+    let pointer = percent_decode(fragment).ok_or_else(invalid)?;
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(pointer) = pointer.strip_prefix('/') else {
+        return Err(invalid());
+    };
+    pointer
+        .split('/')
+        .map(|token| unescape_token(token).ok_or_else(invalid))
+        .collect()
+}
+
+/// Path segments of a local reference, or `None` if it is not a valid one
+pub fn ref_segments(reference: &str) -> Option<Vec<String>> {
+    parse_ref(reference).ok()
+}
+
+/// Unescape a JSON Pointer token: `~1` is `/` and `~0` is `~` (RFC 6901)
+fn unescape_token(token: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') => unescaped.push('~'),
+                Some('1') => unescaped.push('/'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
+
+/// Decode `%XX` escapes of a URI fragment
+fn percent_decode(fragment: &str) -> Option<String> {
+    if !fragment.contains('%') {
+        return Some(fragment.to_string());
+    }
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = fragment.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Resolves local references against a specification
+///
+/// The specification is converted to JSON once, so a resolver is cheaper than
+/// [`AsyncApiSpec::resolve`] when following many references.
+///
+/// # Example
+///
+/// ```rust
+/// use protofolio::{AsyncApiSpec, Message, RefResolver};
+///
+/// let spec: AsyncApiSpec = serde_json::from_value(serde_json::json!({
+///     "asyncapi": "3.0.0",
+///     "info": {"title": "Users", "version": "1.0.0"},
+///     "channels": {
+///         "users": {
+///             "address": "users",
+///             "messages": {"UserSignedUp": {"$ref": "#/components/messages/UserSignedUp"}}
+///         }
+///     },
+///     "components": {
+///         "messages": {"UserSignedUp": {"name": "UserSignedUp", "payload": {"type": "object"}}}
+///     }
+/// }))?;
+///
+/// let resolver = RefResolver::new(&spec)?;
+/// // References to references are followed
+/// let message: Message = resolver.resolve("#/channels/users/messages/UserSignedUp")?;
+/// assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RefResolver {
+    document: Value,
+}
+
+impl RefResolver {
+    /// Create a resolver for `spec`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefError::InvalidTarget`] if `spec` cannot be converted to JSON
+    pub fn new(spec: &AsyncApiSpec) -> Result<Self, RefError> {
+        let document = serde_json::to_value(spec).map_err(|e| RefError::InvalidTarget {
+            reference: "#".to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(Self { document })
+    }
+
+    /// Resolve `reference` and deserialize the target as `T`
+    ///
+    /// If the target is itself a reference, it is followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RefError` if the reference cannot be followed or its target is
+    /// not a `T`
+    pub fn resolve<T: DeserializeOwned>(&self, reference: &str) -> Result<T, RefError> {
+        let target = self.resolve_value(reference)?;
+        serde_json::from_value(target.clone()).map_err(|e| RefError::InvalidTarget {
+            reference: reference.to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Resolve `reference` to the raw JSON value it points to
+    ///
+    /// If the target is itself a reference, it is followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RefError` if the reference is external or malformed, points to
+    /// nothing, or leads back to itself
+    pub fn resolve_value(&self, reference: &str) -> Result<&Value, RefError> {
+        let mut chain = vec![reference.to_string()];
+        let mut target = self.lookup(reference)?;
+        while let Some(next) = local_reference(target) {
+            if chain.iter().any(|seen| seen == next) {
+                chain.push(next.to_string());
+                return Err(RefError::Cycle(chain));
+            }
+            chain.push(next.to_string());
+            target = self.lookup(next)?;
+        }
+        Ok(target)
+    }
+
+    /// Follow a single reference, without following the target if it is a reference too
+    fn lookup(&self, reference: &str) -> Result<&Value, RefError> {
+        lookup_ref(&self.document, reference)
+    }
+}
+
+/// A [`RefResolver`] that is only built once the first reference is resolved
+///
+/// Checks that may or may not meet a reference share one of these, so the
+/// specification is converted to JSON at most once.
+pub struct LazyResolver<'a> {
+    spec: &'a AsyncApiSpec,
+    resolver: OnceCell<Result<RefResolver, RefError>>,
+}
+
+impl<'a> LazyResolver<'a> {
+    pub const fn new(spec: &'a AsyncApiSpec) -> Self {
+        Self {
+            spec,
+            resolver: OnceCell::new(),
+        }
+    }
+
+    /// Resolve `reference` like [`RefResolver::resolve`]
+    pub fn resolve<T: DeserializeOwned>(&self, reference: &str) -> Result<T, RefError> {
+        self.resolver
+            .get_or_init(|| RefResolver::new(self.spec))
+            .as_ref()
+            .map_err(Clone::clone)?
+            .resolve(reference)
+    }
+}
+
+/// Value a local reference points to in `document`, without following further references
+pub fn lookup_ref<'a>(document: &'a Value, reference: &str) -> Result<&'a Value, RefError> {
+    parse_ref(reference)?
+        .iter()
+        .try_fold(document, |value, segment| match value {
+            Value::Object(object) => object.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+        .ok_or_else(|| RefError::NotFound(reference.to_string()))
+}
+
+/// The `$ref` of a reference object pointing into the same document
+fn local_reference(value: &Value) -> Option<&str> {
+    value
+        .get("$ref")
+        .and_then(Value::as_str)
+        .filter(|reference| reference.starts_with('#'))
+}
+
+impl AsyncApiSpec {
+    /// Resolve a local reference (e.g. `#/components/messages/UserSignedUp`) as `T`
+    ///
+    /// If the target is itself a reference, it is followed. To resolve many
+    /// references, create a [`RefResolver`] once instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RefError` if the reference cannot be followed or its target is
+    /// not a `T`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use protofolio::{AsyncApi, Message};
+    /// # use protofolio_derive::AsyncApi;
+    /// #
+    /// # #[derive(AsyncApi)]
+    /// # #[asyncapi(info(title = "Test", version = "1.0.0"), channels("events"), messages())]
+    /// # struct MyApi;
+    ///
+    /// let spec = MyApi::asyncapi();
+    /// let message: Message = spec.resolve("#/components/messages/UserSignedUp")?;
+    /// # Ok::<(), protofolio::RefError>(())
+    /// ```
+    pub fn resolve<T: DeserializeOwned>(&self, reference: &str) -> Result<T, RefError> {
+        RefResolver::new(self)?.resolve(reference)
+    }
+
+    /// Copy of this specification with every local reference inlined
+    ///
+    /// Message, trait, binding, reply and schema references are replaced by
+    /// their targets. The `components` section is kept as-is, so an inlined
+    /// component message also still appears there. References that
+    /// identify a channel or message rather than describe one (the `channel` and
+    /// `messages` of operations and replies) stay references.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefError::Cycle`] for recursive references (e.g. a recursive
+    /// schema), which cannot be inlined, and other `RefError`s for references
+    /// that cannot be followed.
+    pub fn dereferenced(&self) -> Result<AsyncApiSpec, RefError> {
+        let resolver = RefResolver::new(self)?;
+        let mut document = resolver.document.clone();
+        dereference::inline_refs(&resolver, &mut document, &mut Vec::new(), &mut Vec::new())?;
+        serde_json::from_value(document).map_err(|e| RefError::InvalidTarget {
+            reference: "#".to_string(),
+            message: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{Message, OperationTrait};
+    use crate::test_utils;
+    use serde_json::json;
+
+    fn spec() -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "channels": {
+                "user/signedup": {
+                    "address": "user/signedup",
+                    "messages": {"UserSignedUp": {"$ref": "#/components/messages/UserSignedUp"}}
+                }
+            },
+            "components": {
+                "messages": {
+                    "UserSignedUp": {"name": "UserSignedUp", "payload": {"type": "object"}}
+                },
+                "operationTraits": {"Traced": {"summary": "Traced operation"}}
+            }
+        }))
+    }
+
+    #[test]
+    fn test_local_ref_escaping() {
+        assert_eq!(local_ref(["channels", "orders"]), "#/channels/orders");
+        assert_eq!(local_ref(["channels", "a/b~c"]), "#/channels/a~1b~0c");
+        assert_eq!(local_ref([]), "#");
+    }
+
+    #[test]
+    fn test_parse_ref() {
+        assert_eq!(parse_ref("#").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            parse_ref("#/channels/a~1b~0c/messages/M").unwrap(),
+            vec!["channels", "a/b~c", "messages", "M"]
+        );
+        // `~01` is `~1`, not `/`
+        assert_eq!(parse_ref("#/a~01").unwrap(), vec!["a~1"]);
+        assert_eq!(parse_ref("#/user%20events").unwrap(), vec!["user events"]);
+        assert_eq!(
+            parse_ref("#/a~2"),
+            Err(RefError::InvalidPointer("#/a~2".to_string()))
+        );
+        assert_eq!(
+            parse_ref("#channels"),
+            Err(RefError::InvalidPointer("#channels".to_string()))
+        );
+        assert_eq!(
+            parse_ref("other.yaml#/components"),
+            Err(RefError::External("other.yaml#/components".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let spec = spec();
+
+        let message: Message = spec.resolve("#/components/messages/UserSignedUp").unwrap();
+        assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+
+        // The channel message is a reference, which is followed
+        let message: Message = spec
+            .resolve("#/channels/user~1signedup/messages/UserSignedUp")
+            .unwrap();
+        assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+
+        let trait_: OperationTrait = spec.resolve("#/components/operationTraits/Traced").unwrap();
+        assert_eq!(trait_.summary.as_deref(), Some("Traced operation"));
+
+        let title: String = spec.resolve("#/info/title").unwrap();
+        assert_eq!(title, "Test");
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let spec = spec();
+
+        assert_eq!(
+            spec.resolve::<Message>("#/components/messages/Missing")
+                .unwrap_err(),
+            RefError::NotFound("#/components/messages/Missing".to_string())
+        );
+        // Unescaped `/` splits the channel name
+        assert!(matches!(
+            spec.resolve::<Message>("#/channels/user/signedup/messages/UserSignedUp"),
+            Err(RefError::NotFound(_))
+        ));
+        assert!(matches!(
+            spec.resolve::<Message>("#/info"),
+            Err(RefError::InvalidTarget { .. })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_cycle() {
+        let mut spec = spec();
+        let components = spec.components.as_mut().unwrap();
+        components.schemas = Some(
+            [
+                ("A".to_string(), json!({"$ref": "#/components/schemas/B"})),
+                ("B".to_string(), json!({"$ref": "#/components/schemas/A"})),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            spec.resolve::<Value>("#/components/schemas/A").unwrap_err(),
+            RefError::Cycle(vec![
+                "#/components/schemas/A".to_string(),
+                "#/components/schemas/B".to_string(),
+                "#/components/schemas/A".to_string(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Hoisting of JSON Schema definitions into `components.schemas`
+//!
+//! `schemars` emits nested structs and enums as `$ref: "#/$defs/Name"` pointing
+//! into the schema's own `$defs` map. Inside an AsyncAPI document those refs
+//! dangle, so the definitions are moved into `components.schemas` (deduplicated
+//! by name) and every ref is rewritten to `#/components/schemas/Name`.
+
+use crate::spec::{AsyncApiSpec, Components, MessageOrRef, MessagePayload};
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Key under which subschema definitions are stored (JSON Schema 2020-12)
+pub const DEFS_KEY: &str = "$defs";
+
+/// Legacy key under which subschema definitions are stored (draft-07)
+const LEGACY_DEFS_KEY: &str = "definitions";
+
+/// Prefix for references into `components.schemas`
+pub const COMPONENT_SCHEMAS_PREFIX: &str = "#/components/schemas/";
+
+/// Hoist the definitions of every payload and header schema in the spec into
+/// `components.schemas`, rewriting refs to point at the new location.
+///
+/// Schemas already present in `components.schemas` keep their names; colliding
+/// definitions are renamed. Channels and messages are visited in name order so
+/// the chosen names are stable across runs.
+pub fn hoist_spec_definitions(spec: &mut AsyncApiSpec) {
+    let mut hoisted: IndexMap<String, Value> = spec
+        .components
+        .as_mut()
+        .and_then(|components| components.schemas.take())
+        .unwrap_or_default();
+
+    let existing: Vec<String> = hoisted.keys().cloned().collect();
+    for name in existing {
+        if let Some(mut schema) = hoisted.get(&name).cloned() {
+            hoist_definitions(&mut schema, &mut hoisted);
+            hoisted.insert(name, schema);
+        }
+    }
+
+    let mut channels: Vec<_> = spec.channels.iter_mut().collect();
+    channels.sort_by(|a, b| a.0.cmp(b.0));
+    for (_, channel) in channels {
+        let mut messages: Vec<_> = channel.messages.iter_mut().collect();
+        messages.sort_by(|a, b| a.0.cmp(b.0));
+        for (_, message_or_ref) in messages {
+            if let MessageOrRef::Message(message) = message_or_ref {
+                hoist_payload(&mut message.payload, &mut hoisted);
+                if let Some(ref mut headers) = message.headers {
+                    hoist_payload(headers, &mut hoisted);
+                }
+            }
+        }
+    }
+
+    if let Some(ref mut components) = spec.components {
+        if let Some(ref mut messages) = components.messages {
+            let mut messages: Vec<_> = messages.iter_mut().collect();
+            messages.sort_by(|a, b| a.0.cmp(b.0));
+            for (_, message) in messages {
+                hoist_payload(&mut message.payload, &mut hoisted);
+                if let Some(ref mut headers) = message.headers {
+                    hoist_payload(headers, &mut hoisted);
+                }
+            }
+        }
+        if let Some(ref mut message_traits) = components.message_traits {
+            let mut message_traits: Vec<_> = message_traits.iter_mut().collect();
+            message_traits.sort_by(|a, b| a.0.cmp(b.0));
+            for (_, message_trait) in message_traits {
+                if let Some(ref mut headers) = message_trait.headers {
+                    hoist_payload(headers, &mut hoisted);
+                }
+            }
+        }
+    }
+
+    if hoisted.is_empty() {
+        return;
+    }
+
+    let components = spec.components.get_or_insert_with(Components::default);
+    components.schemas = Some(hoisted);
+}
+
+fn hoist_payload(payload: &mut MessagePayload, hoisted: &mut IndexMap<String, Value>) {
+    hoist_definitions(&mut payload.schema, hoisted);
+}
+
+/// Move the root-level definitions of `schema` into `hoisted` and rewrite refs
+///
+/// Definitions already present in `hoisted` with identical content are reused.
+/// A definition whose name is taken by a different schema is renamed with a
+/// numeric suffix (`Address2`, `Address3`, ...).
+pub fn hoist_definitions(schema: &mut Value, hoisted: &mut IndexMap<String, Value>) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    let mut local = Map::new();
+    let mut prefixes = Vec::new();
+    for key in [DEFS_KEY, LEGACY_DEFS_KEY] {
+        if let Some(Value::Object(defs)) = object.remove(key) {
+            prefixes.push(format!("#/{}/", key));
+            local.extend(defs);
+        }
+    }
+    if local.is_empty() {
+        return;
+    }
+
+    // Pick a component name for every local definition. Content is compared with
+    // refs rewritten under the identity mapping so that definitions produced by
+    // different generators for the same type compare equal.
+    let identity: HashMap<String, String> = local.keys().map(|k| (k.clone(), k.clone())).collect();
+    let mut names = HashMap::new();
+    for (name, definition) in &local {
+        let mut candidate = definition.clone();
+        rewrite_refs(&mut candidate, &prefixes, &identity);
+
+        let mut target = name.clone();
+        let mut suffix = 2;
+        while hoisted
+            .get(&target)
+            .is_some_and(|existing| *existing != candidate)
+            || (target != *name && local.contains_key(&target))
+        {
+            target = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        names.insert(name.clone(), target);
+    }
+
+    for (name, mut definition) in local {
+        rewrite_refs(&mut definition, &prefixes, &names);
+        if let Some(target) = names.get(&name) {
+            hoisted.entry(target.clone()).or_insert(definition);
+        }
+    }
+    rewrite_refs(schema, &prefixes, &names);
+}
+
+/// Rewrite `$ref`s into local definitions to point at `components.schemas`
+fn rewrite_refs(value: &mut Value, prefixes: &[String], names: &HashMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                let local_name = prefixes
+                    .iter()
+                    .find_map(|prefix| reference.strip_prefix(prefix.as_str()))
+                    .map(|name| name.replace("~1", "/").replace("~0", "~"));
+                if let Some(target) = local_name.and_then(|name| names.get(&name)) {
+                    *reference = format!(
+                        "{}{}",
+                        COMPONENT_SCHEMAS_PREFIX,
+                        target.replace('~', "~0").replace('/', "~1")
+                    );
+                }
+            }
+            for child in object.values_mut() {
+                rewrite_refs(child, prefixes, names);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_refs(item, prefixes, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_hoist_rewrites_refs() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "address": {"$ref": "#/$defs/Address"},
+                "status": {"$ref": "#/$defs/Status"}
+            },
+            "$defs": {
+                "Address": {
+                    "type": "object",
+                    "properties": {"country": {"$ref": "#/$defs/Country"}}
+                },
+                "Country": {"type": "string"},
+                "Status": {"type": "string", "enum": ["active", "inactive"]}
+            }
+        });
+        let mut hoisted = IndexMap::new();
+        hoist_definitions(&mut schema, &mut hoisted);
+
+        assert!(schema.get("$defs").is_none());
+        assert_eq!(
+            schema["properties"]["address"]["$ref"],
+            "#/components/schemas/Address"
+        );
+        assert_eq!(
+            hoisted["Address"]["properties"]["country"]["$ref"],
+            "#/components/schemas/Country"
+        );
+        assert_eq!(hoisted.len(), 3);
+    }
+
+    #[test]
+    fn test_hoist_deduplicates_identical_definitions() {
+        let definition = json!({"type": "object", "properties": {"city": {"type": "string"}}});
+        let mut first = json!({"$ref": "#/$defs/Address", "$defs": {"Address": definition}});
+        let mut second =
+            json!({"$ref": "#/definitions/Address", "definitions": {"Address": definition}});
+        let mut hoisted = IndexMap::new();
+        hoist_definitions(&mut first, &mut hoisted);
+        hoist_definitions(&mut second, &mut hoisted);
+
+        assert_eq!(hoisted.len(), 1);
+        assert_eq!(first["$ref"], "#/components/schemas/Address");
+        assert_eq!(second["$ref"], "#/components/schemas/Address");
+    }
+
+    #[test]
+    fn test_hoist_renames_conflicting_definitions() {
+        let mut first = json!({
+            "$ref": "#/$defs/Address",
+            "$defs": {"Address": {"type": "string"}}
+        });
+        let mut second = json!({
+            "$ref": "#/$defs/Address",
+            "$defs": {"Address": {"type": "object"}}
+        });
+        let mut hoisted = IndexMap::new();
+        hoist_definitions(&mut first, &mut hoisted);
+        hoist_definitions(&mut second, &mut hoisted);
+
+        assert_eq!(first["$ref"], "#/components/schemas/Address");
+        assert_eq!(second["$ref"], "#/components/schemas/Address2");
+        assert_eq!(hoisted["Address"]["type"], "string");
+        assert_eq!(hoisted["Address2"]["type"], "object");
+    }
+
+    #[test]
+    fn test_hoist_without_definitions_is_noop() {
+        let mut schema = json!({"type": "object", "properties": {"id": {"type": "string"}}});
+        let expected = schema.clone();
+        let mut hoisted = IndexMap::new();
+        hoist_definitions(&mut schema, &mut hoisted);
+
+        assert_eq!(schema, expected);
+        assert!(hoisted.is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 //! JSON Schema generator implementation
 
+use super::definitions::DEFS_KEY;
 use crate::error::SchemaError;
 use schemars::JsonSchema;
 use std::any::TypeId;
@@ -15,6 +16,8 @@ static SCHEMA_CACHE: LazyLock<RwLock<HashMap<TypeId, Arc<serde_json::Value>>>> =
 /// Generate JSON Schema for a type that implements JsonSchema
 ///
 /// Returns an error if schema serialization fails.
+/// Definitions for nested types are kept under the schema's `$defs` key, so the
+/// returned schema is self-contained.
 /// Schemas are cached by TypeId to avoid regenerating the same schema multiple times.
 /// Uses Arc internally to avoid cloning on cache hits.
 ///
@@ -61,9 +64,19 @@ pub fn generate_schema<T: JsonSchema + 'static>() -> Result<serde_json::Value, S
 
     let mut gen = SchemaGenerator::default();
     let root_schema = T::json_schema(&mut gen);
-    let value = serde_json::to_value(&root_schema)
+    let mut value = serde_json::to_value(&root_schema)
         .map_err(|e| SchemaError::Serialization(e.to_string()))?;
 
+    // Keep subschema definitions (nested structs, enums) next to the root so the
+    // schema stays self-contained. The builder later hoists them into
+    // `components.schemas`.
+    let definitions = gen.take_definitions(true);
+    if !definitions.is_empty() {
+        if let Some(object) = value.as_object_mut() {
+            object.insert(DEFS_KEY.to_string(), serde_json::Value::Object(definitions));
+        }
+    }
+
     // Store in cache wrapped in Arc (write lock for exclusive access)
     {
         let value_arc = Arc::new(value.clone());
@@ -138,6 +151,21 @@ mod tests {
         assert_eq!(schema["type"], "object");
     }
 
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    struct NestedStruct {
+        inner: SimpleStruct,
+    }
+
+    #[test]
+    fn test_schema_keeps_nested_definitions() {
+        let schema = generate_schema::<NestedStruct>().unwrap();
+        assert_eq!(
+            schema["properties"]["inner"]["$ref"],
+            "#/$defs/SimpleStruct"
+        );
+        assert_eq!(schema["$defs"]["SimpleStruct"]["type"], "object");
+    }
+
     #[test]
     fn test_schema_contains_properties() {
         let schema = generate_schema::<TestStruct>().unwrap();
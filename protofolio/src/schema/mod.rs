@@ -28,6 +28,11 @@
 //! Schemas are automatically cached by type ID, so repeated calls for the same
 //! type are fast. The cache uses `Arc` internally to avoid cloning on cache hits.
 
+pub mod definitions;
 mod generator;
+mod pattern;
+mod validator;
 
 pub use generator::*;
+pub use validator::SchemaValidator;
+pub use validator::SchemaViolation;
@@ -0,0 +1,93 @@
+//! Regular expressions for the `pattern` and `patternProperties` keywords
+//!
+//! JSON Schema patterns use ECMA-262 syntax. They are compiled with the `regex`
+//! crate, which matches in linear time and supports the commonly used subset:
+//! literals, `.`, character classes and sets, `\d`/`\w`/`\s`, anchors, word
+//! boundaries, groups, alternation and greedy or lazy quantifiers.
+//! Backreferences and lookaround are not supported; such patterns are reported
+//! rather than matched.
+
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// The patterns of a schema, each compiled the first time it is used
+#[derive(Debug, Default)]
+pub struct Patterns {
+    compiled: RefCell<HashMap<String, Result<Regex, String>>>,
+}
+
+impl Patterns {
+    /// The compiled `source`, or why it cannot be compiled
+    pub(crate) fn get(&self, source: &str) -> Result<Regex, String> {
+        self.compiled
+            .borrow_mut()
+            .entry(source.to_string())
+            .or_insert_with(|| Regex::new(source).map_err(|error| describe(&error)))
+            .clone()
+    }
+}
+
+/// The reason `regex` gives for rejecting a pattern, without the pattern itself
+fn describe(error: &regex::Error) -> String {
+    let message = error.to_string();
+    match message.lines().last() {
+        Some(reason) => reason.strip_prefix("error: ").unwrap_or(reason).to_string(),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Patterns;
+
+    fn is_match(pattern: &str, text: &str) -> bool {
+        Patterns::default()
+            .get(pattern)
+            .unwrap_or_else(|error| panic!("{} did not compile: {}", pattern, error))
+            .is_match(text)
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(is_match("^[a-z]+(-[a-z]+)*$", "order-placed"));
+        assert!(!is_match("^[a-z]+(-[a-z]+)*$", "order-"));
+        assert!(is_match(r"^\d{3}-\d{4}$", "555-1234"));
+        assert!(!is_match(r"^\d{3}-\d{4}$", "5551-234"));
+        assert!(is_match("^(foo|bar)baz$", "barbaz"));
+        assert!(is_match(r"\bword\b", "a word here"));
+        assert!(!is_match(r"\bword\b", "swordfish"));
+        assert!(is_match("^a.*?b$", "axxb"));
+        assert!(is_match(
+            r"^[^@\s]+@[^@\s]+\.[a-z]{2,}$",
+            "user@example.com"
+        ));
+        assert!(!is_match(r"^[^@\s]+@[^@\s]+\.[a-z]{2,}$", "user@example"));
+        assert!(is_match("^(?:ab){2,3}$", "ababab"));
+        assert!(!is_match("^(?:ab){2,3}$", "abababab"));
+        assert!(is_match(r"^é+$", "éé"));
+        // Unanchored patterns match anywhere
+        assert!(is_match("ord", "order"));
+    }
+
+    #[test]
+    fn test_pattern_matching_is_linear() {
+        // Both take exponential time with a backtracking matcher
+        let text = format!("{}!", "a".repeat(64));
+        assert!(!is_match("^(a+)+$", &text));
+        let text = format!("{}c", "ab".repeat(100_000));
+        assert!(!is_match("^(ab)*$", &text));
+    }
+
+    #[test]
+    fn test_pattern_unsupported_syntax() {
+        let patterns = Patterns::default();
+        for pattern in [r"(a)\1", "a(?=b)", "(a", "*a", "[z-a]"] {
+            assert!(patterns.get(pattern).is_err(), "{}", pattern);
+        }
+        assert_eq!(
+            patterns.get("a(?=b)").unwrap_err(),
+            "look-around, including look-ahead and look-behind, is not supported"
+        );
+    }
+}
@@ -0,0 +1,887 @@
+//! Validation of JSON values against JSON Schemas
+//!
+//! Implements the core validation keywords shared by JSON Schema draft-07 and
+//! 2020-12 (types, enums, numeric, string, array and object constraints,
+//! combinators and conditionals). `$ref`s are resolved within the
+//! specification, so schemas can reference `components.schemas`. Annotations
+//! such as `format`, `title` or `examples` are not validated, nor are
+//! `unevaluatedProperties`/`unevaluatedItems`. Patterns that cannot be
+//! compiled (e.g. because they use lookaround) are reported as violations.
+
+use super::pattern::Patterns;
+use crate::error::RefError;
+use crate::resolve::{lookup_ref, parse_ref};
+use crate::spec::AsyncApiSpec;
+use crate::validation::json_pointer;
+use serde_json::{Map, Number, Value};
+use std::cell::{OnceCell, RefCell};
+use std::fmt;
+
+/// A value that does not satisfy a schema keyword
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the invalid value in the validated instance (`""` for the root)
+    pub instance_path: String,
+
+    /// JSON Pointer to the violated keyword in the specification, e.g.
+    /// `/components/schemas/User/properties/email/type`
+    pub schema_path: String,
+
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instance = if self.instance_path.is_empty() {
+            "(root)"
+        } else {
+            &self.instance_path
+        };
+        write!(
+            f,
+            "{}: {} (schema {})",
+            instance, self.message, self.schema_path
+        )
+    }
+}
+
+/// Validates values against a schema embedded in a specification
+pub struct SchemaValidator<'a> {
+    spec: &'a AsyncApiSpec,
+    /// The schema validation starts from, for references relative to it (`#/$defs/...`)
+    root: &'a Value,
+    /// Location of `root` in the specification
+    root_location: String,
+    /// The specification as JSON, for references outside `components.schemas`
+    document: OnceCell<Value>,
+    /// References being followed and the instance location they were entered at
+    active_refs: RefCell<Vec<(String, String)>>,
+    patterns: Patterns,
+}
+
+impl<'a> SchemaValidator<'a> {
+    /// Validator for `root`, located at `location` in `spec`
+    pub(crate) fn new(spec: &'a AsyncApiSpec, root: &'a Value, location: &[&str]) -> Self {
+        Self {
+            spec,
+            root,
+            root_location: json_pointer(location.iter().copied()),
+            document: OnceCell::new(),
+            active_refs: RefCell::new(Vec::new()),
+            patterns: Patterns::default(),
+        }
+    }
+
+    /// Every violation of the schema by `instance`
+    pub(crate) fn validate(&self, instance: &Value) -> Result<Vec<SchemaViolation>, RefError> {
+        let mut violations = Vec::new();
+        self.check(
+            self.root,
+            &self.root_location,
+            instance,
+            "",
+            &mut violations,
+        )?;
+        Ok(violations)
+    }
+
+    fn is_valid(
+        &self,
+        schema: &Value,
+        location: &str,
+        instance: &Value,
+        path: &str,
+    ) -> Result<bool, RefError> {
+        let mut violations = Vec::new();
+        self.check(schema, location, instance, path, &mut violations)?;
+        Ok(violations.is_empty())
+    }
+
+    fn check(
+        &self,
+        schema: &Value,
+        location: &str,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) -> Result<(), RefError> {
+        let schema = match schema {
+            Value::Object(schema) => schema,
+            Value::Bool(false) => {
+                violations.push(SchemaViolation {
+                    instance_path: path.to_string(),
+                    schema_path: location.to_string(),
+                    message: "No value is allowed here".to_string(),
+                });
+                return Ok(());
+            }
+            // `true` and non-schemas accept everything
+            _ => return Ok(()),
+        };
+        let mut fail = |keyword: &str, message: String| {
+            violations.push(SchemaViolation {
+                instance_path: path.to_string(),
+                schema_path: child(location, keyword),
+                message,
+            });
+        };
+
+        if let Some(types) = schema.get("type") {
+            let allowed: Vec<&str> = match types {
+                Value::String(name) => vec![name.as_str()],
+                Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|name| has_type(instance, name)) {
+                fail(
+                    "type",
+                    format!(
+                        "Expected {}, found {}",
+                        allowed.join(" or "),
+                        type_name(instance)
+                    ),
+                );
+            }
+        }
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.iter().any(|value| json_eq(value, instance)) {
+                fail(
+                    "enum",
+                    format!(
+                        "{} is not one of {}",
+                        instance,
+                        Value::Array(values.clone())
+                    ),
+                );
+            }
+        }
+        if let Some(value) = schema.get("const") {
+            if !json_eq(value, instance) {
+                fail("const", format!("Expected {}, found {}", value, instance));
+            }
+        }
+
+        match instance {
+            Value::Number(number) => check_number(schema, number, &mut fail),
+            Value::String(string) => check_string(schema, string, &self.patterns, &mut fail),
+            _ => {}
+        }
+        match instance {
+            Value::Array(items) => {
+                check_array_bounds(schema, items, &mut fail);
+                self.check_items(schema, location, items, path, violations)?;
+            }
+            Value::Object(object) => {
+                check_object_bounds(schema, object, &mut fail);
+                self.check_properties(schema, location, instance, object, path, violations)?;
+            }
+            _ => {}
+        }
+
+        self.check_combinators(schema, location, instance, path, violations)?;
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            self.check_ref(reference, instance, path, violations)?;
+        }
+        Ok(())
+    }
+
+    fn check_ref(
+        &self,
+        reference: &str,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) -> Result<(), RefError> {
+        let (target, location) = self.lookup(reference)?;
+
+        // Following the same reference again without descending into the instance loops forever
+        let entry = (reference.to_string(), path.to_string());
+        if let Some(start) = self
+            .active_refs
+            .borrow()
+            .iter()
+            .position(|active| *active == entry)
+        {
+            let mut cycle: Vec<String> = self.active_refs.borrow()[start..]
+                .iter()
+                .map(|(reference, _)| reference.clone())
+                .collect();
+            cycle.push(reference.to_string());
+            return Err(RefError::Cycle(cycle));
+        }
+
+        self.active_refs.borrow_mut().push(entry);
+        let result = self.check(target, &location, instance, path, violations);
+        self.active_refs.borrow_mut().pop();
+        result
+    }
+
+    /// The schema `reference` points to, and its location in the specification
+    ///
+    /// References are resolved against the specification, falling back to the
+    /// root schema for references such as `#/$defs/Address`.
+    fn lookup(&self, reference: &str) -> Result<(&Value, String), RefError> {
+        let segments = parse_ref(reference)?;
+        let location = json_pointer(segments.iter().map(String::as_str));
+
+        if let [components, schemas, name, rest @ ..] = segments.as_slice() {
+            let schema = self
+                .spec
+                .components
+                .as_ref()
+                .and_then(|components| components.schemas.as_ref())
+                .and_then(|schemas| schemas.get(name));
+            if let (true, Some(schema)) =
+                (components == "components" && schemas == "schemas", schema)
+            {
+                let rest = format!("#{}", json_pointer(rest.iter().map(String::as_str)));
+                return Ok((lookup_ref(schema, &rest)?, location));
+            }
+        }
+
+        let document = self
+            .document
+            .get_or_init(|| serde_json::to_value(self.spec).unwrap_or(Value::Null));
+        if let Ok(target) = lookup_ref(document, reference) {
+            return Ok((target, location));
+        }
+        let target = lookup_ref(self.root, reference)?;
+        Ok((target, format!("{}{}", self.root_location, location)))
+    }
+
+    fn check_items(
+        &self,
+        schema: &Map<String, Value>,
+        location: &str,
+        items: &[Value],
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) -> Result<(), RefError> {
+        // Positional schemas: `prefixItems` (2020-12) or an `items` array (draft-07)
+        let (positional, keyword) = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(Value::Array(prefix)), _) => (prefix.as_slice(), "prefixItems"),
+            (_, Some(Value::Array(items))) => (items.as_slice(), "items"),
+            _ => (&[][..], "prefixItems"),
+        };
+        for (index, (item_schema, item)) in positional.iter().zip(items).enumerate() {
+            let location = child(&child(location, keyword), &index.to_string());
+            self.check(
+                item_schema,
+                &location,
+                item,
+                &child(path, &index.to_string()),
+                violations,
+            )?;
+        }
+
+        let rest = match schema.get("items") {
+            Some(Value::Array(_)) => schema
+                .get("additionalItems")
+                .map(|additional| (additional, "additionalItems")),
+            Some(items) => Some((items, "items")),
+            None => None,
+        };
+        if let Some((item_schema, keyword)) = rest {
+            for (index, item) in items.iter().enumerate().skip(positional.len()) {
+                let item_path = child(path, &index.to_string());
+                self.check(
+                    item_schema,
+                    &child(location, keyword),
+                    item,
+                    &item_path,
+                    violations,
+                )?;
+            }
+        }
+
+        if let Some(contains) = schema.get("contains") {
+            let mut matches = 0;
+            for (index, item) in items.iter().enumerate() {
+                let item_path = child(path, &index.to_string());
+                if self.is_valid(contains, &child(location, "contains"), item, &item_path)? {
+                    matches += 1;
+                }
+            }
+            let min = schema
+                .get("minContains")
+                .and_then(Value::as_u64)
+                .unwrap_or(1);
+            let max = schema.get("maxContains").and_then(Value::as_u64);
+            if matches < min {
+                violations.push(SchemaViolation {
+                    instance_path: path.to_string(),
+                    schema_path: child(location, "contains"),
+                    message: format!(
+                        "Expected at least {} item(s) matching the contains schema, found {}",
+                        min, matches
+                    ),
+                });
+            }
+            if let Some(max) = max.filter(|max| matches > *max) {
+                violations.push(SchemaViolation {
+                    instance_path: path.to_string(),
+                    schema_path: child(location, "maxContains"),
+                    message: format!(
+                        "Expected at most {} item(s) matching the contains schema, found {}",
+                        max, matches
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_properties(
+        &self,
+        schema: &Map<String, Value>,
+        location: &str,
+        instance: &Value,
+        object: &Map<String, Value>,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) -> Result<(), RefError> {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let mut patterns = Vec::new();
+        for (source, property) in schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            let location = child(&child(location, "patternProperties"), source);
+            match self.patterns.get(source) {
+                Ok(pattern) => patterns.push((pattern, location, property)),
+                Err(reason) => violations.push(SchemaViolation {
+                    instance_path: path.to_string(),
+                    schema_path: location,
+                    message: unsupported_pattern(source, &reason),
+                }),
+            }
+        }
+
+        for (name, value) in object {
+            let value_path = child(path, name);
+            let mut evaluated =
+                if let Some(property) = properties.and_then(|properties| properties.get(name)) {
+                    let location = child(&child(location, "properties"), name);
+                    self.check(property, &location, value, &value_path, violations)?;
+                    true
+                } else {
+                    false
+                };
+            for (pattern, location, property) in &patterns {
+                if pattern.is_match(name) {
+                    self.check(property, location, value, &value_path, violations)?;
+                    evaluated = true;
+                }
+            }
+            match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) if !evaluated => violations.push(SchemaViolation {
+                    instance_path: value_path.clone(),
+                    schema_path: child(location, "additionalProperties"),
+                    message: format!("Property '{}' is not allowed", name),
+                }),
+                Some(additional) if !evaluated => {
+                    let location = child(location, "additionalProperties");
+                    self.check(additional, &location, value, &value_path, violations)?;
+                }
+                _ => {}
+            }
+            if let Some(names) = schema.get("propertyNames") {
+                let name_value = Value::String(name.clone());
+                let location = child(location, "propertyNames");
+                self.check(names, &location, &name_value, &value_path, violations)?;
+            }
+        }
+
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    violations.push(SchemaViolation {
+                        instance_path: path.to_string(),
+                        schema_path: child(location, "required"),
+                        message: format!("Missing required property '{}'", name),
+                    });
+                }
+            }
+        }
+
+        // `dependentRequired`/`dependentSchemas` (2020-12) and `dependencies` (draft-07)
+        for keyword in ["dependentRequired", "dependentSchemas", "dependencies"] {
+            let Some(dependencies) = schema.get(keyword).and_then(Value::as_object) else {
+                continue;
+            };
+            for (name, dependency) in dependencies
+                .iter()
+                .filter(|(name, _)| object.contains_key(*name))
+            {
+                let location = child(&child(location, keyword), name);
+                match dependency {
+                    Value::Array(required) => {
+                        for missing in required
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .filter(|required| !object.contains_key(*required))
+                        {
+                            violations.push(SchemaViolation {
+                                instance_path: path.to_string(),
+                                schema_path: location.clone(),
+                                message: format!(
+                                    "Property '{}' is required when '{}' is present",
+                                    missing, name
+                                ),
+                            });
+                        }
+                    }
+                    dependency => self.check(dependency, &location, instance, path, violations)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_combinators(
+        &self,
+        schema: &Map<String, Value>,
+        location: &str,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) -> Result<(), RefError> {
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for (index, subschema) in schemas.iter().enumerate() {
+                let location = child(&child(location, "allOf"), &index.to_string());
+                self.check(subschema, &location, instance, path, violations)?;
+            }
+        }
+
+        for keyword in ["anyOf", "oneOf"] {
+            let Some(Value::Array(schemas)) = schema.get(keyword) else {
+                continue;
+            };
+            let mut matches = 0;
+            for (index, subschema) in schemas.iter().enumerate() {
+                let location = child(&child(location, keyword), &index.to_string());
+                if self.is_valid(subschema, &location, instance, path)? {
+                    matches += 1;
+                }
+            }
+            let message = match (keyword, matches) {
+                (_, 0) => format!("Value does not match any of the {} schemas", keyword),
+                ("oneOf", matches) if matches > 1 => format!(
+                    "Value matches {} oneOf schemas, expected exactly one",
+                    matches
+                ),
+                _ => continue,
+            };
+            violations.push(SchemaViolation {
+                instance_path: path.to_string(),
+                schema_path: child(location, keyword),
+                message,
+            });
+        }
+
+        if let Some(not) = schema.get("not") {
+            if self.is_valid(not, &child(location, "not"), instance, path)? {
+                violations.push(SchemaViolation {
+                    instance_path: path.to_string(),
+                    schema_path: child(location, "not"),
+                    message: "Value must not match the not schema".to_string(),
+                });
+            }
+        }
+
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.is_valid(condition, &child(location, "if"), instance, path)? {
+                "then"
+            } else {
+                "else"
+            };
+            if let Some(subschema) = schema.get(branch) {
+                self.check(
+                    subschema,
+                    &child(location, branch),
+                    instance,
+                    path,
+                    violations,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_number(schema: &Map<String, Value>, number: &Number, fail: &mut impl FnMut(&str, String)) {
+    let Some(value) = number.as_f64() else {
+        return;
+    };
+    let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+
+    if let Some(minimum) = bound("minimum").filter(|minimum| value < *minimum) {
+        fail(
+            "minimum",
+            format!("{} is less than the minimum of {}", number, minimum),
+        );
+    }
+    if let Some(maximum) = bound("maximum").filter(|maximum| value > *maximum) {
+        fail(
+            "maximum",
+            format!("{} is greater than the maximum of {}", number, maximum),
+        );
+    }
+    if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| value <= *minimum) {
+        fail(
+            "exclusiveMinimum",
+            format!(
+                "{} is not greater than the exclusive minimum of {}",
+                number, minimum
+            ),
+        );
+    }
+    if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| value >= *maximum) {
+        fail(
+            "exclusiveMaximum",
+            format!(
+                "{} is not less than the exclusive maximum of {}",
+                number, maximum
+            ),
+        );
+    }
+    if let Some(divisor) = bound("multipleOf").filter(|divisor| *divisor > 0.0) {
+        let quotient = value / divisor;
+        if (quotient - quotient.round()).abs() > 1e-9 {
+            fail(
+                "multipleOf",
+                format!("{} is not a multiple of {}", number, divisor),
+            );
+        }
+    }
+}
+
+fn check_string(
+    schema: &Map<String, Value>,
+    string: &str,
+    patterns: &Patterns,
+    fail: &mut impl FnMut(&str, String),
+) {
+    let length = string.chars().count() as u64;
+    if let Some(min) = schema
+        .get("minLength")
+        .and_then(Value::as_u64)
+        .filter(|min| length < *min)
+    {
+        fail(
+            "minLength",
+            format!("Expected at least {} character(s), found {}", min, length),
+        );
+    }
+    if let Some(max) = schema
+        .get("maxLength")
+        .and_then(Value::as_u64)
+        .filter(|max| length > *max)
+    {
+        fail(
+            "maxLength",
+            format!("Expected at most {} character(s), found {}", max, length),
+        );
+    }
+    if let Some(source) = schema.get("pattern").and_then(Value::as_str) {
+        match patterns.get(source) {
+            Ok(pattern) if !pattern.is_match(string) => fail(
+                "pattern",
+                format!("'{}' does not match the pattern '{}'", string, source),
+            ),
+            Ok(_) => {}
+            Err(reason) => fail("pattern", unsupported_pattern(source, &reason)),
+        }
+    }
+}
+
+fn unsupported_pattern(source: &str, reason: &str) -> String {
+    format!("Pattern '{}' cannot be checked: {}", source, reason)
+}
+
+fn check_array_bounds(
+    schema: &Map<String, Value>,
+    items: &[Value],
+    fail: &mut impl FnMut(&str, String),
+) {
+    let count = items.len() as u64;
+    if let Some(min) = schema
+        .get("minItems")
+        .and_then(Value::as_u64)
+        .filter(|min| count < *min)
+    {
+        fail(
+            "minItems",
+            format!("Expected at least {} item(s), found {}", min, count),
+        );
+    }
+    if let Some(max) = schema
+        .get("maxItems")
+        .and_then(Value::as_u64)
+        .filter(|max| count > *max)
+    {
+        fail(
+            "maxItems",
+            format!("Expected at most {} item(s), found {}", max, count),
+        );
+    }
+    if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+        let duplicate = items.iter().enumerate().find_map(|(index, item)| {
+            items[..index]
+                .iter()
+                .position(|other| json_eq(other, item))
+                .map(|first| (first, index))
+        });
+        if let Some((first, index)) = duplicate {
+            fail(
+                "uniqueItems",
+                format!("Items {} and {} are equal", first, index),
+            );
+        }
+    }
+}
+
+fn check_object_bounds(
+    schema: &Map<String, Value>,
+    object: &Map<String, Value>,
+    fail: &mut impl FnMut(&str, String),
+) {
+    let count = object.len() as u64;
+    if let Some(min) = schema
+        .get("minProperties")
+        .and_then(Value::as_u64)
+        .filter(|min| count < *min)
+    {
+        fail(
+            "minProperties",
+            format!("Expected at least {} properties, found {}", min, count),
+        );
+    }
+    if let Some(max) = schema
+        .get("maxProperties")
+        .and_then(Value::as_u64)
+        .filter(|max| count > *max)
+    {
+        fail(
+            "maxProperties",
+            format!("Expected at most {} properties, found {}", max, count),
+        );
+    }
+}
+
+fn has_type(instance: &Value, name: &str) -> bool {
+    match name {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => match instance {
+            Value::Number(number) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|n| n.fract() == 0.0)
+            }
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) if has_type(instance, "integer") => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// JSON equality, where numbers are equal if their values are (`1 == 1.0`)
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a == b || a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| json_eq(a, b)))
+        }
+        (a, b) => a == b,
+    }
+}
+
+/// JSON Pointer `pointer` extended by `segment`
+fn child(pointer: &str, segment: &str) -> String {
+    format!("{}{}", pointer, json_pointer([segment]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use serde_json::json;
+
+    fn spec() -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "channels": {},
+            "components": {
+                "schemas": {
+                    "Address": {
+                        "type": "object",
+                        "properties": {"city": {"type": "string", "minLength": 1}},
+                        "required": ["city"]
+                    },
+                    "Node": {
+                        "type": "object",
+                        "properties": {"next": {"$ref": "#/components/schemas/Node"}}
+                    },
+                    "Loop": {"$ref": "#/components/schemas/Loop"}
+                }
+            }
+        }))
+    }
+
+    fn violations(schema: &Value, instance: &Value) -> Vec<(String, String)> {
+        let spec = spec();
+        SchemaValidator::new(&spec, schema, &["payload"])
+            .validate(instance)
+            .unwrap()
+            .into_iter()
+            .map(|violation| (violation.instance_path, violation.schema_path))
+            .collect()
+    }
+
+    #[test]
+    fn test_core_keywords() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "email": {"type": "string", "pattern": "^[^@]+@[^@]+$"},
+                "tags": {"type": "array", "items": {"enum": ["a", "b"]}, "uniqueItems": true},
+                "kind": {"const": "user"}
+            },
+            "required": ["id", "kind"],
+            "additionalProperties": false
+        });
+
+        let valid = json!({"id": 1.0, "email": "a@b", "tags": ["a", "b"], "kind": "user"});
+        assert!(violations(&schema, &valid).is_empty());
+
+        let invalid = json!({"id": 0, "email": "nope", "tags": ["a", "a", "c"], "extra": 1});
+        let paths = violations(&schema, &invalid);
+        for expected in [
+            ("", "/payload/required"),
+            ("/id", "/payload/properties/id/minimum"),
+            ("/email", "/payload/properties/email/pattern"),
+            ("/tags", "/payload/properties/tags/uniqueItems"),
+            ("/tags/2", "/payload/properties/tags/items/enum"),
+            ("/extra", "/payload/additionalProperties"),
+        ] {
+            assert!(
+                paths.contains(&(expected.0.to_string(), expected.1.to_string())),
+                "missing {:?} in {:?}",
+                expected,
+                paths
+            );
+        }
+    }
+
+    #[test]
+    fn test_combinators() {
+        let schema = json!({
+            "oneOf": [{"type": "string"}, {"type": "number"}],
+            "not": {"const": 0},
+            "if": {"type": "string"},
+            "then": {"maxLength": 3}
+        });
+        assert!(violations(&schema, &json!("abc")).is_empty());
+        assert!(violations(&schema, &json!(2)).is_empty());
+        assert_eq!(
+            violations(&schema, &json!("abcd")),
+            [(String::new(), "/payload/then/maxLength".to_string())]
+        );
+        assert_eq!(violations(&schema, &json!(true)).len(), 1);
+        assert_eq!(violations(&schema, &json!(0)).len(), 1);
+    }
+
+    #[test]
+    fn test_refs_resolve_into_components() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "address": {"$ref": "#/components/schemas/Address"},
+                "list": {"$ref": "#/components/schemas/Node"},
+                "code": {"$ref": "#/$defs/Code"}
+            },
+            "$defs": {"Code": {"type": "string"}}
+        });
+        let instance = json!({
+            "address": {"city": ""},
+            "list": {"next": {"next": {"next": 1}}},
+            "code": 7
+        });
+        assert_eq!(
+            violations(&schema, &instance),
+            [
+                (
+                    "/address/city".to_string(),
+                    "/components/schemas/Address/properties/city/minLength".to_string()
+                ),
+                (
+                    "/list/next/next/next".to_string(),
+                    "/components/schemas/Node/type".to_string()
+                ),
+                ("/code".to_string(), "/payload/$defs/Code/type".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ref_errors() {
+        let spec = spec();
+        let missing = json!({"$ref": "#/components/schemas/Missing"});
+        assert!(matches!(
+            SchemaValidator::new(&spec, &missing, &[]).validate(&json!(1)),
+            Err(RefError::NotFound(_))
+        ));
+        let cycle = json!({"$ref": "#/components/schemas/Loop"});
+        assert!(matches!(
+            SchemaValidator::new(&spec, &cycle, &[]).validate(&json!(1)),
+            Err(RefError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_patterns_are_reported() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"code": {"type": "string", "pattern": "^(?=A)[A-Z]+$"}},
+            "patternProperties": {"^x-(?!internal)": {"type": "string"}}
+        });
+        assert_eq!(
+            violations(&schema, &json!({"code": "ABC", "x-trace": "1"})),
+            [
+                (
+                    String::new(),
+                    "/payload/patternProperties/^x-(?!internal)".to_string()
+                ),
+                (
+                    "/code".to_string(),
+                    "/payload/properties/code/pattern".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -2,10 +2,12 @@
 //!
 //! This module contains types related to channels, messages, and their metadata.
 
+use crate::resolve::local_ref;
+use crate::spec::extensions::deserialize_extensions;
 use crate::spec::operation::MessageReference;
-use crate::spec::ExternalDocumentation;
+use crate::spec::{Extensions, ExternalDocumentation};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Message or reference to a component message
 ///
@@ -30,14 +32,14 @@ impl MessageOrRef {
     /// Create a MessageOrRef from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(MessageReference {
-            ref_path: format!("#/components/messages/{}", component_name),
+            ref_path: local_ref(["components", "messages", component_name]),
         })
     }
 
     /// Create a MessageOrRef from a channel message reference
     pub fn channel_ref(channel_name: &str, message_name: &str) -> Self {
         Self::Ref(MessageReference {
-            ref_path: format!("#/channels/{}/messages/{}", channel_name, message_name),
+            ref_path: local_ref(["channels", channel_name, "messages", message_name]),
         })
     }
 
@@ -69,7 +71,7 @@ pub struct Channel {
 
     /// Messages available on this channel
     /// Can contain both inline messages and references to component messages
-    pub messages: HashMap<String, MessageOrRef>,
+    pub messages: IndexMap<String, MessageOrRef>,
 
     /// Servers this channel is available on
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,11 +79,19 @@ pub struct Channel {
 
     /// Channel parameters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<HashMap<String, Parameter>>,
+    pub parameters: Option<IndexMap<String, Parameter>>,
 
     /// Protocol-specific bindings (inline or reference to component)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bindings: Option<ChannelBindingsOrRef>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Correlation ID definition
@@ -93,10 +103,19 @@ pub struct CorrelationId {
     /// Description of the correlation ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Message definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Message {
     /// Unique message identifier
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -152,6 +171,14 @@ pub struct Message {
     /// Protocol-specific message bindings
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bindings: Option<MessageBindingsOrRef>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Message payload schema
@@ -163,7 +190,7 @@ pub struct MessagePayload {
 }
 
 /// Tag definition for messages and operations
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Tag {
     /// Tag name
     pub name: String,
@@ -171,32 +198,58 @@ pub struct Tag {
     /// Tag description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+// Extensions are left out of the hash: `serde_json::Value` does not implement `Hash`
+impl std::hash::Hash for Tag {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.description.hash(state);
+    }
 }
 
 /// Channel bindings or reference to component bindings
 ///
 /// In AsyncAPI 3.0, channel bindings can be either:
-/// - Inline bindings (JSON object)
+/// - Inline bindings
 /// - References to reusable component bindings using `$ref`
+///
+/// Bindings that do not fit [`ChannelBindings`](crate::ChannelBindings) (other protocols
+/// or fields) are kept as-is in `Custom`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ChannelBindingsOrRef {
-    /// Inline bindings (JSON object)
-    Bindings(serde_json::Value),
+    /// Inline bindings
+    Bindings(crate::protocol::ChannelBindings),
     /// Reference to component bindings
     Ref(crate::spec::operation::MessageReference),
+    /// Inline bindings outside the typed model
+    Custom(serde_json::Value),
 }
 
 impl ChannelBindingsOrRef {
-    /// Create ChannelBindingsOrRef from inline bindings
-    pub fn bindings(bindings: serde_json::Value) -> Self {
+    /// Create `ChannelBindingsOrRef` from inline bindings
+    pub const fn bindings(bindings: crate::protocol::ChannelBindings) -> Self {
         Self::Bindings(bindings)
     }
 
-    /// Create ChannelBindingsOrRef from a component reference
+    /// Create `ChannelBindingsOrRef` from raw bindings outside the typed model
+    pub const fn custom(bindings: serde_json::Value) -> Self {
+        Self::Custom(bindings)
+    }
+
+    /// Create `ChannelBindingsOrRef` from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(crate::spec::operation::MessageReference {
-            ref_path: format!("#/components/channelBindings/{}", component_name),
+            ref_path: local_ref(["components", "channelBindings", component_name]),
         })
     }
 }
@@ -206,13 +259,17 @@ impl ChannelBindingsOrRef {
 /// In AsyncAPI 3.0, message traits can be either:
 /// - Inline trait definitions
 /// - References to reusable components using `$ref`
+///
+/// The reference variant comes first: every field of [`MessageTrait`](crate::spec::MessageTrait)
+/// is optional, so an untagged `$ref` object would otherwise deserialize as an
+/// empty inline trait.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum MessageTraitOrRef {
-    /// Inline message trait definition
-    Trait(crate::spec::MessageTrait),
     /// Reference to a component message trait
     Ref(crate::spec::operation::MessageReference),
+    /// Inline message trait definition
+    Trait(crate::spec::MessageTrait),
 }
 
 impl MessageTraitOrRef {
@@ -224,7 +281,7 @@ impl MessageTraitOrRef {
     /// Create a MessageTraitOrRef from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(crate::spec::operation::MessageReference {
-            ref_path: format!("#/components/messageTraits/{}", component_name),
+            ref_path: local_ref(["components", "messageTraits", component_name]),
         })
     }
 }
@@ -232,43 +289,75 @@ impl MessageTraitOrRef {
 /// Message bindings or reference to component bindings
 ///
 /// In AsyncAPI 3.0, message bindings can be either:
-/// - Inline bindings (JSON object)
+/// - Inline bindings
 /// - References to reusable component bindings using `$ref`
+///
+/// Bindings that do not fit [`MessageBindings`](crate::MessageBindings) (other protocols
+/// or fields) are kept as-is in `Custom`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum MessageBindingsOrRef {
-    /// Inline bindings (JSON object)
-    Bindings(serde_json::Value),
+    /// Inline bindings
+    Bindings(crate::protocol::MessageBindings),
     /// Reference to component bindings
     Ref(crate::spec::operation::MessageReference),
+    /// Inline bindings outside the typed model
+    Custom(serde_json::Value),
 }
 
 impl MessageBindingsOrRef {
-    /// Create MessageBindingsOrRef from inline bindings
-    pub fn bindings(bindings: serde_json::Value) -> Self {
+    /// Create `MessageBindingsOrRef` from inline bindings
+    pub const fn bindings(bindings: crate::protocol::MessageBindings) -> Self {
         Self::Bindings(bindings)
     }
 
-    /// Create MessageBindingsOrRef from a component reference
+    /// Create `MessageBindingsOrRef` from raw bindings outside the typed model
+    pub const fn custom(bindings: serde_json::Value) -> Self {
+        Self::Custom(bindings)
+    }
+
+    /// Create `MessageBindingsOrRef` from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(crate::spec::operation::MessageReference {
-            ref_path: format!("#/components/messageBindings/{}", component_name),
+            ref_path: local_ref(["components", "messageBindings", component_name]),
         })
     }
 }
 
 /// Parameter definition for channels
+///
+/// Describes a `{name}` placeholder in a channel address.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Parameter {
     /// Parameter description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// Allowed values for the parameter
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<String>>,
+
+    /// Default value for the parameter (should be one of `enum_values`, if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+
+    /// Example values for the parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<String>>,
+
     /// Parameter schema
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<serde_json::Value>,
 
-    /// Parameter location
+    /// Runtime expression locating the parameter value in a message (e.g. `$message.payload#/tenantId`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
@@ -2,46 +2,72 @@
 //!
 //! This module contains types for reusable AsyncAPI components.
 
-use crate::spec::{Message, MessageTrait, OperationTrait, Parameter, SecurityScheme};
+use crate::spec::extensions::deserialize_extensions;
+use crate::spec::{
+    ChannelBindingsOrRef, Extensions, Message, MessageBindingsOrRef, MessageTrait,
+    OperationBindingsOrRef, OperationReply, OperationReplyAddress, OperationTrait, Parameter,
+    SecurityScheme, ServerBindingsOrRef,
+};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Reusable components
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct Components {
     /// Message components
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub messages: Option<HashMap<String, Message>>,
+    pub messages: Option<IndexMap<String, Message>>,
 
     /// Schema components
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schemas: Option<HashMap<String, serde_json::Value>>,
+    pub schemas: Option<IndexMap<String, serde_json::Value>>,
 
     /// Security scheme components
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_schemes: Option<HashMap<String, SecurityScheme>>,
+    pub security_schemes: Option<IndexMap<String, SecurityScheme>>,
 
     /// Parameter components (reusable parameter definitions)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<HashMap<String, Parameter>>,
+    pub parameters: Option<IndexMap<String, Parameter>>,
 
     /// Channel binding components (reusable channel bindings)
-    #[serde(rename = "channelBindings", skip_serializing_if = "Option::is_none")]
-    pub channel_bindings: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_bindings: Option<IndexMap<String, ChannelBindingsOrRef>>,
 
     /// Message binding components (reusable message bindings)
-    #[serde(rename = "messageBindings", skip_serializing_if = "Option::is_none")]
-    pub message_bindings: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_bindings: Option<IndexMap<String, MessageBindingsOrRef>>,
+
+    /// Operation binding components (reusable operation bindings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_bindings: Option<IndexMap<String, OperationBindingsOrRef>>,
 
     /// Server binding components (reusable server bindings)
-    #[serde(rename = "serverBindings", skip_serializing_if = "Option::is_none")]
-    pub server_bindings: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_bindings: Option<IndexMap<String, ServerBindingsOrRef>>,
 
     /// Operation trait components (reusable operation traits)
-    #[serde(rename = "operationTraits", skip_serializing_if = "Option::is_none")]
-    pub operation_traits: Option<HashMap<String, OperationTrait>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_traits: Option<IndexMap<String, OperationTrait>>,
 
     /// Message trait components (reusable message traits)
-    #[serde(rename = "messageTraits", skip_serializing_if = "Option::is_none")]
-    pub message_traits: Option<HashMap<String, MessageTrait>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_traits: Option<IndexMap<String, MessageTrait>>,
+
+    /// Operation reply components (reusable request/reply definitions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replies: Option<IndexMap<String, OperationReply>>,
+
+    /// Operation reply address components (reusable reply addresses)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_addresses: Option<IndexMap<String, OperationReplyAddress>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
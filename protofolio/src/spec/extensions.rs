@@ -0,0 +1,21 @@
+//! Specification extensions
+//!
+//! AsyncAPI objects can carry extra fields whose names start with `x-`
+//! (e.g. `x-owner`). Each spec object keeps them in an [`Extensions`] map that
+//! is flattened into the object, so they survive parsing and serialization.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+
+/// Specification extensions of an object (map of `x-` field name to value)
+pub type Extensions = IndexMap<String, serde_json::Value>;
+
+/// Collect the `x-` fields of an object; other unknown fields are ignored
+pub fn deserialize_extensions<'de, D>(deserializer: D) -> Result<Extensions, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut extensions = Extensions::deserialize(deserializer)?;
+    extensions.retain(|name, _| name.starts_with("x-"));
+    Ok(extensions)
+}
@@ -2,8 +2,14 @@
 //!
 //! This module contains types related to API information and server definitions.
 
-use crate::spec::SecurityRequirement;
+use crate::error::ServerUrlError;
+use crate::resolve::local_ref;
+use crate::spec::extensions::deserialize_extensions;
+use crate::spec::{Extensions, MessageReference, SecurityRequirement, Tag};
+use crate::validation::address_placeholders;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// External documentation reference
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +20,14 @@ pub struct ExternalDocumentation {
     /// Description of the external documentation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Contact information for the API
@@ -30,6 +44,14 @@ pub struct Contact {
     /// Email address of the contact person/organization
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// License information for the API
@@ -41,10 +63,19 @@ pub struct License {
     /// URL pointing to the license
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Information about the API
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Info {
     /// Title of the API
     pub title: String,
@@ -71,6 +102,14 @@ pub struct Info {
     /// URL to the Terms of Service
     #[serde(skip_serializing_if = "Option::is_none")]
     pub terms_of_service: Option<String>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Server variable definition
@@ -91,26 +130,279 @@ pub struct ServerVariable {
     /// Example value for this variable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Vec<String>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Server definition
+///
+/// In AsyncAPI 3.0 a server is described by its `host` (optionally with a port)
+/// and an optional `pathname`, rather than a single URL.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Server {
-    /// Server URL (may contain variables like {host} or {port})
-    pub url: String,
+    /// Server host name, optionally including the port (may contain variables like {host} or {port})
+    pub host: String,
 
     /// Protocol used (e.g., "nats", "kafka", "mqtt")
     pub protocol: String,
 
+    /// Version of the protocol used for the connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<String>,
+
+    /// Path to a resource in the host (e.g., "/production")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pathname: Option<String>,
+
+    /// Human-friendly title for the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Short summary of the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Server description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// Tags for logical grouping of servers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+
+    /// External documentation for this server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocumentation>,
+
+    /// Protocol-specific server bindings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ServerBindingsOrRef>,
+
     /// Security requirements for this server
     #[serde(skip_serializing_if = "Option::is_none")]
     pub security: Option<Vec<SecurityRequirement>>,
 
-    /// Server variables (for templated URLs)
+    /// Server variables (for templated host and pathname)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub variables: Option<std::collections::HashMap<String, ServerVariable>>,
+    pub variables: Option<indexmap::IndexMap<String, ServerVariable>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+impl Server {
+    /// The concrete URL of this server, e.g. `nats://broker.example.com:4222/prod`
+    ///
+    /// Every `{placeholder}` in the host and pathname is replaced by its value in
+    /// `values`, falling back to the variable's `default`. Values for variables
+    /// the server does not use are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerUrlError` if a placeholder has no variable definition, has
+    /// neither a value nor a default, or gets a value outside the variable's `enum`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use protofolio::Server;
+    /// use std::collections::HashMap;
+    ///
+    /// let server: Server = serde_json::from_value(serde_json::json!({
+    ///     "host": "{host}:{port}",
+    ///     "protocol": "nats",
+    ///     "variables": {
+    ///         "host": {"default": "localhost"},
+    ///         "port": {"default": "4222", "enum": ["4222", "4223"]}
+    ///     }
+    /// }))?;
+    ///
+    /// assert_eq!(server.resolve_url(&HashMap::new())?, "nats://localhost:4222");
+    ///
+    /// let values = HashMap::from([("host".to_string(), "broker.internal".to_string())]);
+    /// assert_eq!(server.resolve_url(&values)?, "nats://broker.internal:4222");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve_url(&self, values: &HashMap<String, String>) -> Result<String, ServerUrlError> {
+        let variables = self.variables.as_ref();
+        let host = render_template(&self.host, variables, values)?;
+        let pathname = match self.pathname {
+            Some(ref pathname) => render_template(pathname, variables, values)?,
+            None => String::new(),
+        };
+        Ok(format!("{}://{}{}", self.protocol, host, pathname))
+    }
+}
+
+/// Replace every placeholder in `template` by the value of its variable
+fn render_template(
+    template: &str,
+    variables: Option<&IndexMap<String, ServerVariable>>,
+    values: &HashMap<String, String>,
+) -> Result<String, ServerUrlError> {
+    let invalid =
+        |problem: String| ServerUrlError::InvalidTemplate(format!("'{}': {}", template, problem));
+    address_placeholders(template).map_err(invalid)?;
+
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        rendered.push_str(&rest[..open]);
+        let Some((name, after)) = rest[open + 1..].split_once('}') else {
+            return Err(invalid("unclosed '{'".to_string()));
+        };
+        rendered.push_str(variable_value(name, variables, values)?);
+        rest = after;
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// The value of variable `name`: the given value or else its default, checked
+/// against its enum
+fn variable_value<'a>(
+    name: &str,
+    variables: Option<&'a IndexMap<String, ServerVariable>>,
+    values: &'a HashMap<String, String>,
+) -> Result<&'a str, ServerUrlError> {
+    let Some(variable) = variables.and_then(|variables| variables.get(name)) else {
+        return Err(ServerUrlError::UndefinedVariable(name.to_string()));
+    };
+    let Some(value) = values.get(name).or(variable.default.as_ref()) else {
+        return Err(ServerUrlError::MissingValue(name.to_string()));
+    };
+    if let Some(ref allowed) = variable.enum_values {
+        if !allowed.contains(value) {
+            return Err(ServerUrlError::NotAllowed {
+                variable: name.to_string(),
+                value: value.clone(),
+                allowed: allowed.clone(),
+            });
+        }
+    }
+    Ok(value)
+}
+
+/// Server bindings or reference to component bindings
+///
+/// In AsyncAPI 3.0, server bindings can be either:
+/// - Inline bindings
+/// - References to reusable component bindings using `$ref`
+///
+/// Bindings that do not fit [`ServerBindings`](crate::ServerBindings) (other protocols
+/// or fields) are kept as-is in `Custom`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)] // Same shape as the other `OrRef` enums
+pub enum ServerBindingsOrRef {
+    /// Inline bindings
+    Bindings(crate::protocol::ServerBindings),
+    /// Reference to component bindings
+    Ref(MessageReference),
+    /// Inline bindings outside the typed model
+    Custom(serde_json::Value),
+}
+
+impl ServerBindingsOrRef {
+    /// Create `ServerBindingsOrRef` from inline bindings
+    pub const fn bindings(bindings: crate::protocol::ServerBindings) -> Self {
+        Self::Bindings(bindings)
+    }
+
+    /// Create `ServerBindingsOrRef` from raw bindings outside the typed model
+    pub const fn custom(bindings: serde_json::Value) -> Self {
+        Self::Custom(bindings)
+    }
+
+    /// Create `ServerBindingsOrRef` from a component reference
+    pub fn component_ref(component_name: &str) -> Self {
+        Self::Ref(MessageReference {
+            ref_path: local_ref(["components", "serverBindings", component_name]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn server(value: serde_json::Value) -> Server {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        let server = server(json!({
+            "host": "{host}:{port}",
+            "pathname": "/{env}",
+            "protocol": "kafka",
+            "variables": {
+                "host": {"default": "localhost"},
+                "port": {"default": "9092", "enum": ["9092", "9093"]},
+                "env": {}
+            }
+        }));
+
+        let values = HashMap::from([
+            ("env".to_string(), "prod".to_string()),
+            ("port".to_string(), "9093".to_string()),
+            ("unused".to_string(), "ignored".to_string()),
+        ]);
+        assert_eq!(
+            server.resolve_url(&values),
+            Ok("kafka://localhost:9093/prod".to_string())
+        );
+
+        assert_eq!(
+            server.resolve_url(&HashMap::new()),
+            Err(ServerUrlError::MissingValue("env".to_string()))
+        );
+
+        let values = HashMap::from([
+            ("env".to_string(), "prod".to_string()),
+            ("port".to_string(), "1234".to_string()),
+        ]);
+        assert_eq!(
+            server.resolve_url(&values),
+            Err(ServerUrlError::NotAllowed {
+                variable: "port".to_string(),
+                value: "1234".to_string(),
+                allowed: vec!["9092".to_string(), "9093".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_url_without_variables() {
+        let plain = server(json!({"host": "broker.example.com", "protocol": "mqtt"}));
+        assert_eq!(
+            plain.resolve_url(&HashMap::new()),
+            Ok("mqtt://broker.example.com".to_string())
+        );
+
+        let undefined = server(json!({"host": "{host}", "protocol": "mqtt"}));
+        assert_eq!(
+            undefined.resolve_url(&HashMap::new()),
+            Err(ServerUrlError::UndefinedVariable("host".to_string()))
+        );
+
+        let malformed = server(json!({"host": "{host", "protocol": "mqtt"}));
+        assert!(matches!(
+            malformed.resolve_url(&HashMap::new()),
+            Err(ServerUrlError::InvalidTemplate(_))
+        ));
+    }
 }
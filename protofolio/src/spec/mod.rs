@@ -2,9 +2,16 @@
 //!
 //! This module contains the core data structures for AsyncAPI 3.0.
 //! Currently implements MVP subset, structured for full AsyncAPI 3.0 support.
+//!
+//! Every object keeps its specification extensions (`x-` fields) in an
+//! [`Extensions`] map, so they are preserved when a spec is parsed and written back.
+//!
+//! All maps are [`IndexMap`]s: entries serialize in insertion order, so the same
+//! code always produces byte-identical JSON and YAML documents.
 
 mod channel;
 mod components;
+mod extensions;
 mod info;
 mod operation;
 mod security;
@@ -12,13 +19,15 @@ mod traits;
 
 pub use channel::*;
 pub use components::*;
+pub use extensions::Extensions;
 pub use info::*;
 pub use operation::*;
 pub use security::*;
 pub use traits::*;
 
+use extensions::deserialize_extensions;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Root AsyncAPI specification document
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,22 +57,30 @@ pub struct AsyncApiSpec {
     /// Root-level tags (reusable tag definitions)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Channel definitions (map of channel name to Channel)
-pub type Channels = HashMap<String, Channel>;
+pub type Channels = IndexMap<String, Channel>;
 
 /// Message definitions (map of message name to Message or MessageOrRef)
 ///
 /// Note: In channels, messages use MessageOrRef to support both inline definitions
 /// and component references. In components, messages are always inline Message definitions.
-pub type Messages = HashMap<String, Message>;
+pub type Messages = IndexMap<String, Message>;
 
 /// Message or reference type for channels
 pub use channel::MessageOrRef;
 
 /// Server definitions (map of server name to Server)
-pub type Servers = HashMap<String, Server>;
+pub type Servers = IndexMap<String, Server>;
 
 /// Operation definitions (map of operation ID to Operation)
-pub type Operations = HashMap<String, Operation>;
+pub type Operations = IndexMap<String, Operation>;
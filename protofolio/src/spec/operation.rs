@@ -2,11 +2,15 @@
 //!
 //! This module contains types related to operations (send/receive actions).
 
-use crate::spec::{ExternalDocumentation, Tag};
+use crate::resolve::local_ref;
+use crate::spec::extensions::deserialize_extensions;
+use crate::spec::{Extensions, ExternalDocumentation, Tag};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// Operation definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Operation {
     /// Unique operation identifier
     pub operation_id: String,
@@ -43,6 +47,18 @@ pub struct Operation {
     /// Protocol-specific operation bindings
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bindings: Option<OperationBindingsOrRef>,
+
+    /// Reply definition for request/reply operations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply: Option<OperationReplyOrRef>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Channel reference in operation
@@ -66,13 +82,17 @@ pub struct MessageReference {
 /// In AsyncAPI 3.0, operation traits can be either:
 /// - Inline trait definitions
 /// - References to reusable components using `$ref`
+///
+/// The reference variant comes first: every field of [`OperationTrait`](crate::spec::OperationTrait)
+/// is optional, so an untagged `$ref` object would otherwise deserialize as an
+/// empty inline trait.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OperationTraitOrRef {
-    /// Inline operation trait definition
-    Trait(crate::spec::OperationTrait),
     /// Reference to a component operation trait
     Ref(MessageReference),
+    /// Inline operation trait definition
+    Trait(crate::spec::OperationTrait),
 }
 
 impl OperationTraitOrRef {
@@ -84,7 +104,7 @@ impl OperationTraitOrRef {
     /// Create an OperationTraitOrRef from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(MessageReference {
-            ref_path: format!("#/components/operationTraits/{}", component_name),
+            ref_path: local_ref(["components", "operationTraits", component_name]),
         })
     }
 }
@@ -92,27 +112,136 @@ impl OperationTraitOrRef {
 /// Operation bindings or reference to component bindings
 ///
 /// In AsyncAPI 3.0, operation bindings can be either:
-/// - Inline bindings (JSON object)
+/// - Inline bindings
 /// - References to reusable component bindings using `$ref`
+///
+/// Bindings that do not fit [`OperationBindings`](crate::OperationBindings) (other protocols
+/// or fields) are kept as-is in `Custom`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OperationBindingsOrRef {
-    /// Inline bindings (JSON object)
-    Bindings(serde_json::Value),
+    /// Inline bindings
+    Bindings(crate::protocol::OperationBindings),
     /// Reference to component bindings
     Ref(MessageReference),
+    /// Inline bindings outside the typed model
+    Custom(serde_json::Value),
 }
 
 impl OperationBindingsOrRef {
-    /// Create OperationBindingsOrRef from inline bindings
-    pub fn bindings(bindings: serde_json::Value) -> Self {
+    /// Create `OperationBindingsOrRef` from inline bindings
+    pub const fn bindings(bindings: crate::protocol::OperationBindings) -> Self {
         Self::Bindings(bindings)
     }
 
-    /// Create OperationBindingsOrRef from a component reference
+    /// Create `OperationBindingsOrRef` from raw bindings outside the typed model
+    pub const fn custom(bindings: serde_json::Value) -> Self {
+        Self::Custom(bindings)
+    }
+
+    /// Create `OperationBindingsOrRef` from a component reference
+    pub fn component_ref(component_name: &str) -> Self {
+        Self::Ref(MessageReference {
+            ref_path: local_ref(["components", "operationBindings", component_name]),
+        })
+    }
+}
+
+/// Reply of a request/reply operation
+///
+/// Describes where the reply is sent (`address` and/or `channel`) and which
+/// messages can be sent as the reply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationReply {
+    /// Runtime address of the reply (e.g. taken from a `replyTo` header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<OperationReplyAddressOrRef>,
+
+    /// Channel the reply is sent on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelReference>,
+
+    /// Messages that can be sent as the reply (must belong to the reply channel)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<MessageReference>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+/// Address of an operation reply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationReplyAddress {
+    /// Address description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Runtime expression locating the reply address (e.g. `$message.header#/replyTo`)
+    pub location: String,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
+}
+
+/// Operation reply or reference to a component reply
+///
+/// The reference variant comes first: every field of [`OperationReply`] is
+/// optional, so an untagged `$ref` object would otherwise deserialize as an
+/// empty inline reply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)] // Same shape as the other `OrRef` enums
+pub enum OperationReplyOrRef {
+    /// Reference to a component reply
+    Ref(MessageReference),
+    /// Inline reply definition
+    Reply(OperationReply),
+}
+
+impl OperationReplyOrRef {
+    /// Create an `OperationReplyOrRef` from an inline `OperationReply`
+    pub const fn reply(reply: OperationReply) -> Self {
+        Self::Reply(reply)
+    }
+
+    /// Create an `OperationReplyOrRef` from a component reference
+    pub fn component_ref(component_name: &str) -> Self {
+        Self::Ref(MessageReference {
+            ref_path: local_ref(["components", "replies", component_name]),
+        })
+    }
+}
+
+/// Operation reply address or reference to a component reply address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OperationReplyAddressOrRef {
+    /// Inline reply address
+    Address(OperationReplyAddress),
+    /// Reference to a component reply address
+    Ref(MessageReference),
+}
+
+impl OperationReplyAddressOrRef {
+    /// Create an `OperationReplyAddressOrRef` from an inline `OperationReplyAddress`
+    pub const fn address(address: OperationReplyAddress) -> Self {
+        Self::Address(address)
+    }
+
+    /// Create an `OperationReplyAddressOrRef` from a component reference
     pub fn component_ref(component_name: &str) -> Self {
         Self::Ref(MessageReference {
-            ref_path: format!("#/components/operationBindings/{}", component_name),
+            ref_path: local_ref(["components", "replyAddresses", component_name]),
         })
     }
 }
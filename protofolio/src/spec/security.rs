@@ -2,12 +2,18 @@
 //!
 //! This module contains types for AsyncAPI 3.0 security schemes.
 
+use crate::spec::extensions::deserialize_extensions;
+use crate::spec::Extensions;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Security scheme definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "camelCase")]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
 pub enum SecurityScheme {
     /// User/password authentication
     #[serde(rename = "userPassword")]
@@ -15,6 +21,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// API key authentication
@@ -26,6 +39,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// HTTP authentication
@@ -39,6 +59,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// OAuth2 authentication
@@ -49,6 +76,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// OpenID Connect authentication
@@ -59,6 +93,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// X.509 certificate authentication
@@ -67,6 +108,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// Symmetric encryption
@@ -75,6 +123,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// Asymmetric encryption
@@ -83,6 +138,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// HTTP API key authentication
@@ -96,6 +158,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 
     /// Mutual TLS authentication
@@ -104,6 +173,13 @@ pub enum SecurityScheme {
         /// Description of the security scheme
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// Specification extensions (`x-` fields)
+        #[serde(
+            flatten,
+            deserialize_with = "deserialize_extensions",
+            skip_serializing_if = "IndexMap::is_empty"
+        )]
+        extensions: Extensions,
     },
 }
 
@@ -126,6 +202,14 @@ pub struct OAuth2Flows {
     /// Password flow
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<OAuth2Flow>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// OAuth2 flow configuration
@@ -146,8 +230,16 @@ pub struct OAuth2Flow {
 
     /// Scopes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scopes: Option<HashMap<String, String>>,
+    pub scopes: Option<IndexMap<String, String>>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Security requirement (map of scheme name to scopes/requirements)
-pub type SecurityRequirement = HashMap<String, Vec<String>>;
+pub type SecurityRequirement = IndexMap<String, Vec<String>>;
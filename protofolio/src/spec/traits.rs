@@ -3,7 +3,9 @@
 //! Traits are reusable sets of properties that can be applied to operations or messages.
 //! They allow you to define common patterns once and reference them multiple times.
 
-use crate::spec::{CorrelationId, ExternalDocumentation, MessagePayload, Tag};
+use crate::spec::extensions::deserialize_extensions;
+use crate::spec::{CorrelationId, Extensions, ExternalDocumentation, MessagePayload, Tag};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// Operation trait definition
@@ -12,6 +14,7 @@ use serde::{Deserialize, Serialize};
 /// When an operation references a trait, the trait's properties are merged with
 /// the operation's own properties.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OperationTrait {
     /// Operation summary
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -31,7 +34,15 @@ pub struct OperationTrait {
 
     /// Protocol-specific operation bindings
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bindings: Option<serde_json::Value>,
+    pub bindings: Option<crate::spec::OperationBindingsOrRef>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
 
 /// Message trait definition
@@ -40,6 +51,7 @@ pub struct OperationTrait {
 /// When a message references a trait, the trait's properties are merged with
 /// the message's own properties.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MessageTrait {
     /// Message headers schema
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,5 +95,13 @@ pub struct MessageTrait {
 
     /// Protocol-specific message bindings
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bindings: Option<serde_json::Value>,
+    pub bindings: Option<crate::spec::MessageBindingsOrRef>,
+
+    /// Specification extensions (`x-` fields)
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_extensions",
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub extensions: Extensions,
 }
@@ -0,0 +1,20 @@
+//! Shared fixtures for unit tests
+
+use crate::AsyncApiSpec;
+use serde_json::{json, Value};
+
+/// Build a spec from the given top-level fields
+///
+/// `asyncapi`, `info` and `channels` fall back to a minimal document, so tests
+/// only spell out the sections they exercise.
+pub fn spec(fields: Value) -> AsyncApiSpec {
+    let mut document = json!({
+        "asyncapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "channels": {}
+    });
+    if let (Value::Object(document), Value::Object(fields)) = (&mut document, fields) {
+        document.extend(fields);
+    }
+    serde_json::from_value(document).unwrap_or_else(|error| panic!("invalid test spec: {}", error))
+}
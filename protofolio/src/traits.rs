@@ -172,15 +172,32 @@ pub trait AsyncApiOperation {
         None
     }
 
+    /// Get the reply definition (request/reply operations only)
+    fn reply() -> Option<crate::spec::OperationReply> {
+        None
+    }
+
+    /// Get the protocol bindings
+    fn bindings() -> Option<crate::spec::OperationBindingsOrRef> {
+        None
+    }
+
+    /// Get the specification extensions (`x-` fields)
+    fn extensions() -> crate::spec::Extensions {
+        crate::spec::Extensions::new()
+    }
+
     /// Convert this operation to an Operation struct
     fn to_operation() -> Operation {
+        use crate::resolve::local_ref;
         use crate::spec::{ChannelReference, MessageReference};
 
-        let channel_ref = format!("#/channels/{}", Self::channel());
+        let channel_ref = local_ref(["channels", Self::channel()]);
         let message_refs: Vec<MessageReference> = Self::message_names()
             .iter()
             .map(|msg_name| {
-                let ref_path = format!("#/channels/{}/messages/{msg_name}", Self::channel());
+                let ref_path =
+                    local_ref(["channels", Self::channel(), "messages", msg_name.as_str()]);
                 MessageReference { ref_path }
             })
             .collect();
@@ -197,7 +214,9 @@ pub trait AsyncApiOperation {
             tags: Self::tags(),
             external_docs: Self::external_docs(),
             traits: None,
-            bindings: None,
+            bindings: Self::bindings(),
+            reply: Self::reply().map(crate::spec::OperationReplyOrRef::reply),
+            extensions: Self::extensions(),
         }
     }
 }
@@ -1,7 +1,8 @@
 //! Protocol binding validation helpers
 
 use crate::error::ValidationError;
-use crate::spec::{AsyncApiSpec, Channel};
+use crate::protocol::find_protocol;
+use crate::spec::{AsyncApiSpec, Channel, ChannelBindingsOrRef};
 
 /// Get the protocol for a channel based on its server references
 pub(crate) fn get_channel_protocol(channel: &Channel, spec: &AsyncApiSpec) -> Option<String> {
@@ -18,44 +19,88 @@ pub(crate) fn get_channel_protocol(channel: &Channel, spec: &AsyncApiSpec) -> Op
     None
 }
 
-/// Validate channel bindings match the protocol
+/// Validate channel bindings with the validator of the registered protocol
+///
+/// Unknown protocols are skipped; the server protocol check reports them.
 pub(crate) fn validate_channel_bindings(
     protocol: &str,
-    bindings: &serde_json::Value,
+    bindings: &ChannelBindingsOrRef,
     channel_name: &str,
 ) -> Result<(), ValidationError> {
-    match protocol {
-        "kafka" => {
-            // Validate Kafka bindings structure
-            if !bindings.as_object().and_then(|o| o.get("kafka")).is_some() {
-                return Err(ValidationError::InvalidSchema(format!(
-                    "Channel '{}': Kafka channel bindings must have 'kafka' key",
-                    channel_name
-                )));
-            }
+    match find_protocol(protocol) {
+        Some(protocol) => protocol.validate_channel_bindings(bindings, channel_name),
+        None => Ok(()),
+    }
+}
+
+/// Require a binding for `key` (e.g. `"kafka"`); `label` names the protocol in the error
+#[allow(dead_code)] // Unused when no protocol feature is enabled
+pub fn require_channel_binding(
+    bindings: &ChannelBindingsOrRef,
+    key: &str,
+    label: &str,
+    channel_name: &str,
+) -> Result<(), ValidationError> {
+    let has_binding = match bindings {
+        ChannelBindingsOrRef::Bindings(bindings) => match key {
+            "kafka" => bindings.kafka.is_some(),
+            "mqtt" => bindings.mqtt.is_some(),
+            "nats" => bindings.nats.is_some(),
+            #[cfg(feature = "amqp")]
+            "amqp" => bindings.amqp.is_some(),
+            #[cfg(feature = "ws")]
+            "ws" => bindings.ws.is_some(),
+            _ => false,
+        },
+        ChannelBindingsOrRef::Custom(bindings) => {
+            bindings.as_object().and_then(|o| o.get(key)).is_some()
         }
-        "mqtt" => {
-            // Validate MQTT bindings structure
-            if !bindings.as_object().and_then(|o| o.get("mqtt")).is_some() {
-                return Err(ValidationError::InvalidSchema(format!(
-                    "Channel '{}': MQTT channel bindings must have 'mqtt' key",
-                    channel_name
-                )));
-            }
+        // References are skipped (component validation happens separately)
+        ChannelBindingsOrRef::Ref(_) => true,
+    };
+    if !has_binding {
+        return Err(ValidationError::InvalidSchema(format!(
+            "Channel '{}': {} channel bindings must have '{}' key",
+            channel_name, label, key
+        )));
+    }
+    Ok(())
+}
+
+/// The `query` and `headers` of a WebSockets channel binding must be object schemas
+#[cfg(feature = "ws")]
+pub fn validate_ws_schemas(
+    bindings: &ChannelBindingsOrRef,
+    channel_name: &str,
+) -> Result<(), ValidationError> {
+    let ws = match bindings {
+        ChannelBindingsOrRef::Bindings(bindings) => match bindings.ws {
+            Some(ref ws) => vec![
+                ("query", ws.query.as_ref()),
+                ("headers", ws.headers.as_ref()),
+            ],
+            None => return Ok(()),
+        },
+        ChannelBindingsOrRef::Custom(bindings) => {
+            let ws = bindings.get("ws");
+            vec![
+                ("query", ws.and_then(|ws| ws.get("query"))),
+                ("headers", ws.and_then(|ws| ws.get("headers"))),
+            ]
         }
-        "nats" => {
-            // Validate NATS bindings structure
-            if !bindings.as_object().and_then(|o| o.get("nats")).is_some() {
+        ChannelBindingsOrRef::Ref(_) => return Ok(()),
+    };
+    for (field, schema) in ws {
+        // Schemas without a `type` (e.g. a `$ref`) are left to the schema itself
+        let schema_type = schema.and_then(|schema| schema.get("type"));
+        if let Some(schema_type) = schema_type {
+            if schema_type != "object" {
                 return Err(ValidationError::InvalidSchema(format!(
-                    "Channel '{}': NATS channel bindings must have 'nats' key",
-                    channel_name
+                    "Channel '{}': WebSocket binding '{}' must be an object schema, found type {}",
+                    channel_name, field, schema_type
                 )));
             }
         }
-        _ => {
-            // Unknown protocol, but we already validated protocol identifier
-            // so we can skip binding validation
-        }
     }
     Ok(())
 }
@@ -0,0 +1,273 @@
+//! Validation of message examples against the message schemas
+
+use crate::error::{RefError, ValidationError};
+use crate::resolve::{parse_ref, LazyResolver};
+use crate::schema::SchemaValidator;
+use crate::spec::{
+    AsyncApiSpec, Message, MessageOrRef, MessagePayload, MessageTrait, MessageTraitOrRef,
+};
+use serde_json::Value;
+use std::borrow::Cow;
+
+use super::report::{json_pointer, ValidationReport};
+
+/// Check the examples of every message, and of the traits applied to it, against
+/// the message's payload and headers schemas
+///
+/// Examples are AsyncAPI Message Example Objects; their `payload` and `headers`
+/// are checked.
+pub fn validate_examples(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    let resolver = LazyResolver::new(spec);
+    for (channel_name, channel) in &spec.channels {
+        for (message_name, message) in &channel.messages {
+            // Referenced messages are checked where they are defined
+            if let MessageOrRef::Message(message) = message {
+                let location = ["channels", channel_name, "messages", message_name];
+                validate_message_examples(
+                    spec,
+                    &resolver,
+                    message_name,
+                    message,
+                    &location,
+                    report,
+                );
+            }
+        }
+    }
+
+    let messages = spec
+        .components
+        .as_ref()
+        .and_then(|components| components.messages.as_ref());
+    for (message_name, message) in messages.into_iter().flatten() {
+        let location = ["components", "messages", message_name];
+        validate_message_examples(spec, &resolver, message_name, message, &location, report);
+    }
+}
+
+fn validate_message_examples(
+    spec: &AsyncApiSpec,
+    resolver: &LazyResolver<'_>,
+    name: &str,
+    message: &Message,
+    location: &[&str],
+    report: &mut ValidationReport,
+) {
+    let with = |segment: &str| {
+        let mut location: Vec<String> = location.iter().map(|s| (*s).to_string()).collect();
+        location.push(segment.to_string());
+        location
+    };
+
+    let mut traits: Vec<(Cow<'_, MessageTrait>, Vec<String>)> = Vec::new();
+    for (index, message_trait) in message.traits.iter().flatten().enumerate() {
+        match message_trait {
+            MessageTraitOrRef::Trait(message_trait) => {
+                let mut location = with("traits");
+                location.push(index.to_string());
+                traits.push((Cow::Borrowed(message_trait), location));
+            }
+            MessageTraitOrRef::Ref(reference) => {
+                // Unresolvable traits cannot contribute examples
+                let target = resolver.resolve::<MessageTrait>(&reference.ref_path);
+                if let (Ok(message_trait), Ok(location)) = (target, parse_ref(&reference.ref_path))
+                {
+                    traits.push((Cow::Owned(message_trait), location));
+                }
+            }
+        }
+    }
+
+    // Headers come from the message or, failing that, the first trait defining them
+    let headers = message
+        .headers
+        .as_ref()
+        .map(|headers| (headers, with("headers")))
+        .or_else(|| {
+            traits.iter().find_map(|(message_trait, location)| {
+                let headers = message_trait.headers.as_ref()?;
+                let mut location = location.clone();
+                location.push("headers".to_string());
+                Some((headers, location))
+            })
+        });
+    let payload = (&message.payload, with("payload"));
+
+    let examples = std::iter::once((
+        message.examples.as_ref(),
+        location.iter().map(|s| (*s).to_string()).collect(),
+    ))
+    .chain(
+        traits
+            .iter()
+            .map(|(message_trait, location)| (message_trait.examples.as_ref(), location.clone())),
+    );
+    for (examples, owner) in examples {
+        for (index, example) in examples.into_iter().flatten().enumerate() {
+            let mut pointer: Vec<String> = owner.clone();
+            pointer.extend(["examples".to_string(), index.to_string()]);
+            let parts = [
+                (example.get("payload"), Some(&payload), "payload"),
+                (example.get("headers"), headers.as_ref(), "headers"),
+            ];
+            for (instance, schema, field) in parts {
+                let (Some(instance), Some((schema, schema_location))) = (instance, schema) else {
+                    continue;
+                };
+                let mut pointer = pointer.clone();
+                pointer.push(field.to_string());
+                if let Err(error) = check_example(
+                    spec,
+                    name,
+                    schema,
+                    schema_location,
+                    instance,
+                    &pointer,
+                    report,
+                ) {
+                    report.error(
+                        json_pointer(schema_location.iter().map(String::as_str)),
+                        ValidationError::InvalidSchema(format!(
+                            "Examples of message '{}' cannot be checked: {}",
+                            name, error
+                        )),
+                    );
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Report every violation of `schema` by the example value at `pointer`
+fn check_example(
+    spec: &AsyncApiSpec,
+    name: &str,
+    schema: &MessagePayload,
+    schema_location: &[String],
+    instance: &Value,
+    pointer: &[String],
+    report: &mut ValidationReport,
+) -> Result<(), RefError> {
+    let location: Vec<&str> = schema_location.iter().map(String::as_str).collect();
+    let violations = SchemaValidator::new(spec, &schema.schema, &location).validate(instance)?;
+    let pointer = json_pointer(pointer.iter().map(String::as_str));
+    for violation in violations {
+        let path = if violation.instance_path.is_empty() {
+            "/".to_string()
+        } else {
+            violation.instance_path.clone()
+        };
+        report.error(
+            format!("{}{}", pointer, violation.instance_path),
+            ValidationError::InvalidExample {
+                message: name.to_string(),
+                path,
+                error: format!("{} (schema {})", violation.message, violation.schema_path),
+            },
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use crate::validate_spec_report;
+    use serde_json::json;
+
+    fn spec(examples: &Value, trait_examples: &Value) -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "channels": {
+                "users": {
+                    "address": "users",
+                    "messages": {
+                        "UserSignedUp": {
+                            "payload": {
+                                "type": "object",
+                                "properties": {"email": {"type": "string"}},
+                                "required": ["email"],
+                                "additionalProperties": false
+                            },
+                            "examples": examples,
+                            "traits": [{"$ref": "#/components/messageTraits/Traced"}]
+                        }
+                    }
+                }
+            },
+            "components": {
+                "messageTraits": {
+                    "Traced": {
+                        "headers": {
+                            "type": "object",
+                            "properties": {"traceId": {"type": "string"}}
+                        },
+                        "examples": trait_examples
+                    }
+                }
+            }
+        }))
+    }
+
+    fn invalid_examples(spec: &AsyncApiSpec) -> Vec<(String, String)> {
+        validate_spec_report(spec)
+            .errors()
+            .filter_map(|issue| match &issue.error {
+                ValidationError::InvalidExample { message, path, .. } => {
+                    assert_eq!(message, "UserSignedUp");
+                    Some((issue.pointer.clone(), path.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_examples() {
+        let spec = spec(
+            &json!([
+                {"payload": {"email": "a@example.com"}},
+                {"name": "with-headers", "payload": {"email": "b@example.com"}, "headers": {"traceId": "1"}}
+            ]),
+            &json!([{"headers": {"traceId": "abc"}}]),
+        );
+        assert!(invalid_examples(&spec).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_examples_are_reported_with_paths() {
+        let spec = spec(
+            &json!([
+                {"payload": {"mail": "a@example.com"}},
+                {"payload": {"email": 1}, "headers": {"traceId": 2}}
+            ]),
+            &json!([{"headers": {"traceId": false}}]),
+        );
+        assert_eq!(
+            invalid_examples(&spec),
+            [
+                (
+                    "/channels/users/messages/UserSignedUp/examples/0/payload/mail".to_string(),
+                    "/mail".to_string()
+                ),
+                (
+                    "/channels/users/messages/UserSignedUp/examples/0/payload".to_string(),
+                    "/".to_string()
+                ),
+                (
+                    "/channels/users/messages/UserSignedUp/examples/1/payload/email".to_string(),
+                    "/email".to_string()
+                ),
+                (
+                    "/channels/users/messages/UserSignedUp/examples/1/headers/traceId".to_string(),
+                    "/traceId".to_string()
+                ),
+                (
+                    "/components/messageTraits/Traced/examples/0/headers/traceId".to_string(),
+                    "/traceId".to_string()
+                ),
+            ]
+        );
+    }
+}
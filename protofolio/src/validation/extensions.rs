@@ -0,0 +1,333 @@
+//! Specification extension names
+//!
+//! Extensions are flattened into their object, so a key without the `x-`
+//! prefix would be written next to (and clash with) the object's own fields.
+
+use crate::error::ValidationError;
+use crate::spec::{
+    AsyncApiSpec, Components, CorrelationId, Extensions, ExternalDocumentation, Message,
+    MessageOrRef, MessageTrait, MessageTraitOrRef, OAuth2Flows, Operation, OperationReply,
+    OperationReplyAddressOrRef, OperationReplyOrRef, OperationTrait, OperationTraitOrRef,
+    SecurityScheme, Server, Tag,
+};
+
+use super::report::{json_pointer, ValidationReport};
+
+/// Validate that every extension of every object in the spec is named `x-...`
+pub fn validate_extensions(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    check(report, "", &spec.extensions);
+
+    let info = &spec.info;
+    check(report, "/info", &info.extensions);
+    if let Some(contact) = &info.contact {
+        check(report, "/info/contact", &contact.extensions);
+    }
+    if let Some(license) = &info.license {
+        check(report, "/info/license", &license.extensions);
+    }
+    check_external_docs(report, "/info", info.external_docs.as_ref());
+    check_tags(report, "", spec.tags.as_ref());
+
+    for (name, server) in spec.servers.iter().flatten() {
+        check_server(report, &json_pointer(["servers", name]), server);
+    }
+
+    for (channel_name, channel) in &spec.channels {
+        let pointer = json_pointer(["channels", channel_name]);
+        check(report, &pointer, &channel.extensions);
+        for (name, message) in &channel.messages {
+            if let MessageOrRef::Message(message) = message {
+                check_message(report, &child(&pointer, ["messages", name]), message);
+            }
+        }
+        for (name, parameter) in channel.parameters.iter().flatten() {
+            check(
+                report,
+                &child(&pointer, ["parameters", name]),
+                &parameter.extensions,
+            );
+        }
+    }
+
+    for (name, operation) in spec.operations.iter().flatten() {
+        check_operation(report, &json_pointer(["operations", name]), operation);
+    }
+
+    if let Some(components) = &spec.components {
+        check_components(report, components);
+    }
+}
+
+/// Report the keys of `extensions` that do not start with `x-`
+fn check(report: &mut ValidationReport, pointer: &str, extensions: &Extensions) {
+    for name in extensions.keys().filter(|name| !name.starts_with("x-")) {
+        report.error(
+            child(pointer, [name.as_str()]),
+            ValidationError::InvalidExtension(name.clone()),
+        );
+    }
+}
+
+/// Append segments to a JSON Pointer
+fn child<'a>(pointer: &str, segments: impl IntoIterator<Item = &'a str>) -> String {
+    format!("{}{}", pointer, json_pointer(segments))
+}
+
+fn check_tags(report: &mut ValidationReport, pointer: &str, tags: Option<&Vec<Tag>>) {
+    for (index, tag) in tags.into_iter().flatten().enumerate() {
+        check(
+            report,
+            &child(pointer, ["tags", &index.to_string()]),
+            &tag.extensions,
+        );
+    }
+}
+
+fn check_external_docs(
+    report: &mut ValidationReport,
+    pointer: &str,
+    external_docs: Option<&ExternalDocumentation>,
+) {
+    if let Some(external_docs) = external_docs {
+        check(
+            report,
+            &child(pointer, ["externalDocs"]),
+            &external_docs.extensions,
+        );
+    }
+}
+
+fn check_correlation_id(
+    report: &mut ValidationReport,
+    pointer: &str,
+    correlation_id: Option<&CorrelationId>,
+) {
+    if let Some(correlation_id) = correlation_id {
+        check(
+            report,
+            &child(pointer, ["correlationId"]),
+            &correlation_id.extensions,
+        );
+    }
+}
+
+fn check_server(report: &mut ValidationReport, pointer: &str, server: &Server) {
+    check(report, pointer, &server.extensions);
+    check_tags(report, pointer, server.tags.as_ref());
+    check_external_docs(report, pointer, server.external_docs.as_ref());
+    for (name, variable) in server.variables.iter().flatten() {
+        check(
+            report,
+            &child(pointer, ["variables", name]),
+            &variable.extensions,
+        );
+    }
+}
+
+fn check_message(report: &mut ValidationReport, pointer: &str, message: &Message) {
+    check(report, pointer, &message.extensions);
+    check_tags(report, pointer, message.tags.as_ref());
+    check_external_docs(report, pointer, message.external_docs.as_ref());
+    check_correlation_id(report, pointer, message.correlation_id.as_ref());
+    for (index, message_trait) in message.traits.iter().flatten().enumerate() {
+        if let MessageTraitOrRef::Trait(message_trait) = message_trait {
+            check_message_trait(
+                report,
+                &child(pointer, ["traits", &index.to_string()]),
+                message_trait,
+            );
+        }
+    }
+}
+
+fn check_message_trait(report: &mut ValidationReport, pointer: &str, message_trait: &MessageTrait) {
+    check(report, pointer, &message_trait.extensions);
+    check_tags(report, pointer, message_trait.tags.as_ref());
+    check_external_docs(report, pointer, message_trait.external_docs.as_ref());
+    check_correlation_id(report, pointer, message_trait.correlation_id.as_ref());
+}
+
+fn check_operation(report: &mut ValidationReport, pointer: &str, operation: &Operation) {
+    check(report, pointer, &operation.extensions);
+    check_tags(report, pointer, operation.tags.as_ref());
+    check_external_docs(report, pointer, operation.external_docs.as_ref());
+    for (index, operation_trait) in operation.traits.iter().flatten().enumerate() {
+        if let OperationTraitOrRef::Trait(operation_trait) = operation_trait {
+            check_operation_trait(
+                report,
+                &child(pointer, ["traits", &index.to_string()]),
+                operation_trait,
+            );
+        }
+    }
+    if let Some(OperationReplyOrRef::Reply(reply)) = &operation.reply {
+        check_reply(report, &child(pointer, ["reply"]), reply);
+    }
+}
+
+fn check_operation_trait(
+    report: &mut ValidationReport,
+    pointer: &str,
+    operation_trait: &OperationTrait,
+) {
+    check(report, pointer, &operation_trait.extensions);
+    check_tags(report, pointer, operation_trait.tags.as_ref());
+    check_external_docs(report, pointer, operation_trait.external_docs.as_ref());
+}
+
+fn check_reply(report: &mut ValidationReport, pointer: &str, reply: &OperationReply) {
+    check(report, pointer, &reply.extensions);
+    if let Some(OperationReplyAddressOrRef::Address(address)) = &reply.address {
+        check(report, &child(pointer, ["address"]), &address.extensions);
+    }
+}
+
+fn check_components(report: &mut ValidationReport, components: &Components) {
+    let pointer = "/components";
+    check(report, pointer, &components.extensions);
+    for (name, message) in components.messages.iter().flatten() {
+        check_message(report, &child(pointer, ["messages", name]), message);
+    }
+    for (name, scheme) in components.security_schemes.iter().flatten() {
+        check_security_scheme(report, &child(pointer, ["securitySchemes", name]), scheme);
+    }
+    for (name, parameter) in components.parameters.iter().flatten() {
+        check(
+            report,
+            &child(pointer, ["parameters", name]),
+            &parameter.extensions,
+        );
+    }
+    for (name, operation_trait) in components.operation_traits.iter().flatten() {
+        check_operation_trait(
+            report,
+            &child(pointer, ["operationTraits", name]),
+            operation_trait,
+        );
+    }
+    for (name, message_trait) in components.message_traits.iter().flatten() {
+        check_message_trait(
+            report,
+            &child(pointer, ["messageTraits", name]),
+            message_trait,
+        );
+    }
+    for (name, reply) in components.replies.iter().flatten() {
+        check_reply(report, &child(pointer, ["replies", name]), reply);
+    }
+    for (name, address) in components.reply_addresses.iter().flatten() {
+        check(
+            report,
+            &child(pointer, ["replyAddresses", name]),
+            &address.extensions,
+        );
+    }
+}
+
+fn check_security_scheme(report: &mut ValidationReport, pointer: &str, scheme: &SecurityScheme) {
+    match scheme {
+        SecurityScheme::OAuth2 {
+            flows, extensions, ..
+        } => {
+            check(report, pointer, extensions);
+            check_oauth2_flows(report, &child(pointer, ["flows"]), flows);
+        }
+        SecurityScheme::UserPassword { extensions, .. }
+        | SecurityScheme::ApiKey { extensions, .. }
+        | SecurityScheme::Http { extensions, .. }
+        | SecurityScheme::OpenIdConnect { extensions, .. }
+        | SecurityScheme::X509 { extensions, .. }
+        | SecurityScheme::SymmetricEncryption { extensions, .. }
+        | SecurityScheme::AsymmetricEncryption { extensions, .. }
+        | SecurityScheme::HttpApiKey { extensions, .. }
+        | SecurityScheme::MutualTls { extensions, .. } => check(report, pointer, extensions),
+    }
+}
+
+fn check_oauth2_flows(report: &mut ValidationReport, pointer: &str, flows: &OAuth2Flows) {
+    check(report, pointer, &flows.extensions);
+    let named = [
+        ("authorizationCode", &flows.authorization_code),
+        ("clientCredentials", &flows.client_credentials),
+        ("implicit", &flows.implicit),
+        ("password", &flows.password),
+    ];
+    for (name, flow) in named {
+        if let Some(flow) = flow {
+            check(report, &child(pointer, [name]), &flow.extensions);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_extension_names_must_start_with_x() {
+        let mut spec = test_utils::spec(serde_json::json!({
+            "channels": {
+                "orders": {
+                    "address": "orders",
+                    "messages": {"OrderPlaced": {"payload": {"type": "object"}}}
+                }
+            },
+            "components": {
+                "securitySchemes": {
+                    "oauth": {
+                        "type": "oauth2",
+                        "flows": {"implicit": {"authorizationUrl": "https://example.com", "scopes": {}}}
+                    }
+                }
+            }
+        }));
+        spec.extensions
+            .insert("x-owner".to_string(), serde_json::json!("orders"));
+        spec.extensions
+            .insert("info".to_string(), serde_json::json!({"title": "Other"}));
+        if let MessageOrRef::Message(message) = &mut spec.channels["orders"].messages["OrderPlaced"]
+        {
+            message
+                .extensions
+                .insert("owner".to_string(), serde_json::json!("orders"));
+        }
+        if let Some(SecurityScheme::OAuth2 { flows, .. }) = spec
+            .components
+            .as_mut()
+            .and_then(|components| components.security_schemes.as_mut())
+            .and_then(|schemes| schemes.get_mut("oauth"))
+        {
+            if let Some(flow) = &mut flows.implicit {
+                flow.extensions
+                    .insert("owner".to_string(), serde_json::json!("auth"));
+            }
+        }
+
+        let mut report = ValidationReport::new();
+        validate_extensions(&spec, &mut report);
+
+        let issues: Vec<_> = report
+            .issues()
+            .iter()
+            .map(|issue| (issue.pointer.as_str(), &issue.error))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    "/info",
+                    &ValidationError::InvalidExtension("info".to_string())
+                ),
+                (
+                    "/channels/orders/messages/OrderPlaced/owner",
+                    &ValidationError::InvalidExtension("owner".to_string())
+                ),
+                (
+                    "/components/securitySchemes/oauth/flows/implicit/owner",
+                    &ValidationError::InvalidExtension("owner".to_string())
+                ),
+            ]
+        );
+    }
+}
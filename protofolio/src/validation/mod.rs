@@ -13,9 +13,20 @@
 //! - At least one channel is defined
 //! - All channels have messages
 //! - Server references are valid
+//! - Server host and pathname placeholders match the server variables, and
+//!   variable defaults are among their enum values
 //! - Message IDs are unique
-//! - Protocol identifiers are supported
-//! - Protocol-specific bindings are valid
+//! - Protocol identifiers are registered (see [`register_protocol`](crate::register_protocol))
+//! - Protocol-specific bindings pass the protocol's binding validator
+//! - Operations point at existing channels, and their messages belong to that channel
+//! - Operation and message trait references exist in the components
+//! - Server security requirements name existing schemes and declared OAuth2
+//!   scopes, and each security scheme has the fields its type requires
+//! - Operation replies point at existing channels and messages
+//! - Channel address placeholders and parameters match, parameter defaults
+//!   are among their enum values and locations are runtime expressions
+//! - Message examples match the payload and headers schemas
+//! - Specification extension names start with `x-`
 //!
 //! # Example
 //!
@@ -33,10 +44,45 @@
 //! }
 //! ```
 //!
+//! To see every problem at once, use
+//! [`validate_spec_report`](crate::validate_spec_report). It returns a
+//! [`ValidationReport`](crate::ValidationReport) with all errors and warnings,
+//! each with a JSON Pointer to the offending node:
+//!
+//! ```rust,no_run
+//! # use protofolio::{AsyncApi, validate_spec_report};
+//! # use protofolio_derive::AsyncApi;
+//! #
+//! # #[derive(AsyncApi)]
+//! # #[asyncapi(info(title = "Test", version = "1.0.0"), channels("events"), messages())]
+//! # struct MyApi;
+//! let report = validate_spec_report(&MyApi::asyncapi());
+//! println!("{}", report);
+//! ```
+//!
 //! Note: The `try_asyncapi()` method automatically validates the spec, so you
 //! typically don't need to call `validate_spec` separately.
+//!
+//! Messages themselves are validated against the spec at runtime with
+//! [`AsyncApiSpec::validate_payload`](crate::AsyncApiSpec::validate_payload) and
+//! [`AsyncApiSpec::validate_headers`](crate::AsyncApiSpec::validate_headers).
 
 mod bindings;
+mod examples;
+mod extensions;
+mod parameters;
+mod payload;
+mod reply;
+mod report;
+mod security;
+mod servers;
 mod validator;
 
+#[allow(unused_imports)] // Unused when no protocol feature is enabled
+pub use bindings::require_channel_binding;
+#[cfg(feature = "ws")]
+pub use bindings::validate_ws_schemas;
+pub use parameters::address_placeholders;
+pub use report::json_pointer;
+pub use report::{Severity, ValidationIssue, ValidationReport};
 pub use validator::*;
@@ -0,0 +1,264 @@
+//! Channel address template and parameter validation
+
+use crate::error::ValidationError;
+use crate::spec::{AsyncApiSpec, Parameter};
+
+use super::report::{json_pointer, ValidationReport};
+
+/// Names of the `{placeholders}` in a channel address, in order
+pub fn address_placeholders(address: &str) -> Result<Vec<&str>, String> {
+    let mut placeholders = Vec::new();
+    let mut rest = address;
+    loop {
+        match (rest.find('{'), rest.find('}')) {
+            (None, None) => return Ok(placeholders),
+            (open, Some(close)) if open.map_or(true, |open| close < open) => {
+                return Err("'}' without a matching '{'".to_string());
+            }
+            (Some(open), _) => {
+                let after = &rest[open + 1..];
+                let Some(end) = after.find(['{', '}']) else {
+                    return Err("unclosed '{'".to_string());
+                };
+                if after[end..].starts_with('{') {
+                    return Err("placeholders cannot be nested".to_string());
+                }
+                if end == 0 {
+                    return Err("empty placeholder '{}'".to_string());
+                }
+                placeholders.push(&after[..end]);
+                rest = &after[end + 1..];
+            }
+            (None, Some(_)) => unreachable!("handled by the '}}' arm"),
+        }
+    }
+}
+
+/// Check the syntax of a runtime expression such as `$message.header#/replyTo`
+///
+/// Runtime expressions name the message header or payload, optionally followed
+/// by a JSON Pointer fragment.
+pub fn check_runtime_expression(expression: &str) -> Result<(), String> {
+    let Some(source) = expression.strip_prefix("$message.") else {
+        return Err("must start with '$message.'".to_string());
+    };
+    let (source, fragment) = match source.split_once('#') {
+        Some((source, fragment)) => (source, Some(fragment)),
+        None => (source, None),
+    };
+    if source != "header" && source != "payload" {
+        return Err(format!(
+            "'{}' is not a message source; use '$message.header' or '$message.payload'",
+            source
+        ));
+    }
+    if let Some(fragment) = fragment {
+        if !fragment.is_empty() && !fragment.starts_with('/') {
+            return Err(format!(
+                "fragment '{}' is not a JSON Pointer; it must start with '/'",
+                fragment
+            ));
+        }
+        let mut chars = fragment.chars();
+        while let Some(c) = chars.next() {
+            if c == '~' && !matches!(chars.next(), Some('0' | '1')) {
+                return Err(format!(
+                    "fragment '{}' has an invalid '~' escape; use '~0' for '~' and '~1' for '/'",
+                    fragment
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate channel addresses against their parameters, and every parameter's
+/// `default` and `location`
+pub fn validate_parameters(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    for (channel_name, channel) in &spec.channels {
+        let parameters = channel.parameters.as_ref();
+        let placeholders = match address_placeholders(&channel.address) {
+            Ok(placeholders) => Some(placeholders),
+            Err(problem) => {
+                report.error(
+                    json_pointer(["channels", channel_name, "address"]),
+                    ValidationError::InvalidChannelAddress(format!(
+                        "Channel '{}' address '{}': {}",
+                        channel_name, channel.address, problem
+                    )),
+                );
+                None
+            }
+        };
+
+        if let Some(ref placeholders) = placeholders {
+            for placeholder in placeholders {
+                if !parameters.is_some_and(|parameters| parameters.contains_key(*placeholder)) {
+                    report.error(
+                        json_pointer(["channels", channel_name, "address"]),
+                        ValidationError::InvalidChannelParameter(format!(
+                            "Channel '{}' address '{}' has placeholder '{{{}}}' without a parameter",
+                            channel_name, channel.address, placeholder
+                        )),
+                    );
+                }
+            }
+        }
+
+        for (name, parameter) in parameters.into_iter().flatten() {
+            let pointer = json_pointer(["channels", channel_name, "parameters", name]);
+            let context = format!("Parameter '{}' of channel '{}'", name, channel_name);
+            // Without parsed placeholders, every parameter would look unused
+            if placeholders
+                .as_ref()
+                .is_some_and(|placeholders| !placeholders.contains(&name.as_str()))
+            {
+                report.error(
+                    pointer.clone(),
+                    ValidationError::InvalidChannelParameter(format!(
+                        "{} does not appear in address '{}'",
+                        context, channel.address
+                    )),
+                );
+            }
+            validate_parameter(&context, &pointer, parameter, report);
+        }
+    }
+
+    let parameters = spec
+        .components
+        .as_ref()
+        .and_then(|components| components.parameters.as_ref());
+    for (name, parameter) in parameters.into_iter().flatten() {
+        validate_parameter(
+            &format!("Component parameter '{}'", name),
+            &json_pointer(["components", "parameters", name]),
+            parameter,
+            report,
+        );
+    }
+}
+
+/// Validate that the `default` of a parameter is allowed and its `location` is well-formed
+fn validate_parameter(
+    context: &str,
+    pointer: &str,
+    parameter: &Parameter,
+    report: &mut ValidationReport,
+) {
+    if let (Some(default), Some(values)) = (&parameter.default, &parameter.enum_values) {
+        if !values.contains(default) {
+            report.error(
+                format!("{}/default", pointer),
+                ValidationError::InvalidChannelParameter(format!(
+                    "{} has default '{}', which is not one of its enum values {:?}",
+                    context, default, values
+                )),
+            );
+        }
+    }
+    if let Some(ref location) = parameter.location {
+        if let Err(problem) = check_runtime_expression(location) {
+            report.error(
+                format!("{}/location", pointer),
+                ValidationError::InvalidChannelParameter(format!(
+                    "{} has invalid location '{}': {}",
+                    context, location, problem
+                )),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use crate::validate_spec_report;
+    use serde_json::json;
+
+    #[test]
+    fn test_address_placeholders() {
+        assert_eq!(
+            address_placeholders("orders.{tenant}.{region}"),
+            Ok(vec!["tenant", "region"])
+        );
+        assert_eq!(address_placeholders("orders"), Ok(vec![]));
+        for malformed in ["orders.{tenant", "orders.tenant}", "{a{b}}", "orders.{}"] {
+            assert!(address_placeholders(malformed).is_err(), "{}", malformed);
+        }
+    }
+
+    #[test]
+    fn test_runtime_expressions() {
+        for valid in [
+            "$message.payload",
+            "$message.header#/replyTo",
+            "$message.payload#/user/id",
+            "$message.payload#/a~1b~0c",
+        ] {
+            assert_eq!(check_runtime_expression(valid), Ok(()), "{}", valid);
+        }
+        for invalid in [
+            "replyTo",
+            "$message.body#/id",
+            "$message.payload#id",
+            "$message.payload#/a~2",
+        ] {
+            assert!(check_runtime_expression(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_validate_channel_parameters() {
+        let spec = test_utils::spec(json!({
+            "channels": {
+                "valid": {
+                    "address": "orders.{tenant}",
+                    "parameters": {
+                        "tenant": {
+                            "enum": ["acme", "globex"],
+                            "default": "acme",
+                            "location": "$message.payload#/tenant"
+                        }
+                    },
+                    "messages": {"Order": {"payload": {"type": "object"}}}
+                },
+                "invalid": {
+                    "address": "orders.{tenant}.{region}",
+                    "parameters": {
+                        "tenant": {"enum": ["acme"], "default": "initech"},
+                        "kind": {"location": "payload.kind"}
+                    },
+                    "messages": {"Order": {"payload": {"type": "object"}}}
+                },
+                "malformed": {
+                    "address": "orders.{tenant",
+                    "parameters": {"tenant": {}},
+                    "messages": {"Order": {"payload": {"type": "object"}}}
+                }
+            }
+        }));
+
+        let report = validate_spec_report(&spec);
+        let issues: Vec<_> = report
+            .errors()
+            .map(|issue| (issue.pointer.as_str(), &issue.error))
+            .collect();
+        let pointers: Vec<_> = issues.iter().map(|(pointer, _)| *pointer).collect();
+        assert_eq!(
+            pointers,
+            [
+                "/channels/invalid/address",
+                "/channels/invalid/parameters/tenant/default",
+                "/channels/invalid/parameters/kind",
+                "/channels/invalid/parameters/kind/location",
+                "/channels/malformed/address",
+            ]
+        );
+        assert!(matches!(
+            issues[4].1,
+            ValidationError::InvalidChannelAddress(_)
+        ));
+    }
+}
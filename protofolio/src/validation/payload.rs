@@ -0,0 +1,242 @@
+//! Validation of message payloads and headers against their schemas
+
+use crate::error::{PayloadError, RefError};
+use crate::resolve::parse_ref;
+use crate::schema::SchemaValidator;
+use crate::spec::{AsyncApiSpec, Message, MessageOrRef, MessagePayload};
+use serde_json::Value;
+use std::borrow::Cow;
+
+impl AsyncApiSpec {
+    /// Validate a message payload against the documented schema
+    ///
+    /// `message` is the key of the message in the `messages` of `channel`;
+    /// references to component messages are followed. Every violation is
+    /// returned in [`PayloadError::Invalid`], with JSON Pointers into the
+    /// payload and the specification. See [`SchemaViolation`](crate::SchemaViolation).
+    ///
+    /// The built-in validator needs no network access. It checks the core
+    /// JSON Schema (draft-07 and 2020-12) validation keywords and resolves `$ref`s
+    /// within the specification, such as `#/components/schemas/Address`.
+    /// Annotations like `format` are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PayloadError::Invalid`] if the payload violates the schema, and
+    /// another `PayloadError` if the channel, message or schema cannot be found
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use protofolio::{AsyncApi, PayloadError};
+    /// # use protofolio_derive::AsyncApi;
+    /// #
+    /// # #[derive(AsyncApi)]
+    /// # #[asyncapi(info(title = "Test", version = "1.0.0"), channels("users"), messages())]
+    /// # struct MyApi;
+    ///
+    /// let spec = MyApi::asyncapi();
+    /// let payload = serde_json::json!({"id": "42", "email": "user@example.com"});
+    /// if let Err(PayloadError::Invalid(violations)) =
+    ///     spec.validate_payload("users", "UserSignedUp", &payload)
+    /// {
+    ///     for violation in violations {
+    ///         eprintln!("{}", violation);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate_payload(
+        &self,
+        channel: &str,
+        message: &str,
+        payload: &Value,
+    ) -> Result<(), PayloadError> {
+        validate_part(self, channel, message, "payload", payload, |message| {
+            Some(&message.payload)
+        })
+    }
+
+    /// Validate message headers against the documented headers schema
+    ///
+    /// Works like [`validate_payload`](Self::validate_payload). Messages
+    /// without a headers schema accept any headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PayloadError` like [`validate_payload`](Self::validate_payload)
+    pub fn validate_headers(
+        &self,
+        channel: &str,
+        message: &str,
+        headers: &Value,
+    ) -> Result<(), PayloadError> {
+        validate_part(self, channel, message, "headers", headers, |message| {
+            message.headers.as_ref()
+        })
+    }
+}
+
+fn validate_part(
+    spec: &AsyncApiSpec,
+    channel: &str,
+    name: &str,
+    part: &str,
+    instance: &Value,
+    schema: impl Fn(&Message) -> Option<&MessagePayload>,
+) -> Result<(), PayloadError> {
+    let (message, location) = find_message(spec, channel, name)?;
+    let Some(schema) = schema(&message) else {
+        return Ok(());
+    };
+
+    let mut location: Vec<&str> = location.iter().map(String::as_str).collect();
+    location.push(part);
+    let violations = SchemaValidator::new(spec, &schema.schema, &location).validate(instance)?;
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(PayloadError::Invalid(violations))
+    }
+}
+
+/// The message `name` of `channel`, following references, and its location
+fn find_message<'a>(
+    spec: &'a AsyncApiSpec,
+    channel: &str,
+    name: &str,
+) -> Result<(Cow<'a, Message>, Vec<String>), PayloadError> {
+    let mut message = spec
+        .channels
+        .get(channel)
+        .ok_or_else(|| PayloadError::ChannelNotFound(channel.to_string()))?
+        .messages
+        .get(name)
+        .ok_or_else(|| PayloadError::MessageNotFound {
+            channel: channel.to_string(),
+            message: name.to_string(),
+        })?;
+    let mut location = ["channels", channel, "messages", name]
+        .map(String::from)
+        .to_vec();
+
+    let mut followed: Vec<String> = Vec::new();
+    loop {
+        let reference = match message {
+            MessageOrRef::Message(message) => return Ok((Cow::Borrowed(message), location)),
+            MessageOrRef::Ref(reference) => &reference.ref_path,
+        };
+        if followed.contains(reference) {
+            followed.push(reference.clone());
+            return Err(RefError::Cycle(followed).into());
+        }
+        followed.push(reference.clone());
+
+        location = parse_ref(reference)?;
+        let not_found = || RefError::NotFound(reference.clone());
+        message = match location.as_slice() {
+            [components, messages, name]
+                if components == "components" && messages == "messages" =>
+            {
+                let message = spec
+                    .components
+                    .as_ref()
+                    .and_then(|components| components.messages.as_ref())
+                    .and_then(|messages| messages.get(name))
+                    .ok_or_else(not_found)?;
+                return Ok((Cow::Borrowed(message), location));
+            }
+            [channels, channel, messages, name]
+                if channels == "channels" && messages == "messages" =>
+            {
+                spec.channels
+                    .get(channel)
+                    .and_then(|channel| channel.messages.get(name))
+                    .ok_or_else(not_found)?
+            }
+            _ => return Ok((Cow::Owned(spec.resolve(reference)?), location)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use serde_json::json;
+
+    fn spec() -> AsyncApiSpec {
+        test_utils::spec(json!({
+            "channels": {
+                "orders": {
+                    "address": "orders",
+                    "messages": {
+                        "OrderPlaced": {"$ref": "#/components/messages/OrderPlaced"}
+                    }
+                },
+                "audit": {
+                    "address": "audit",
+                    "messages": {
+                        "OrderPlaced": {"$ref": "#/channels/orders/messages/OrderPlaced"}
+                    }
+                }
+            },
+            "components": {
+                "messages": {
+                    "OrderPlaced": {
+                        "payload": {"$ref": "#/components/schemas/Order"},
+                        "headers": {
+                            "type": "object",
+                            "properties": {"version": {"type": "integer"}},
+                            "required": ["version"]
+                        }
+                    }
+                },
+                "schemas": {
+                    "Order": {
+                        "type": "object",
+                        "properties": {"total": {"type": "number", "minimum": 0}}
+                    }
+                }
+            }
+        }))
+    }
+
+    #[test]
+    fn test_follows_message_refs() {
+        let spec = spec();
+        for channel in ["orders", "audit"] {
+            assert_eq!(
+                spec.validate_payload(channel, "OrderPlaced", &json!({"total": 3})),
+                Ok(())
+            );
+            let Err(PayloadError::Invalid(violations)) =
+                spec.validate_payload(channel, "OrderPlaced", &json!({"total": -3}))
+            else {
+                panic!("payload should be invalid");
+            };
+            assert_eq!(violations[0].instance_path, "/total");
+            assert_eq!(
+                violations[0].schema_path,
+                "/components/schemas/Order/properties/total/minimum"
+            );
+        }
+    }
+
+    #[test]
+    fn test_headers() {
+        let spec = spec();
+        assert_eq!(
+            spec.validate_headers("orders", "OrderPlaced", &json!({"version": 2})),
+            Ok(())
+        );
+        let Err(PayloadError::Invalid(violations)) =
+            spec.validate_headers("orders", "OrderPlaced", &json!({"version": "2"}))
+        else {
+            panic!("headers should be invalid");
+        };
+        assert_eq!(
+            violations[0].schema_path,
+            "/components/messages/OrderPlaced/headers/properties/version/type"
+        );
+    }
+}
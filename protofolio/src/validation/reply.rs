@@ -0,0 +1,204 @@
+//! Operation reply validation helpers
+
+use crate::error::ValidationError;
+use crate::resolve::ref_segments;
+use crate::spec::{AsyncApiSpec, OperationReply, OperationReplyAddressOrRef, OperationReplyOrRef};
+
+use super::parameters::check_runtime_expression;
+use super::report::ValidationReport;
+
+const REPLIES_PREFIX: &str = "#/components/replies/";
+
+/// Name of the component in `#/components/{section}/{name}`
+fn component_name(ref_path: &str, section: &str) -> Option<String> {
+    match ref_segments(ref_path)?.as_mut_slice() {
+        [components, ref_section, name] if components == "components" && ref_section == section => {
+            Some(std::mem::take(name))
+        }
+        _ => None,
+    }
+}
+
+/// Name of the channel in `#/channels/{name}`
+fn channel_name(ref_path: &str) -> Option<String> {
+    match ref_segments(ref_path)?.as_mut_slice() {
+        [channels, name] if channels == "channels" => Some(std::mem::take(name)),
+        _ => None,
+    }
+}
+
+/// Validate an operation reply, resolving a reference to `components.replies`
+///
+/// `context` names the owner of the reply in error messages (e.g. "Operation 'getUser'")
+/// and `pointer` is the JSON Pointer of the reply.
+pub fn validate_operation_reply(
+    context: &str,
+    pointer: &str,
+    reply: &OperationReplyOrRef,
+    spec: &AsyncApiSpec,
+    report: &mut ValidationReport,
+) {
+    match reply {
+        OperationReplyOrRef::Reply(reply) => validate_reply(context, pointer, reply, spec, report),
+        OperationReplyOrRef::Ref(reference) => {
+            let Some(name) = component_name(&reference.ref_path, "replies") else {
+                report.error(
+                    pointer,
+                    ValidationError::InvalidOperationReply(format!(
+                        "{}: reply reference '{}' must point to '{}...'",
+                        context, reference.ref_path, REPLIES_PREFIX
+                    )),
+                );
+                return;
+            };
+            let exists = spec
+                .components
+                .as_ref()
+                .and_then(|components| components.replies.as_ref())
+                .is_some_and(|replies| replies.contains_key(&name));
+            // Component replies themselves are validated with the other components
+            if !exists {
+                report.error(
+                    pointer,
+                    ValidationError::InvalidOperationReply(format!(
+                        "{} references component reply '{}' which does not exist in components.replies",
+                        context, name
+                    )),
+                );
+            }
+        }
+    }
+}
+
+/// Validate an inline reply: its address, channel and messages
+pub fn validate_reply(
+    context: &str,
+    pointer: &str,
+    reply: &OperationReply,
+    spec: &AsyncApiSpec,
+    report: &mut ValidationReport,
+) {
+    if let Some(ref address) = reply.address {
+        let address_pointer = format!("{}/address", pointer);
+        match address {
+            OperationReplyAddressOrRef::Address(address) => {
+                if let Err(e) = validate_location(context, &address.location) {
+                    report.error(format!("{}/location", address_pointer), e);
+                }
+            }
+            OperationReplyAddressOrRef::Ref(reference) => {
+                let name =
+                    component_name(&reference.ref_path, "replyAddresses").unwrap_or_default();
+                let exists = spec
+                    .components
+                    .as_ref()
+                    .and_then(|components| components.reply_addresses.as_ref())
+                    .is_some_and(|addresses| addresses.contains_key(&name));
+                if !exists {
+                    report.error(
+                        address_pointer,
+                        ValidationError::InvalidOperationReply(format!(
+                            "{} references reply address '{}' which does not exist in components.replyAddresses",
+                            context, reference.ref_path
+                        )),
+                    );
+                }
+            }
+        }
+    }
+
+    let mut reply_channel = None;
+    if let Some(ref channel) = reply.channel {
+        let channel_pointer = format!("{}/channel", pointer);
+        match channel_name(&channel.ref_path) {
+            None => report.error(
+                channel_pointer,
+                ValidationError::InvalidChannelReference(channel.ref_path.clone()),
+            ),
+            Some(name) if !spec.channels.contains_key(&name) => report.error(
+                channel_pointer,
+                ValidationError::InvalidChannelReference(name),
+            ),
+            Some(name) => reply_channel = Some(name),
+        }
+    }
+
+    for (index, message) in reply.messages.iter().flatten().enumerate() {
+        if let Err(e) =
+            validate_reply_message(context, &message.ref_path, reply_channel.as_deref(), spec)
+        {
+            report.error(format!("{}/messages/{}", pointer, index), e);
+        }
+    }
+}
+
+/// Validate the runtime expression locating a reply address
+fn validate_location(context: &str, location: &str) -> Result<(), ValidationError> {
+    if location.is_empty() {
+        return Err(ValidationError::InvalidOperationReply(format!(
+            "{}: reply address location must not be empty",
+            context
+        )));
+    }
+    check_runtime_expression(location).map_err(|problem| {
+        ValidationError::InvalidOperationReply(format!(
+            "{}: reply address location '{}' is not a valid runtime expression: {}",
+            context, location, problem
+        ))
+    })
+}
+
+/// Validate that a reply message reference exists (and belongs to the reply channel, if any)
+fn validate_reply_message(
+    context: &str,
+    ref_path: &str,
+    reply_channel: Option<&str>,
+    spec: &AsyncApiSpec,
+) -> Result<(), ValidationError> {
+    if let Some(name) = component_name(ref_path, "messages") {
+        let exists = spec
+            .components
+            .as_ref()
+            .and_then(|components| components.messages.as_ref())
+            .is_some_and(|messages| messages.contains_key(&name));
+        if !exists {
+            return Err(ValidationError::InvalidOperationReply(format!(
+                "{} replies with component message '{}' which does not exist in components.messages",
+                context, name
+            )));
+        }
+        return Ok(());
+    }
+
+    let segments = ref_segments(ref_path).unwrap_or_default();
+    let ["channels", channel_name, "messages", message_name] =
+        segments.iter().map(String::as_str).collect::<Vec<_>>()[..]
+    else {
+        return Err(ValidationError::InvalidOperationReply(format!(
+            "{}: invalid reply message reference '{}'. Expected '#/channels/.../messages/...' or '#/components/messages/...'",
+            context, ref_path
+        )));
+    };
+
+    if let Some(reply_channel) = reply_channel {
+        if channel_name != reply_channel {
+            return Err(ValidationError::InvalidOperationReply(format!(
+                "{}: reply message '{}' does not belong to reply channel '{}'",
+                context, ref_path, reply_channel
+            )));
+        }
+    }
+
+    let Some(channel) = spec.channels.get(channel_name) else {
+        return Err(ValidationError::InvalidChannelReference(
+            channel_name.to_string(),
+        ));
+    };
+    if !channel.messages.contains_key(message_name) {
+        return Err(ValidationError::MessageNotFound {
+            channel: channel_name.to_string(),
+            message: message_name.to_string(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,208 @@
+//! Validation reports collecting every issue found in a specification
+
+use crate::error::ValidationError;
+use std::fmt;
+
+/// Severity of a validation issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The specification is invalid
+    Error,
+    /// The specification is valid but likely not what was intended
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A single problem found while validating a specification
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// Issue severity
+    pub severity: Severity,
+
+    /// JSON Pointer to the offending node (e.g. `/channels/orders/messages/OrderCreated`)
+    pub pointer: String,
+
+    /// What is wrong
+    pub error: ValidationError,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(f, "{} at {}: ", self.severity, pointer)?;
+        // Indent continuation lines (hints) under the issue
+        let message = self.error.to_string();
+        for (index, line) in message.lines().enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+                if !line.is_empty() {
+                    f.write_str("  ")?;
+                }
+            }
+            f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every error and warning found in a specification
+///
+/// Returned by [`validate_spec_report`](crate::validate_spec_report). Issues are
+/// kept in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an error at the given JSON Pointer
+    pub fn error(&mut self, pointer: impl Into<String>, error: ValidationError) {
+        self.push(Severity::Error, pointer.into(), error);
+    }
+
+    /// Record a warning at the given JSON Pointer
+    pub fn warning(&mut self, pointer: impl Into<String>, error: ValidationError) {
+        self.push(Severity::Warning, pointer.into(), error);
+    }
+
+    fn push(&mut self, severity: Severity, pointer: String, error: ValidationError) {
+        self.issues.push(ValidationIssue {
+            severity,
+            pointer,
+            error,
+        });
+    }
+
+    /// All issues, in the order they were found
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Issues with [`Severity::Error`]
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    /// Issues with [`Severity::Warning`]
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    /// Whether the specification is valid (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Whether no issues at all were found
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Convert into a result holding the first error, ignoring warnings
+    ///
+    /// # Errors
+    ///
+    /// Returns the first issue with error severity, if any
+    pub fn into_result(self) -> Result<(), ValidationError> {
+        match self
+            .issues
+            .into_iter()
+            .find(|issue| issue.severity == Severity::Error)
+        {
+            Some(issue) => Err(issue.error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{}\n", issue)?;
+        }
+        write!(
+            f,
+            "{} error(s), {} warning(s)",
+            self.errors().count(),
+            self.warnings().count()
+        )
+    }
+}
+
+/// Build a JSON Pointer from path segments, escaping `~` and `/`
+pub fn json_pointer<'a>(segments: impl IntoIterator<Item = &'a str>) -> String {
+    let mut pointer = String::new();
+    for segment in segments {
+        pointer.push('/');
+        pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    }
+    pointer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_pointer_escaping() {
+        assert_eq!(json_pointer(["channels", "orders"]), "/channels/orders");
+        assert_eq!(json_pointer(["channels", "a/b~c"]), "/channels/a~1b~0c");
+        assert_eq!(json_pointer([]), "");
+    }
+
+    #[test]
+    fn test_report_severities() {
+        let mut report = ValidationReport::new();
+        assert!(report.is_valid());
+        assert!(report.is_empty());
+
+        report.warning(
+            "/operations/op/messages",
+            ValidationError::OperationWithoutMessages("op".to_string()),
+        );
+        assert!(report.is_valid());
+        assert!(!report.is_empty());
+
+        report.error(
+            "/info/title",
+            ValidationError::MissingRequiredField("info.title".to_string()),
+        );
+        report.error("/channels", ValidationError::EmptyChannels);
+        assert!(!report.is_valid());
+        assert_eq!(report.errors().count(), 2);
+        assert_eq!(report.warnings().count(), 1);
+
+        let text = report.to_string();
+        assert!(text.starts_with("warning at /operations/op/messages: "));
+        assert!(text.contains("error at /info/title: Missing required field: info.title"));
+        assert!(text.ends_with("2 error(s), 1 warning(s)"));
+
+        assert_eq!(
+            report.into_result(),
+            Err(ValidationError::MissingRequiredField(
+                "info.title".to_string()
+            ))
+        );
+    }
+}
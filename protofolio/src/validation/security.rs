@@ -0,0 +1,246 @@
+//! Security scheme and security requirement validation
+
+use crate::error::ValidationError;
+use crate::spec::{AsyncApiSpec, OAuth2Flow, SecurityScheme};
+
+use super::report::{json_pointer, ValidationReport};
+
+/// Validate server security requirements against `components.securitySchemes`,
+/// and the fields each security scheme type needs
+pub fn validate_security(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    let schemes = spec
+        .components
+        .as_ref()
+        .and_then(|components| components.security_schemes.as_ref());
+
+    for (server_name, server) in spec.servers.iter().flatten() {
+        for (index, requirement) in server.security.iter().flatten().enumerate() {
+            for (scheme_name, scopes) in requirement {
+                let pointer = json_pointer([
+                    "servers",
+                    server_name,
+                    "security",
+                    &index.to_string(),
+                    scheme_name,
+                ]);
+                let Some(scheme) = schemes.and_then(|schemes| schemes.get(scheme_name)) else {
+                    report.error(
+                        pointer,
+                        ValidationError::SecuritySchemeNotFound {
+                            server: server_name.clone(),
+                            scheme: scheme_name.clone(),
+                        },
+                    );
+                    continue;
+                };
+                // Scopes of other scheme types are not declared anywhere
+                let SecurityScheme::OAuth2 { flows, .. } = scheme else {
+                    continue;
+                };
+                let declared = [
+                    &flows.authorization_code,
+                    &flows.client_credentials,
+                    &flows.implicit,
+                    &flows.password,
+                ];
+                for (scope_index, scope) in scopes.iter().enumerate() {
+                    let is_declared = declared.iter().copied().flatten().any(|flow| {
+                        flow.scopes
+                            .as_ref()
+                            .is_some_and(|scopes| scopes.contains_key(scope))
+                    });
+                    if !is_declared {
+                        report.error(
+                            format!("{}/{}", pointer, scope_index),
+                            ValidationError::UndeclaredScope {
+                                server: server_name.clone(),
+                                scheme: scheme_name.clone(),
+                                scope: scope.clone(),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    for (name, scheme) in schemes.into_iter().flatten() {
+        validate_scheme(name, scheme, report);
+    }
+}
+
+/// Validate that a security scheme has the fields its type requires
+fn validate_scheme(name: &str, scheme: &SecurityScheme, report: &mut ValidationReport) {
+    let pointer = json_pointer(["components", "securitySchemes", name]);
+    let invalid = |report: &mut ValidationReport, field: &str, problem: String| {
+        report.error(
+            format!("{}/{}", pointer, field),
+            ValidationError::InvalidSecurityScheme(format!(
+                "Security scheme '{}' {}",
+                name, problem
+            )),
+        );
+    };
+
+    match scheme {
+        SecurityScheme::Http { scheme, .. } if scheme.is_empty() => {
+            invalid(
+                report,
+                "scheme",
+                "of type 'http' needs a 'scheme' such as 'basic' or 'bearer'".to_string(),
+            );
+        }
+        SecurityScheme::HttpApiKey {
+            name: param, in_, ..
+        } => {
+            if param.is_empty() {
+                invalid(
+                    report,
+                    "name",
+                    "of type 'httpApiKey' needs the 'name' of the header, query or cookie parameter"
+                        .to_string(),
+                );
+            }
+            if !matches!(in_.as_str(), "query" | "header" | "cookie") {
+                invalid(
+                    report,
+                    "in",
+                    format!(
+                        "of type 'httpApiKey' has 'in' = '{}'; \
+                         expected 'query', 'header' or 'cookie'",
+                        in_
+                    ),
+                );
+            }
+        }
+        SecurityScheme::OpenIdConnect {
+            open_id_connect_url,
+            ..
+        } if open_id_connect_url.is_empty() => {
+            invalid(
+                report,
+                "openIdConnectUrl",
+                "of type 'openIdConnect' needs an 'openIdConnectUrl'".to_string(),
+            );
+        }
+        SecurityScheme::OAuth2 { flows, .. } => {
+            let flows = [
+                ("authorizationCode", &flows.authorization_code, true, true),
+                ("clientCredentials", &flows.client_credentials, false, true),
+                ("implicit", &flows.implicit, true, false),
+                ("password", &flows.password, false, true),
+            ];
+            if flows.iter().all(|(_, flow, _, _)| flow.is_none()) {
+                report.warning(
+                    format!("{}/flows", pointer),
+                    ValidationError::InvalidSecurityScheme(format!(
+                        "Security scheme '{}' of type 'oauth2' defines no flows, so clients cannot obtain a token",
+                        name
+                    )),
+                );
+            }
+            for (flow_name, flow, needs_authorization_url, needs_token_url) in flows {
+                let Some(OAuth2Flow {
+                    authorization_url,
+                    token_url,
+                    ..
+                }) = flow
+                else {
+                    continue;
+                };
+                let urls = [
+                    (
+                        "authorizationUrl",
+                        authorization_url,
+                        needs_authorization_url,
+                    ),
+                    ("tokenUrl", token_url, needs_token_url),
+                ];
+                for (field, url, required) in urls {
+                    if required && url.as_deref().map_or(true, str::is_empty) {
+                        invalid(
+                            report,
+                            &format!("flows/{}/{}", flow_name, field),
+                            format!("needs a '{}' for its '{}' flow", field, flow_name),
+                        );
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+    use crate::test_utils;
+    use crate::validate_spec_report;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_security() {
+        let spec = test_utils::spec(json!({
+            "servers": {
+                "production": {
+                    "host": "broker.example.com",
+                    "protocol": "nats",
+                    "security": [
+                        {"userPassword": []},
+                        {"userPasword": []},
+                        {"oauth": ["orders:read", "orders:delete"]}
+                    ]
+                }
+            },
+            "channels": {
+                "orders": {
+                    "address": "orders",
+                    "messages": {"Order": {"payload": {"type": "object"}}}
+                }
+            },
+            "components": {
+                "securitySchemes": {
+                    "userPassword": {"type": "userPassword"},
+                    "oauth": {
+                        "type": "oauth2",
+                        "flows": {
+                            "clientCredentials": {
+                                "tokenUrl": "https://auth.example.com/token",
+                                "scopes": {"orders:read": "Read orders"}
+                            },
+                            "implicit": {"scopes": {}}
+                        }
+                    },
+                    "bearer": {"type": "http", "scheme": ""},
+                    "key": {"type": "httpApiKey", "name": "X-Key", "in": "body"},
+                    "empty": {"type": "oauth2", "flows": {}}
+                }
+            }
+        }));
+
+        let report = validate_spec_report(&spec);
+        let errors: Vec<_> = report
+            .errors()
+            .map(|issue| (issue.pointer.as_str(), &issue.error))
+            .collect();
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [
+                    ("/servers/production/security/1/userPasword", ValidationError::SecuritySchemeNotFound { .. }),
+                    ("/servers/production/security/2/oauth/1", ValidationError::UndeclaredScope { scope, .. }),
+                    ("/components/securitySchemes/oauth/flows/implicit/authorizationUrl", ValidationError::InvalidSecurityScheme(_)),
+                    ("/components/securitySchemes/bearer/scheme", ValidationError::InvalidSecurityScheme(_)),
+                    ("/components/securitySchemes/key/in", ValidationError::InvalidSecurityScheme(_)),
+                ] if scope == "orders:delete"
+            ),
+            "{:#?}",
+            errors
+        );
+        let warnings: Vec<_> = report
+            .warnings()
+            .map(|issue| issue.pointer.as_str())
+            .collect();
+        assert_eq!(warnings, ["/components/securitySchemes/empty/flows"]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Server URL template variable validation
+
+use crate::error::ValidationError;
+use crate::spec::Server;
+
+use super::parameters::address_placeholders;
+use super::report::{json_pointer, ValidationReport};
+
+/// Validate the `{placeholders}` in a server's host and pathname against its
+/// variables, and every variable's `default`
+pub fn validate_server_variables(
+    server_name: &str,
+    server: &Server,
+    report: &mut ValidationReport,
+) {
+    let variables = server.variables.as_ref();
+    let mut used = Vec::new();
+    let mut parsed = true;
+
+    let templates = [
+        ("host", Some(&server.host)),
+        ("pathname", server.pathname.as_ref()),
+    ];
+    for (field, template) in templates {
+        let Some(template) = template else {
+            continue;
+        };
+        let pointer = json_pointer(["servers", server_name, field]);
+        match address_placeholders(template) {
+            Ok(placeholders) => {
+                for placeholder in placeholders {
+                    if !variables.is_some_and(|variables| variables.contains_key(placeholder)) {
+                        report.error(
+                            pointer.clone(),
+                            ValidationError::InvalidServerVariable(format!(
+                                "Server '{}' {} '{}' has placeholder '{{{}}}' without a variable",
+                                server_name, field, template, placeholder
+                            )),
+                        );
+                    }
+                    used.push(placeholder);
+                }
+            }
+            Err(problem) => {
+                parsed = false;
+                report.error(
+                    pointer,
+                    ValidationError::InvalidServerVariable(format!(
+                        "Server '{}' {} '{}': {}",
+                        server_name, field, template, problem
+                    )),
+                );
+            }
+        }
+    }
+
+    for (name, variable) in variables.into_iter().flatten() {
+        let pointer = json_pointer(["servers", server_name, "variables", name]);
+        if parsed && !used.contains(&name.as_str()) {
+            report.error(
+                pointer.clone(),
+                ValidationError::InvalidServerVariable(format!(
+                    "Variable '{}' of server '{}' does not appear in its host or pathname",
+                    name, server_name
+                )),
+            );
+        }
+        if let (Some(default), Some(values)) = (&variable.default, &variable.enum_values) {
+            if !values.contains(default) {
+                report.error(
+                    format!("{}/default", pointer),
+                    ValidationError::InvalidServerVariable(format!(
+                        "Variable '{}' of server '{}' has default '{}', which is not one of its enum values {:?}",
+                        name, server_name, default, values
+                    )),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use crate::validate_spec_report;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_server_variables() {
+        let spec = test_utils::spec(json!({
+            "servers": {
+                "valid": {
+                    "host": "{host}:{port}",
+                    "pathname": "/{env}",
+                    "protocol": "nats",
+                    "variables": {
+                        "host": {"default": "localhost"},
+                        "port": {"default": "4222", "enum": ["4222", "4223"]},
+                        "env": {}
+                    }
+                },
+                "invalid": {
+                    "host": "{host}:{port}",
+                    "protocol": "nats",
+                    "variables": {
+                        "port": {"default": "9999", "enum": ["4222", "4223"]},
+                        "region": {"default": "eu"}
+                    }
+                },
+                "malformed": {
+                    "host": "{host",
+                    "protocol": "nats",
+                    "variables": {"host": {}}
+                }
+            },
+            "channels": {
+                "orders": {"address": "orders", "messages": {"Order": {"payload": {"type": "object"}}}}
+            }
+        }));
+
+        let report = validate_spec_report(&spec);
+        let pointers: Vec<_> = report
+            .errors()
+            .filter(|issue| matches!(issue.error, ValidationError::InvalidServerVariable(_)))
+            .map(|issue| issue.pointer.as_str())
+            .collect();
+        assert_eq!(
+            pointers,
+            [
+                "/servers/invalid/host",
+                "/servers/invalid/variables/port/default",
+                "/servers/invalid/variables/region",
+                "/servers/malformed/host",
+            ]
+        );
+    }
+}
@@ -2,15 +2,24 @@
 
 use crate::error::ValidationError;
 use crate::protocol;
+use crate::resolve::ref_segments;
 use crate::spec::*;
 use crate::types::ASYNCAPI_VERSION;
+use std::collections::HashSet;
 
 use super::bindings::{get_channel_protocol, validate_channel_bindings};
+use super::examples::validate_examples;
+use super::extensions::validate_extensions;
+use super::parameters::validate_parameters;
+use super::reply::{validate_operation_reply, validate_reply};
+use super::report::{json_pointer, ValidationReport};
+use super::security::validate_security;
+use super::servers::validate_server_variables;
 
 /// Validate an AsyncAPI specification
 ///
-/// Returns `Ok(())` if the specification is valid, or an error describing
-/// what validation failed.
+/// Returns `Ok(())` if the specification is valid, or the first error found.
+/// Use [`validate_spec_report`] to get every error and warning at once.
 ///
 /// # Example
 ///
@@ -35,52 +44,104 @@ use super::bindings::{get_channel_protocol, validate_channel_bindings};
 /// }
 /// ```
 pub fn validate_spec(spec: &AsyncApiSpec) -> Result<(), ValidationError> {
-    // Validate AsyncAPI version
+    validate_spec_report(spec).into_result()
+}
+
+/// Validate an AsyncAPI specification, collecting every error and warning
+///
+/// Each issue carries a JSON Pointer to the offending node, so a large spec
+/// can be fixed in one pass instead of one error per rebuild.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use protofolio::{AsyncApi, validate_spec_report};
+/// # use protofolio_derive::AsyncApi;
+/// #
+/// # #[derive(AsyncApi)]
+/// # #[asyncapi(info(title = "Test", version = "1.0.0"), channels("events"), messages())]
+/// # struct MyApi;
+///
+/// let report = validate_spec_report(&MyApi::asyncapi());
+/// for issue in report.issues() {
+///     eprintln!("{} at {}: {}", issue.severity, issue.pointer, issue.error);
+/// }
+/// if !report.is_valid() {
+///     std::process::exit(1);
+/// }
+/// ```
+pub fn validate_spec_report(spec: &AsyncApiSpec) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    // Message IDs must be unique across channels and components
+    let mut message_ids = HashSet::new();
+
+    validate_document(spec, &mut report);
+    validate_channels(spec, &mut message_ids, &mut report);
+    validate_parameters(spec, &mut report);
+    validate_operations(spec, &mut report);
+    validate_servers(spec, &mut report);
+    validate_security(spec, &mut report);
+    validate_bindings(spec, &mut report);
+    validate_components(spec, &mut message_ids, &mut report);
+    validate_examples(spec, &mut report);
+    validate_extensions(spec, &mut report);
+
+    report
+}
+
+/// Validate the AsyncAPI version and the info section
+fn validate_document(spec: &AsyncApiSpec, report: &mut ValidationReport) {
     if spec.asyncapi != ASYNCAPI_VERSION {
-        return Err(ValidationError::InvalidAsyncApiVersion(
-            spec.asyncapi.clone(),
-        ));
+        report.error(
+            "/asyncapi",
+            ValidationError::InvalidAsyncApiVersion(spec.asyncapi.clone()),
+        );
     }
 
-    // Validate info section
     if spec.info.title.is_empty() {
-        return Err(ValidationError::MissingRequiredField(
-            "info.title".to_string(),
-        ));
+        report.error(
+            "/info/title",
+            ValidationError::MissingRequiredField("info.title".to_string()),
+        );
     }
     if spec.info.version.is_empty() {
-        return Err(ValidationError::MissingRequiredField(
-            "info.version".to_string(),
-        ));
+        report.error(
+            "/info/version",
+            ValidationError::MissingRequiredField("info.version".to_string()),
+        );
     }
+}
 
-    // Validate channels
+/// Validate channels, their server references and their messages
+fn validate_channels(
+    spec: &AsyncApiSpec,
+    message_ids: &mut HashSet<String>,
+    report: &mut ValidationReport,
+) {
     if spec.channels.is_empty() {
-        return Err(ValidationError::EmptyChannels);
+        report.error("/channels", ValidationError::EmptyChannels);
+        return;
     }
 
     // Collect server names if servers are defined
-    let server_names: std::collections::HashSet<String> = spec
+    let server_names: HashSet<String> = spec
         .servers
         .as_ref()
         .map(|servers| servers.keys().cloned().collect())
         .unwrap_or_default();
 
-    // Track message IDs for duplicate detection
-    let mut message_ids = std::collections::HashSet::new();
-
-    // Validate each channel
     for (channel_name, channel) in &spec.channels {
         // Check if channel has messages
         if channel.messages.is_empty() {
-            return Err(ValidationError::ChannelWithoutMessages(
-                channel_name.clone(),
-            ));
+            report.error(
+                json_pointer(["channels", channel_name, "messages"]),
+                ValidationError::ChannelWithoutMessages(channel_name.clone()),
+            );
         }
 
         // Validate server references in channel
         if let Some(ref channel_servers) = channel.servers {
-            for server_name in channel_servers {
+            for (index, server_name) in channel_servers.iter().enumerate() {
                 if !server_names.contains(server_name) {
                     let available: Vec<_> = server_names.iter().collect();
                     let suggestion = if available.is_empty() {
@@ -88,167 +149,337 @@ pub fn validate_spec(spec: &AsyncApiSpec) -> Result<(), ValidationError> {
                     } else {
                         format!("Server '{}' not found. Available servers: {:?}. Update your channel's server reference or add the server in servers(...)", server_name, available)
                     };
-                    return Err(ValidationError::InvalidServerReference(format!(
-                        "{}: {}",
-                        server_name, suggestion
-                    )));
+                    report.error(
+                        json_pointer(["channels", channel_name, "servers", &index.to_string()]),
+                        ValidationError::InvalidServerReference(format!(
+                            "{}: {}",
+                            server_name, suggestion
+                        )),
+                    );
                 }
             }
         }
 
         // Validate messages in channel
         for (message_name, message_or_ref) in &channel.messages {
+            let pointer = json_pointer(["channels", channel_name, "messages", message_name]);
             match message_or_ref {
                 crate::spec::MessageOrRef::Message(message) => {
+                    validate_message_traits(spec, message_name, message, &pointer, report);
+
                     // Basic message validation - ensure payload schema exists
                     if message.payload.schema.is_null() {
-                        return Err(ValidationError::InvalidSchema(format!(
-                            "Message '{}' in channel '{}' has null schema",
-                            message_name, channel_name
-                        )));
+                        report.error(
+                            format!("{}/payload", pointer),
+                            ValidationError::InvalidSchema(format!(
+                                "Message '{}' in channel '{}' has null schema",
+                                message_name, channel_name
+                            )),
+                        );
                     }
 
                     // Check for duplicate message IDs
                     if let Some(ref msg_id) = message.message_id {
                         if !message_ids.insert(msg_id.clone()) {
-                            return Err(ValidationError::DuplicateMessageId(format!(
-                                "Message ID '{}' is used by multiple messages. Each message must have a unique messageId. Found in channel '{}', message '{}'",
-                                msg_id, channel_name, message_name
-                            )));
+                            report.error(
+                                format!("{}/messageId", pointer),
+                                ValidationError::DuplicateMessageId(format!(
+                                    "Message ID '{}' is used by multiple messages. Each message must have a unique messageId. Found in channel '{}', message '{}'",
+                                    msg_id, channel_name, message_name
+                                )),
+                            );
                         }
                     }
-
-                    // Also check message IDs in component messages if this message references one
-                    // (This will be handled when we validate components)
                 }
                 crate::spec::MessageOrRef::Ref(msg_ref) => {
-                    // Validate component reference exists
-                    if msg_ref.ref_path.starts_with("#/components/messages/") {
-                        let component_name = msg_ref
-                            .ref_path
-                            .strip_prefix("#/components/messages/")
-                            .unwrap_or("");
-
-                        // Check if component exists
-                        if let Some(ref components) = spec.components {
-                            if let Some(ref messages) = components.messages {
-                                if !messages.contains_key(component_name) {
-                                    return Err(ValidationError::InvalidSchema(format!(
-                                        "Message '{}' in channel '{}' references component '{}' which does not exist in components.messages",
-                                        message_name, channel_name, component_name
-                                    )));
-                                }
-                            } else {
-                                return Err(ValidationError::InvalidSchema(format!(
-                                    "Message '{}' in channel '{}' references component '{}' but no components.messages are defined",
-                                    message_name, channel_name, component_name
-                                )));
-                            }
-                        } else {
-                            return Err(ValidationError::InvalidSchema(format!(
-                                "Message '{}' in channel '{}' references component '{}' but no components section is defined",
-                                message_name, channel_name, component_name
-                            )));
-                        }
-                    } else if msg_ref.ref_path.starts_with("#/channels/") {
-                        // Channel message reference - validate it exists
-                        // Extract channel and message name from path like "#/channels/{channel}/messages/{message}"
-                        let path_parts: Vec<&str> = msg_ref
-                            .ref_path
-                            .strip_prefix("#/channels/")
-                            .unwrap_or("")
-                            .split("/messages/")
-                            .collect();
-
-                        if path_parts.len() == 2 {
-                            let ref_channel = path_parts[0];
-                            let ref_message = path_parts[1];
-
-                            if let Some(ref_channel_obj) = spec.channels.get(ref_channel) {
-                                if !ref_channel_obj.messages.contains_key(ref_message) {
-                                    return Err(ValidationError::InvalidSchema(format!(
-                                        "Message '{}' in channel '{}' references message '{}' in channel '{}' which does not exist",
-                                        message_name, channel_name, ref_message, ref_channel
-                                    )));
-                                }
-                            } else {
-                                return Err(ValidationError::InvalidSchema(format!(
-                                    "Message '{}' in channel '{}' references channel '{}' which does not exist",
-                                    message_name, channel_name, ref_channel
-                                )));
-                            }
-                        }
-                    } else {
-                        return Err(ValidationError::InvalidSchema(format!(
-                            "Invalid message reference format in channel '{}', message '{}': {}. Expected '#/components/messages/...' or '#/channels/.../messages/...'",
-                            channel_name, message_name, msg_ref.ref_path
-                        )));
+                    if let Some(error) =
+                        check_channel_message_ref(spec, channel_name, message_name, msg_ref)
+                    {
+                        report.error(pointer, error);
                     }
                 }
             }
         }
     }
+}
 
-    // Validate operations if present
-    if let Some(ref operations) = spec.operations {
-        for (op_id, op) in operations {
-            // Validate channel reference format
-            if !op.channel.ref_path.starts_with("#/channels/") {
-                return Err(ValidationError::InvalidChannelReference(
-                    op.channel.ref_path.clone(),
-                ));
+/// Check that a message reference inside a channel resolves
+fn check_channel_message_ref(
+    spec: &AsyncApiSpec,
+    channel_name: &str,
+    message_name: &str,
+    msg_ref: &MessageReference,
+) -> Option<ValidationError> {
+    let segments = ref_segments(&msg_ref.ref_path).unwrap_or_default();
+    match segments
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["components", "messages", component_name] => {
+            // Check if component exists
+            let Some(ref components) = spec.components else {
+                return Some(ValidationError::InvalidSchema(format!(
+                    "Message '{}' in channel '{}' references component '{}' but no components section is defined",
+                    message_name, channel_name, component_name
+                )));
+            };
+            let Some(ref messages) = components.messages else {
+                return Some(ValidationError::InvalidSchema(format!(
+                    "Message '{}' in channel '{}' references component '{}' but no components.messages are defined",
+                    message_name, channel_name, component_name
+                )));
+            };
+            if !messages.contains_key(*component_name) {
+                return Some(ValidationError::InvalidSchema(format!(
+                    "Message '{}' in channel '{}' references component '{}' which does not exist in components.messages",
+                    message_name, channel_name, component_name
+                )));
+            }
+        }
+        // Channel message reference: "#/channels/{channel}/messages/{message}"
+        ["channels", ref_channel, "messages", ref_message] => match spec.channels.get(*ref_channel)
+        {
+            Some(ref_channel_obj) if !ref_channel_obj.messages.contains_key(*ref_message) => {
+                return Some(ValidationError::InvalidSchema(format!(
+                    "Message '{}' in channel '{}' references message '{}' in channel '{}' which does not exist",
+                    message_name, channel_name, ref_message, ref_channel
+                )));
+            }
+            Some(_) => {}
+            None => {
+                return Some(ValidationError::InvalidSchema(format!(
+                    "Message '{}' in channel '{}' references channel '{}' which does not exist",
+                    message_name, channel_name, ref_channel
+                )));
             }
+        },
+        _ => {
+            return Some(ValidationError::InvalidSchema(format!(
+                "Invalid message reference format in channel '{}', message '{}': {}. Expected '#/components/messages/...' or '#/channels/.../messages/...'",
+                channel_name, message_name, msg_ref.ref_path
+            )));
+        }
+    }
+    None
+}
 
-            // Validate message references
-            for msg_ref in &op.messages {
-                // Message references can point to:
-                // - Channel messages: "#/channels/{channel}/messages/{message}"
-                // - Component messages: "#/components/messages/{message}"
-                if !msg_ref.ref_path.starts_with("#/channels/")
-                    && !msg_ref.ref_path.starts_with("#/components/messages/")
-                {
-                    return Err(ValidationError::InvalidSchema(format!(
-                        "Invalid message reference format in operation '{}': {}. Expected '#/channels/.../messages/...' or '#/components/messages/...'",
-                        op_id, msg_ref.ref_path
-                    )));
-                }
+/// Whether `reference` points at an entry of `components.{section}`
+fn component_exists(spec: &AsyncApiSpec, reference: &str, section: &str) -> bool {
+    let Some(ref components) = spec.components else {
+        return false;
+    };
+    match ref_segments(reference).as_deref() {
+        Some([prefix, ref_section, name]) if prefix == "components" && ref_section == section => {
+            match section {
+                "operationTraits" => components
+                    .operation_traits
+                    .as_ref()
+                    .is_some_and(|traits| traits.contains_key(name)),
+                "messageTraits" => components
+                    .message_traits
+                    .as_ref()
+                    .is_some_and(|traits| traits.contains_key(name)),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
 
-                // If it's a component reference, validate it exists
-                if msg_ref.ref_path.starts_with("#/components/messages/") {
-                    let component_name = msg_ref
-                        .ref_path
-                        .strip_prefix("#/components/messages/")
-                        .unwrap_or("");
-
-                    if let Some(ref components) = spec.components {
-                        if let Some(ref messages) = components.messages {
-                            if !messages.contains_key(component_name) {
-                                return Err(ValidationError::InvalidSchema(format!(
-                                    "Operation '{}' references component message '{}' which does not exist in components.messages",
-                                    op_id, component_name
-                                )));
-                            }
-                        } else {
-                            return Err(ValidationError::InvalidSchema(format!(
-                                "Operation '{}' references component message '{}' but no components.messages are defined",
-                                op_id, component_name
-                            )));
-                        }
-                    } else {
-                        return Err(ValidationError::InvalidSchema(format!(
-                            "Operation '{}' references component message '{}' but no components section is defined",
-                            op_id, component_name
-                        )));
+/// Validate that the trait references of a message point at `components.messageTraits`
+fn validate_message_traits(
+    spec: &AsyncApiSpec,
+    message_name: &str,
+    message: &Message,
+    pointer: &str,
+    report: &mut ValidationReport,
+) {
+    for (index, message_trait) in message.traits.iter().flatten().enumerate() {
+        if let MessageTraitOrRef::Ref(reference) = message_trait {
+            if !component_exists(spec, &reference.ref_path, "messageTraits") {
+                report.error(
+                    format!("{}/traits/{}", pointer, index),
+                    ValidationError::MessageTraitNotFound {
+                        message: message_name.to_string(),
+                        reference: reference.ref_path.clone(),
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Validate operations: channel and message references, and replies
+fn validate_operations(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    let Some(ref operations) = spec.operations else {
+        return;
+    };
+
+    for (op_id, op) in operations {
+        // Validate channel reference format and that the channel exists
+        let channel_name = match ref_segments(&op.channel.ref_path).as_deref() {
+            Some([section, name]) if section == "channels" => Some(name.clone()),
+            _ => {
+                report.error(
+                    json_pointer(["operations", op_id, "channel"]),
+                    ValidationError::InvalidChannelReference(op.channel.ref_path.clone()),
+                );
+                None
+            }
+        };
+        let channel = channel_name.as_ref().and_then(|name| {
+            let channel = spec.channels.get(name);
+            if channel.is_none() {
+                report.error(
+                    json_pointer(["operations", op_id, "channel"]),
+                    ValidationError::OperationChannelNotFound {
+                        operation: op_id.clone(),
+                        channel: name.clone(),
+                    },
+                );
+            }
+            channel
+        });
+
+        if op.messages.is_empty() {
+            report.warning(
+                json_pointer(["operations", op_id, "messages"]),
+                ValidationError::OperationWithoutMessages(op_id.clone()),
+            );
+        }
+
+        // Validate message references
+        for (index, msg_ref) in op.messages.iter().enumerate() {
+            let pointer = json_pointer(["operations", op_id, "messages", &index.to_string()]);
+
+            // Message references can point to:
+            // - Channel messages: "#/channels/{channel}/messages/{message}"
+            // - Component messages: "#/components/messages/{message}"
+            let segments = ref_segments(&msg_ref.ref_path).unwrap_or_default();
+            let component_name = match segments
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                ["channels", ref_channel, "messages", ref_message] => {
+                    let exists = spec
+                        .channels
+                        .get(*ref_channel)
+                        .is_some_and(|c| c.messages.contains_key(*ref_message));
+                    if !exists {
+                        report.error(
+                            pointer,
+                            ValidationError::OperationMessageNotFound {
+                                operation: op_id.clone(),
+                                reference: msg_ref.ref_path.clone(),
+                            },
+                        );
+                    } else if channel.is_some() && channel_name.as_deref() != Some(*ref_channel) {
+                        report.error(
+                            pointer,
+                            ValidationError::OperationMessageNotInChannel {
+                                operation: op_id.clone(),
+                                message: msg_ref.ref_path.clone(),
+                                channel: channel_name.clone().unwrap_or_default(),
+                            },
+                        );
                     }
+                    continue;
+                }
+                ["components", "messages", component_name] => (*component_name).to_string(),
+                _ => {
+                    report.error(
+                        pointer,
+                        ValidationError::InvalidSchema(format!(
+                            "Invalid message reference format in operation '{}': {}. Expected '#/channels/.../messages/...' or '#/components/messages/...'",
+                            op_id, msg_ref.ref_path
+                        )),
+                    );
+                    continue;
+                }
+            };
+
+            // Component references must exist
+            let error = match spec.components {
+                None => Some(format!(
+                    "Operation '{}' references component message '{}' but no components section is defined",
+                    op_id, component_name
+                )),
+                Some(Components { messages: None, .. }) => Some(format!(
+                    "Operation '{}' references component message '{}' but no components.messages are defined",
+                    op_id, component_name
+                )),
+                Some(Components {
+                    messages: Some(ref messages),
+                    ..
+                }) if !messages.contains_key(&component_name) => Some(format!(
+                    "Operation '{}' references component message '{}' which does not exist in components.messages",
+                    op_id, component_name
+                )),
+                Some(_) => None,
+            };
+            if let Some(error) = error {
+                report.error(pointer, ValidationError::InvalidSchema(error));
+            } else if let Some(channel) = channel {
+                // A component message belongs to the channel when the channel references it
+                let in_channel = channel.messages.values().any(|message| {
+                    matches!(message, MessageOrRef::Ref(reference)
+                        if ref_segments(&reference.ref_path).as_ref() == Some(&segments))
+                });
+                if !in_channel {
+                    report.error(
+                        pointer,
+                        ValidationError::OperationMessageNotInChannel {
+                            operation: op_id.clone(),
+                            message: msg_ref.ref_path.clone(),
+                            channel: channel_name.clone().unwrap_or_default(),
+                        },
+                    );
                 }
             }
         }
+
+        for (index, operation_trait) in op.traits.iter().flatten().enumerate() {
+            if let OperationTraitOrRef::Ref(reference) = operation_trait {
+                if !component_exists(spec, &reference.ref_path, "operationTraits") {
+                    report.error(
+                        json_pointer(["operations", op_id, "traits", &index.to_string()]),
+                        ValidationError::OperationTraitNotFound {
+                            operation: op_id.clone(),
+                            reference: reference.ref_path.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        // Validate the reply channel and messages of request/reply operations
+        if let Some(ref reply) = op.reply {
+            validate_operation_reply(
+                &format!("Operation '{}'", op_id),
+                &json_pointer(["operations", op_id, "reply"]),
+                reply,
+                spec,
+                report,
+            );
+        }
     }
+}
 
-    // Validate protocol identifiers
-    if let Some(ref servers) = spec.servers {
-        for (server_name, server) in servers {
-            protocol::validate_protocol(&server.protocol).map_err(|e| match e {
+/// Validate server host/pathname and protocol identifiers
+fn validate_servers(spec: &AsyncApiSpec, report: &mut ValidationReport) {
+    let Some(ref servers) = spec.servers else {
+        return;
+    };
+
+    for (server_name, server) in servers {
+        validate_server(server_name, server, report);
+        validate_server_variables(server_name, server, report);
+        if let Err(e) = protocol::validate_protocol(&server.protocol) {
+            let error = match e {
                 ValidationError::UnsupportedProtocol {
                     protocol,
                     supported,
@@ -257,59 +488,130 @@ pub fn validate_spec(spec: &AsyncApiSpec) -> Result<(), ValidationError> {
                     server_name, protocol, supported
                 )),
                 _ => e,
-            })?;
+            };
+            report.error(json_pointer(["servers", server_name, "protocol"]), error);
         }
     }
+}
 
-    // Validate protocol-specific bindings
+/// Validate protocol-specific bindings
+fn validate_bindings(spec: &AsyncApiSpec, report: &mut ValidationReport) {
     for (channel_name, channel) in &spec.channels {
-        if let Some(ref bindings_or_ref) = channel.bindings {
-            // Validate bindings structure matches protocol
-            if let Some(protocol) = get_channel_protocol(channel, spec) {
-                // Extract actual bindings value from ChannelBindingsOrRef
-                let bindings_value = match bindings_or_ref {
-                    crate::spec::ChannelBindingsOrRef::Bindings(b) => b,
-                    // For references, we skip validation (component validation happens separately)
-                    crate::spec::ChannelBindingsOrRef::Ref(_) => continue,
-                };
-                validate_channel_bindings(&protocol, bindings_value, channel_name)?;
+        let Some(ref bindings) = channel.bindings else {
+            continue;
+        };
+        // Validate bindings structure matches protocol
+        if let Some(protocol) = get_channel_protocol(channel, spec) {
+            if let Err(e) = validate_channel_bindings(&protocol, bindings, channel_name) {
+                report.error(json_pointer(["channels", channel_name, "bindings"]), e);
             }
         }
     }
+}
+
+/// Validate reusable components (messages and replies)
+fn validate_components(
+    spec: &AsyncApiSpec,
+    message_ids: &mut HashSet<String>,
+    report: &mut ValidationReport,
+) {
+    let Some(ref components) = spec.components else {
+        return;
+    };
+
+    if let Some(ref messages) = components.messages {
+        for (component_name, message) in messages {
+            validate_message_traits(
+                spec,
+                component_name,
+                message,
+                &json_pointer(["components", "messages", component_name]),
+                report,
+            );
 
-    // Validate component messages (check for duplicate message IDs across components)
-    if let Some(ref components) = spec.components {
-        if let Some(ref messages) = components.messages {
-            for (component_name, message) in messages {
-                // Validate component message has valid schema
-                if message.payload.schema.is_null() {
-                    return Err(ValidationError::InvalidSchema(format!(
+            // Validate component message has valid schema
+            if message.payload.schema.is_null() {
+                report.error(
+                    json_pointer(["components", "messages", component_name, "payload"]),
+                    ValidationError::InvalidSchema(format!(
                         "Component message '{}' has null schema",
                         component_name
-                    )));
-                }
+                    )),
+                );
+            }
 
-                // Check for duplicate message IDs in components
-                if let Some(ref msg_id) = message.message_id {
-                    if !message_ids.insert(msg_id.clone()) {
-                        return Err(ValidationError::DuplicateMessageId(format!(
+            // Check for duplicate message IDs in components
+            if let Some(ref msg_id) = message.message_id {
+                if !message_ids.insert(msg_id.clone()) {
+                    report.error(
+                        json_pointer(["components", "messages", component_name, "messageId"]),
+                        ValidationError::DuplicateMessageId(format!(
                             "Message ID '{}' is used by multiple messages. Found in component message '{}'",
                             msg_id, component_name
-                        )));
-                    }
+                        )),
+                    );
                 }
             }
         }
     }
 
-    Ok(())
+    if let Some(ref replies) = components.replies {
+        for (reply_name, reply) in replies {
+            validate_reply(
+                &format!("Component reply '{}'", reply_name),
+                &json_pointer(["components", "replies", reply_name]),
+                reply,
+                spec,
+                report,
+            );
+        }
+    }
+}
+
+/// Validate the AsyncAPI 3.0 shape of a server (`host` and `pathname`)
+fn validate_server(server_name: &str, server: &Server, report: &mut ValidationReport) {
+    let host_pointer = json_pointer(["servers", server_name, "host"]);
+    if server.host.is_empty() {
+        report.error(
+            host_pointer,
+            ValidationError::MissingRequiredField(format!("servers.{}.host", server_name)),
+        );
+    } else if server.host.contains("://") {
+        report.error(
+            host_pointer,
+            ValidationError::InvalidServer(format!(
+                "Server '{}' host '{}' must not include a scheme; the protocol is given by 'protocol'",
+                server_name, server.host
+            )),
+        );
+    } else if server.host.contains('/') {
+        report.error(
+            host_pointer,
+            ValidationError::InvalidServer(format!(
+                "Server '{}' host '{}' must not include a path; move it to 'pathname'",
+                server_name, server.host
+            )),
+        );
+    }
+    if let Some(ref pathname) = server.pathname {
+        if !pathname.starts_with('/') {
+            report.error(
+                json_pointer(["servers", server_name, "pathname"]),
+                ValidationError::InvalidServer(format!(
+                    "Server '{}' pathname '{}' must start with '/'",
+                    server_name, pathname
+                )),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::builder::AsyncApiBuilder;
-    use std::collections::HashMap;
+    use crate::test_utils;
+    use indexmap::IndexMap;
 
     #[test]
     fn test_validate_valid_spec() {
@@ -322,6 +624,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
@@ -330,7 +633,7 @@ mod tests {
                     description: None,
                     messages: {
                         use crate::spec::MessageOrRef;
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "TestMessage".to_string(),
                             MessageOrRef::Message(Message {
@@ -350,6 +653,7 @@ mod tests {
                                 correlation_id: None,
                                 traits: None,
                                 bindings: None,
+                                extensions: Default::default(),
                             }),
                         );
                         m
@@ -357,6 +661,7 @@ mod tests {
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -364,6 +669,49 @@ mod tests {
         assert!(validate_spec(&spec).is_ok());
     }
 
+    #[test]
+    fn test_validate_server_host_with_scheme() {
+        let server = |host: &str, pathname: Option<&str>| Server {
+            host: host.to_string(),
+            protocol: "nats".to_string(),
+            protocol_version: None,
+            pathname: pathname.map(str::to_string),
+            title: None,
+            summary: None,
+            description: None,
+            tags: None,
+            external_docs: None,
+            bindings: None,
+            security: None,
+            variables: None,
+            extensions: Default::default(),
+        };
+
+        let check = |server: &Server| {
+            let mut report = ValidationReport::new();
+            validate_server("nats", server, &mut report);
+            report.into_result()
+        };
+
+        assert!(check(&server("localhost:4222", Some("/events"))).is_ok());
+        assert!(matches!(
+            check(&server("nats://localhost:4222", None)),
+            Err(ValidationError::InvalidServer(_))
+        ));
+        assert!(matches!(
+            check(&server("localhost:4222/events", None)),
+            Err(ValidationError::InvalidServer(_))
+        ));
+        assert!(matches!(
+            check(&server("localhost:4222", Some("events"))),
+            Err(ValidationError::InvalidServer(_))
+        ));
+        assert!(matches!(
+            check(&server("", None)),
+            Err(ValidationError::MissingRequiredField(_))
+        ));
+    }
+
     #[test]
     fn test_validate_missing_title() {
         let spec = AsyncApiBuilder::new()
@@ -375,6 +723,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .build();
 
@@ -395,6 +744,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .build();
 
@@ -415,6 +765,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
@@ -423,7 +774,7 @@ mod tests {
                     description: None,
                     messages: {
                         use crate::spec::MessageOrRef;
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "TestMessage".to_string(),
                             MessageOrRef::Message(Message {
@@ -443,6 +794,7 @@ mod tests {
                                 correlation_id: None,
                                 traits: None,
                                 bindings: None,
+                                extensions: Default::default(),
                             }),
                         );
                         m
@@ -450,6 +802,7 @@ mod tests {
                     servers: Some(vec!["nonexistent".to_string()]),
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -471,16 +824,18 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
                 Channel {
                     address: "empty.channel".to_string(),
                     description: None,
-                    messages: HashMap::new(),
+                    messages: IndexMap::new(),
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -502,6 +857,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
@@ -510,7 +866,7 @@ mod tests {
                     description: None,
                     messages: {
                         use crate::spec::MessageOrRef;
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "Message1".to_string(),
                             MessageOrRef::Message(Message {
@@ -530,6 +886,7 @@ mod tests {
                                 correlation_id: None,
                                 traits: None,
                                 bindings: None,
+                                extensions: Default::default(),
                             }),
                         );
                         m.insert(
@@ -551,6 +908,7 @@ mod tests {
                                 correlation_id: None,
                                 traits: None,
                                 bindings: None,
+                                extensions: Default::default(),
                             }),
                         );
                         m
@@ -558,6 +916,7 @@ mod tests {
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -570,7 +929,7 @@ mod tests {
 
     #[test]
     fn test_validate_component_message_ref() {
-        use crate::spec::{MessageOrRef, MessageReference};
+        use crate::spec::MessageOrRef;
 
         let spec = AsyncApiBuilder::new()
             .info(Info {
@@ -581,6 +940,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .component_message(
                 "CommonMessage".to_string(),
@@ -601,6 +961,7 @@ mod tests {
                     correlation_id: None,
                     traits: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .channel(
@@ -609,7 +970,7 @@ mod tests {
                     address: "common.channel".to_string(),
                     description: None,
                     messages: {
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "CommonMessage".to_string(),
                             MessageOrRef::component_ref("CommonMessage"),
@@ -619,6 +980,7 @@ mod tests {
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -639,6 +1001,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .channel(
                 "test.channel".to_string(),
@@ -646,7 +1009,7 @@ mod tests {
                     address: "ref.channel".to_string(),
                     description: None,
                     messages: {
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "NonExistent".to_string(),
                             MessageOrRef::component_ref("NonExistent"),
@@ -656,6 +1019,7 @@ mod tests {
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
@@ -669,7 +1033,7 @@ mod tests {
     #[test]
     fn test_validate_operation_with_component_ref() {
         use crate::spec::{ChannelReference, MessageOrRef, MessageReference, Operation};
-        use std::collections::HashMap;
+        use indexmap::IndexMap;
 
         let mut spec = AsyncApiBuilder::new()
             .info(Info {
@@ -680,6 +1044,7 @@ mod tests {
                 contact: None,
                 license: None,
                 terms_of_service: None,
+                extensions: Default::default(),
             })
             .component_message(
                 "ComponentMsg".to_string(),
@@ -700,6 +1065,7 @@ mod tests {
                     correlation_id: None,
                     traits: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .channel(
@@ -708,7 +1074,7 @@ mod tests {
                     address: "test.channel".to_string(),
                     description: None,
                     messages: {
-                        let mut m = HashMap::new();
+                        let mut m = IndexMap::new();
                         m.insert(
                             "InlineMessage".to_string(),
                             MessageOrRef::Message(Message {
@@ -728,6 +1094,14 @@ mod tests {
                                 correlation_id: None,
                                 traits: None,
                                 bindings: None,
+                                extensions: Default::default(),
+                            }),
+                        );
+                        // Operations may only use messages of their channel
+                        m.insert(
+                            "ComponentMsg".to_string(),
+                            MessageOrRef::Ref(MessageReference {
+                                ref_path: "#/components/messages/ComponentMsg".to_string(),
                             }),
                         );
                         m
@@ -735,12 +1109,13 @@ mod tests {
                     servers: None,
                     parameters: None,
                     bindings: None,
+                    extensions: Default::default(),
                 },
             )
             .build();
 
         // Add operation with component reference
-        let mut operations = HashMap::new();
+        let mut operations = IndexMap::new();
         operations.insert(
             "testOp".to_string(),
             Operation {
@@ -758,10 +1133,250 @@ mod tests {
                 external_docs: None,
                 traits: None,
                 bindings: None,
+                reply: None,
+                extensions: Default::default(),
             },
         );
         spec.operations = Some(operations);
 
         assert!(validate_spec(&spec).is_ok());
     }
+
+    fn request_reply_spec(reply: &serde_json::Value) -> AsyncApiSpec {
+        test_utils::spec(serde_json::json!({
+            "channels": {
+                "user.requests": {
+                    "address": "user.requests",
+                    "messages": {"GetUser": {"payload": {"type": "object"}}}
+                },
+                "user.replies": {
+                    "address": "user.replies",
+                    "messages": {"UserFound": {"payload": {"type": "object"}}}
+                }
+            },
+            "operations": {
+                "getUser": {
+                    "operationId": "getUser",
+                    "action": "send",
+                    "channel": {"$ref": "#/channels/user.requests"},
+                    "messages": [{"$ref": "#/channels/user.requests/messages/GetUser"}],
+                    "reply": reply
+                }
+            },
+            "components": {
+                "replies": {
+                    "UserReply": {"channel": {"$ref": "#/channels/user.replies"}}
+                }
+            }
+        }))
+    }
+
+    #[test]
+    fn test_validate_operation_reply() {
+        let valid = request_reply_spec(&serde_json::json!({
+            "address": {"location": "$message.header#/replyTo"},
+            "channel": {"$ref": "#/channels/user.replies"},
+            "messages": [{"$ref": "#/channels/user.replies/messages/UserFound"}]
+        }));
+        assert!(validate_spec(&valid).is_ok());
+
+        let component =
+            request_reply_spec(&serde_json::json!({"$ref": "#/components/replies/UserReply"}));
+        assert!(validate_spec(&component).is_ok());
+    }
+
+    #[test]
+    fn test_validate_operation_reply_errors() {
+        let missing_channel = request_reply_spec(&serde_json::json!({
+            "channel": {"$ref": "#/channels/user.unknown"}
+        }));
+        assert!(matches!(
+            validate_spec(&missing_channel),
+            Err(ValidationError::InvalidChannelReference(channel)) if channel == "user.unknown"
+        ));
+
+        let missing_message = request_reply_spec(&serde_json::json!({
+            "channel": {"$ref": "#/channels/user.replies"},
+            "messages": [{"$ref": "#/channels/user.replies/messages/UserMissing"}]
+        }));
+        assert!(matches!(
+            validate_spec(&missing_message),
+            Err(ValidationError::MessageNotFound { message, .. }) if message == "UserMissing"
+        ));
+
+        let foreign_message = request_reply_spec(&serde_json::json!({
+            "channel": {"$ref": "#/channels/user.replies"},
+            "messages": [{"$ref": "#/channels/user.requests/messages/GetUser"}]
+        }));
+        assert!(matches!(
+            validate_spec(&foreign_message),
+            Err(ValidationError::InvalidOperationReply(_))
+        ));
+
+        let bad_location = request_reply_spec(&serde_json::json!({
+            "address": {"location": "replyTo"}
+        }));
+        assert!(matches!(
+            validate_spec(&bad_location),
+            Err(ValidationError::InvalidOperationReply(_))
+        ));
+
+        let missing_component =
+            request_reply_spec(&serde_json::json!({"$ref": "#/components/replies/Unknown"}));
+        assert!(matches!(
+            validate_spec(&missing_component),
+            Err(ValidationError::InvalidOperationReply(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_spec_report_collects_all_issues() {
+        let spec = test_utils::spec(serde_json::json!({
+            "info": {"title": "", "version": "1.0.0"},
+            "servers": {
+                "broker": {"host": "nats://localhost:4222", "protocol": "nats"}
+            },
+            "channels": {
+                "orders": {
+                    "address": "orders",
+                    "servers": ["missing"],
+                    "messages": {
+                        "OrderCreated": {"$ref": "#/components/messages/OrderCreated"}
+                    }
+                },
+                "audit/log": {"address": "audit", "messages": {}}
+            },
+            "operations": {
+                "listen": {
+                    "operationId": "listen",
+                    "action": "receive",
+                    "channel": {"$ref": "#/channels/orders"},
+                    "messages": []
+                }
+            }
+        }));
+
+        let report = validate_spec_report(&spec);
+        assert!(!report.is_valid());
+
+        let pointers: Vec<_> = report
+            .errors()
+            .map(|issue| issue.pointer.as_str())
+            .collect();
+        assert_eq!(
+            pointers,
+            [
+                "/info/title",
+                "/channels/orders/servers/0",
+                "/channels/orders/messages/OrderCreated",
+                "/channels/audit~1log/messages",
+                "/servers/broker/host",
+            ]
+        );
+        let warnings: Vec<_> = report
+            .warnings()
+            .map(|issue| issue.pointer.as_str())
+            .collect();
+        assert_eq!(warnings, ["/operations/listen/messages"]);
+
+        // validate_spec still reports the first error
+        assert_eq!(
+            validate_spec(&spec),
+            Err(ValidationError::MissingRequiredField(
+                "info.title".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_operation_and_trait_references() {
+        let spec = test_utils::spec(serde_json::json!({
+            "channels": {
+                "orders": {
+                    "address": "orders",
+                    "messages": {
+                        "OrderPlaced": {
+                            "payload": {"type": "object"},
+                            "traits": [{"$ref": "#/components/messageTraits/Missing"}]
+                        },
+                        "Shared": {"$ref": "#/components/messages/Shared"}
+                    }
+                },
+                "shipments": {
+                    "address": "shipments",
+                    "messages": {"OrderShipped": {"payload": {"type": "object"}}}
+                }
+            },
+            "operations": {
+                "placeOrder": {
+                    "operationId": "placeOrder",
+                    "action": "send",
+                    "channel": {"$ref": "#/channels/orders"},
+                    "messages": [
+                        {"$ref": "#/channels/orders/messages/OrderPlaced"},
+                        {"$ref": "#/components/messages/Shared"},
+                        {"$ref": "#/channels/orders/messages/OrderCancelled"},
+                        {"$ref": "#/channels/shipments/messages/OrderShipped"},
+                        {"$ref": "#/components/messages/Other"}
+                    ],
+                    "traits": [
+                        {"$ref": "#/components/operationTraits/Audited"},
+                        {"$ref": "#/components/operationTraits/Missing"}
+                    ]
+                },
+                "trackOrder": {
+                    "operationId": "trackOrder",
+                    "action": "receive",
+                    "channel": {"$ref": "#/channels/tracking"},
+                    "messages": []
+                }
+            },
+            "components": {
+                "messages": {
+                    "Shared": {"payload": {"type": "object"}},
+                    "Other": {"payload": {"type": "object"}}
+                },
+                "operationTraits": {"Audited": {"summary": "Audited"}}
+            }
+        }));
+
+        let report = validate_spec_report(&spec);
+        let issues: Vec<_> = report
+            .errors()
+            .map(|issue| (issue.pointer.as_str(), &issue.error))
+            .collect();
+        assert!(
+            matches!(
+                issues.as_slice(),
+                [
+                    (
+                        "/channels/orders/messages/OrderPlaced/traits/0",
+                        ValidationError::MessageTraitNotFound { .. }
+                    ),
+                    (
+                        "/operations/placeOrder/messages/2",
+                        ValidationError::OperationMessageNotFound { .. }
+                    ),
+                    (
+                        "/operations/placeOrder/messages/3",
+                        ValidationError::OperationMessageNotInChannel { .. }
+                    ),
+                    (
+                        "/operations/placeOrder/messages/4",
+                        ValidationError::OperationMessageNotInChannel { .. }
+                    ),
+                    (
+                        "/operations/placeOrder/traits/1",
+                        ValidationError::OperationTraitNotFound { .. }
+                    ),
+                    (
+                        "/operations/trackOrder/channel",
+                        ValidationError::OperationChannelNotFound { .. }
+                    ),
+                ]
+            ),
+            "{:#?}",
+            issues
+        );
+    }
 }
@@ -0,0 +1,97 @@
+//! Tests for bundling multi-file specifications and splitting them again
+
+use protofolio::{
+    bundle, split, validate_spec, BundleError, DocumentFormat, Message, MessageOrRef,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/multi-file")
+        .join(name)
+}
+
+fn message_ref(spec: &protofolio::AsyncApiSpec, channel: &str, message: &str) -> String {
+    match &spec.channels[channel].messages[message] {
+        MessageOrRef::Ref(reference) => reference.ref_path.clone(),
+        MessageOrRef::Message(_) => panic!("{}/{} is inline", channel, message),
+    }
+}
+
+#[test]
+fn test_bundle_moves_external_refs_into_components() {
+    let spec = bundle(fixture("asyncapi.yaml")).unwrap();
+
+    assert_eq!(
+        message_ref(&spec, "orders", "OrderPlaced"),
+        "#/components/messages/order-placed"
+    );
+    let message: Message = spec
+        .resolve(&message_ref(&spec, "orders", "OrderPlaced"))
+        .unwrap();
+    assert_eq!(message.name.as_deref(), Some("OrderPlaced"));
+    // `Headers` is taken by a local schema
+    assert_eq!(
+        message.headers.unwrap().schema,
+        serde_json::json!({"$ref": "#/components/schemas/Headers2"})
+    );
+    assert_eq!(
+        message.payload.schema["properties"]["customer"],
+        serde_json::json!({"$ref": "#/components/schemas/Customer"})
+    );
+
+    // Both references to the customer schema share one component
+    let MessageOrRef::Message(shipped) = &spec.channels["shipments"].messages["OrderShipped"]
+    else {
+        panic!("OrderShipped is inline");
+    };
+    assert_eq!(
+        shipped.payload.schema["properties"]["customer"],
+        serde_json::json!({"$ref": "#/components/schemas/Customer"})
+    );
+
+    let schemas = spec.components.as_ref().unwrap().schemas.as_ref().unwrap();
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["Headers", "Headers2", "Address", "Customer"]
+    );
+    // Local references of the schemas file point at its other schemas
+    assert_eq!(
+        schemas["Customer"]["properties"]["address"],
+        serde_json::json!({"$ref": "#/components/schemas/Address"})
+    );
+    assert!(validate_spec(&spec).is_ok());
+}
+
+#[test]
+fn test_split_and_bundle_round_trip() {
+    let spec = bundle(fixture("asyncapi.yaml")).unwrap();
+    let dir = std::env::temp_dir().join(format!("protofolio-split-{}", std::process::id()));
+
+    let files = split(&spec, &dir, DocumentFormat::Yaml).unwrap();
+    assert_eq!(files[0], dir.join("asyncapi.yaml"));
+    assert!(files.contains(&dir.join("components/messages/order-placed.yaml")));
+    assert!(files.contains(&dir.join("components/schemas/Customer.yaml")));
+
+    let customer = std::fs::read_to_string(dir.join("components/schemas/Customer.yaml")).unwrap();
+    assert!(customer.contains("$ref: ../../components/schemas/Address.yaml"));
+
+    let bundled = bundle(dir.join("asyncapi.yaml")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        serde_json::to_value(&bundled).unwrap(),
+        serde_json::to_value(&spec).unwrap()
+    );
+}
+
+#[test]
+fn test_bundle_errors() {
+    assert_eq!(
+        bundle(fixture("remote.yaml")).unwrap_err(),
+        BundleError::Remote("https://example.com/messages.yaml#/Event".to_string())
+    );
+    assert!(matches!(
+        bundle(fixture("missing.yaml")),
+        Err(BundleError::Io { .. })
+    ));
+}
@@ -1,5 +1,6 @@
 //! Tests for error handling in try_asyncapi()
 
+use protofolio::IndexMap;
 use protofolio::{
     AsyncApi, AsyncApiBuilder, Channel, Info, Message, MessageOrRef, MessagePayload,
     ValidationError,
@@ -7,7 +8,6 @@ use protofolio::{
 use protofolio_derive::{AsyncApi, AsyncApiMessage};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
 #[asyncapi(
@@ -65,6 +65,7 @@ fn test_validation_error_on_invalid_spec() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .build();
 
@@ -91,16 +92,18 @@ fn test_validation_error_channel_without_messages() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .channel(
             "empty.channel".to_string(),
             Channel {
                 address: "empty.channel".to_string(),
                 description: None,
-                messages: HashMap::new(),
+                messages: IndexMap::new(),
                 servers: None,
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
         )
         .build();
@@ -118,7 +121,7 @@ fn test_validation_error_channel_without_messages() {
 #[test]
 fn test_validation_error_duplicate_message_id() {
     // Create a spec with duplicate message IDs
-    let mut messages1 = HashMap::new();
+    let mut messages1 = IndexMap::new();
     messages1.insert(
         "Message1".to_string(),
         MessageOrRef::Message(Message {
@@ -138,10 +141,11 @@ fn test_validation_error_duplicate_message_id() {
             correlation_id: None,
             traits: None,
             bindings: None,
+            extensions: Default::default(),
         }),
     );
 
-    let mut messages2 = HashMap::new();
+    let mut messages2 = IndexMap::new();
     messages2.insert(
         "Message2".to_string(),
         MessageOrRef::Message(Message {
@@ -161,6 +165,7 @@ fn test_validation_error_duplicate_message_id() {
             correlation_id: None,
             traits: None,
             bindings: None,
+            extensions: Default::default(),
         }),
     );
 
@@ -173,6 +178,7 @@ fn test_validation_error_duplicate_message_id() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .channel(
             "channel1".to_string(),
@@ -183,6 +189,7 @@ fn test_validation_error_duplicate_message_id() {
                 servers: None,
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
         )
         .channel(
@@ -194,6 +201,7 @@ fn test_validation_error_duplicate_message_id() {
                 servers: None,
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
         )
         .build();
@@ -220,6 +228,7 @@ fn test_error_message_quality() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .build();
 
@@ -230,3 +239,15 @@ fn test_error_message_quality() {
     assert!(error_msg.contains("Empty channels"));
     assert!(error_msg.contains("specification must have at least one channel"));
 }
+
+#[test]
+fn test_error_message_and_hint() {
+    let error = ValidationError::ChannelWithoutMessages("orders".to_string());
+
+    assert_eq!(error.message(), "Channel 'orders' has no messages");
+    assert!(error.hint().starts_with("Add at least one message type"));
+    assert_eq!(
+        error.to_string(),
+        format!("{}\n\nHint: {}", error.message(), error.hint())
+    );
+}
@@ -2,10 +2,11 @@
 //!
 //! These tests verify the full macro-generated code paths and serialization.
 
-use protofolio::{validate_spec, AsyncApi, AsyncApiOperation, Tag};
+use protofolio::{validate_spec, AsyncApi, AsyncApiOperation, ServerUrlError, Tag};
 use protofolio_derive::{AsyncApi, AsyncApiMessage, AsyncApiOperation};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // Test message types
 #[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
@@ -67,7 +68,7 @@ fn test_asyncapi_has_servers() {
     assert!(spec.servers.is_some());
     let servers = spec.servers.as_ref().unwrap();
     assert!(servers.contains_key("nats"));
-    assert_eq!(servers["nats"].url, "nats://localhost:4222");
+    assert_eq!(servers["nats"].host, "localhost:4222");
 }
 
 // Test server with variables
@@ -100,7 +101,7 @@ fn test_asyncapi_server_variables() {
     let servers = spec.servers.as_ref().unwrap();
     assert!(servers.contains_key("nats"));
     let server = &servers["nats"];
-    assert_eq!(server.url, "nats://{host}:{port}");
+    assert_eq!(server.host, "{host}:{port}");
     assert!(server.variables.is_some());
     let vars = server.variables.as_ref().unwrap();
     assert!(vars.contains_key("host"));
@@ -120,6 +121,79 @@ fn test_asyncapi_server_variables() {
             "4224".to_string()
         ])
     );
+    assert!(validate_spec(&spec).is_ok());
+
+    // Concrete broker URLs, falling back to the variable defaults
+    assert_eq!(
+        server.resolve_url(&HashMap::new()),
+        Ok("nats://localhost:4222".to_string())
+    );
+    let values = HashMap::from([
+        ("host".to_string(), "broker.internal".to_string()),
+        ("port".to_string(), "4224".to_string()),
+    ]);
+    assert_eq!(
+        server.resolve_url(&values),
+        Ok("nats://broker.internal:4224".to_string())
+    );
+    let values = HashMap::from([("port".to_string(), "80".to_string())]);
+    assert!(matches!(
+        server.resolve_url(&values),
+        Err(ServerUrlError::NotAllowed { .. })
+    ));
+}
+
+// Test AsyncAPI 3.0 server fields: url splitting and explicit host/pathname
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Test AsyncAPI Servers", version = "1.0.0"),
+    servers(
+        (name = "ws", url = "wss://api.example.com/ws/v1", protocol = "nats"),
+        (
+            name = "production",
+            host = "nats.example.com:4222",
+            pathname = "/events",
+            protocol = "nats",
+            protocol_version = "2.10",
+            title = "Production",
+            summary = "Production broker",
+            description = "Main production NATS cluster",
+            tags = ["production"],
+            external_docs(url = "https://example.com/servers", description = "Server docs")
+        )
+    ),
+    channels("test.channel"),
+    messages(TestMessage)
+)]
+struct TestAsyncApiServerFields;
+
+#[test]
+fn test_asyncapi_server_url_is_split() {
+    let spec = TestAsyncApiServerFields::asyncapi();
+    let server = &spec.servers.as_ref().unwrap()["ws"];
+    assert_eq!(server.host, "api.example.com");
+    assert_eq!(server.pathname, Some("/ws/v1".to_string()));
+
+    let nats = &TestAsyncApi::asyncapi().servers.unwrap()["nats"];
+    assert_eq!(nats.pathname, None);
+}
+
+#[test]
+fn test_asyncapi_server_fields_serialization() {
+    let spec = TestAsyncApiServerFields::asyncapi();
+    let json: serde_json::Value =
+        serde_json::from_str(&protofolio::to_json(&spec).unwrap()).unwrap();
+    let server = &json["servers"]["production"];
+    assert_eq!(server["host"], "nats.example.com:4222");
+    assert_eq!(server["pathname"], "/events");
+    assert_eq!(server["protocol"], "nats");
+    assert_eq!(server["protocolVersion"], "2.10");
+    assert_eq!(server["title"], "Production");
+    assert_eq!(server["summary"], "Production broker");
+    assert_eq!(server["tags"][0]["name"], "production");
+    assert_eq!(server["externalDocs"]["url"], "https://example.com/servers");
+    assert!(server.get("url").is_none());
+    assert!(validate_spec(&spec).is_ok());
 }
 
 #[test]
@@ -147,6 +221,7 @@ fn test_message_attributes() {
     assert!(tags.contains(&Tag {
         name: "test".to_string(),
         description: None,
+        extensions: Default::default(),
     }));
 }
 
@@ -296,6 +371,7 @@ fn test_operation_derive() {
     assert!(tags.contains(&Tag {
         name: "test".to_string(),
         description: None,
+        extensions: Default::default(),
     }));
 }
 
@@ -387,6 +463,20 @@ fn test_message_examples() {
     assert_eq!(examples_vec[1]["value"], "second");
 }
 
+// `test_examples` generates a test deserializing each example into the type;
+// it only runs for messages declared at module level
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(
+    channel = "examples.channel",
+    messageId = "tested-example-message-v1",
+    examples = [r#"{"id": "1", "value": "first"}"#, r#"{"id": "2", "value": "second"}"#],
+    test_examples
+)]
+struct TestedExampleMessage {
+    pub id: String,
+    pub value: String,
+}
+
 #[test]
 fn test_message_headers() {
     // Define a header type
@@ -462,7 +552,7 @@ fn test_message_with_examples_and_headers_in_spec() {
     assert!(message.examples.is_some());
     let examples = message.examples.as_ref().unwrap();
     assert_eq!(examples.len(), 1);
-    assert_eq!(examples[0]["id"], "test");
+    assert_eq!(examples[0]["payload"]["id"], "test");
 
     // Verify headers are included
     assert!(message.headers.is_some());
@@ -529,7 +619,7 @@ fn test_try_asyncapi_matches_asyncapi() {
     assert_eq!(spec1.info.title, spec2.info.title);
     assert_eq!(spec1.info.version, spec2.info.version);
     assert_eq!(spec1.channels.len(), spec2.channels.len());
-    // Compare channel keys (order may differ in HashMap)
+    // Compare channel keys
     let mut keys1: Vec<_> = spec1.channels.keys().collect();
     let mut keys2: Vec<_> = spec2.channels.keys().collect();
     keys1.sort();
@@ -880,3 +970,530 @@ fn test_root_level_tags_with_try_asyncapi() {
     assert!(spec.tags.is_some());
     assert_eq!(spec.tags.as_ref().unwrap().len(), 3);
 }
+
+// Nested types produce `$defs` that must end up in `components.schemas`
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct ShippingAddress {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+enum ShippingStatus {
+    Pending,
+    Shipped,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "shipping.updated", summary = "Shipping update")]
+struct ShippingUpdated {
+    pub address: ShippingAddress,
+    pub status: ShippingStatus,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "shipping.created", summary = "Shipping created")]
+struct ShippingCreated {
+    pub address: ShippingAddress,
+}
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Shipping API", version = "1.0.0"),
+    channels("shipping.updated", "shipping.created"),
+    messages(ShippingUpdated, ShippingCreated)
+)]
+struct ShippingApi;
+
+#[test]
+fn test_nested_schemas_hoisted_into_components() {
+    let spec = ShippingApi::asyncapi();
+    let json: serde_json::Value =
+        serde_json::from_str(&protofolio::to_json(&spec).unwrap()).unwrap();
+
+    let schemas = &json["components"]["schemas"];
+    assert_eq!(schemas["ShippingAddress"]["type"], "object");
+    assert!(schemas["ShippingStatus"].is_object());
+    assert_eq!(schemas.as_object().unwrap().len(), 2);
+
+    let payload = &json["channels"]["shipping.updated"]["messages"]["ShippingUpdated"]["payload"];
+    assert!(payload.get("$defs").is_none());
+    assert_eq!(
+        payload["properties"]["address"]["$ref"],
+        "#/components/schemas/ShippingAddress"
+    );
+    assert_eq!(
+        payload["properties"]["status"]["$ref"],
+        "#/components/schemas/ShippingStatus"
+    );
+
+    let json_text = serde_json::to_string(&json).unwrap();
+    assert!(!json_text.contains("#/$defs/"));
+    assert!(validate_spec(&spec).is_ok());
+}
+
+// Request/reply operation types
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "user.requests", summary = "Request a user by ID")]
+struct GetUser {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "user.replies", summary = "User lookup result")]
+struct UserFound {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(AsyncApiOperation)]
+#[asyncapi(
+    id = "get-user",
+    action = "send",
+    channel = "user.requests",
+    messages(GetUser),
+    reply(
+        channel = "user.replies",
+        messages(UserFound),
+        address = "$message.header#/replyTo",
+        address_description = "Inbox named in the request's replyTo header"
+    )
+)]
+struct GetUserOperation;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "User Lookup", version = "1.0.0"),
+    channels("user.requests", "user.replies"),
+    messages(GetUser, UserFound),
+    operations(GetUserOperation)
+)]
+struct UserLookupApi;
+
+#[test]
+fn test_operation_reply() {
+    use protofolio::{OperationReplyAddressOrRef, OperationReplyOrRef};
+
+    assert!(PublishTestMessage::reply().is_none());
+
+    let operation = GetUserOperation::to_operation();
+    let Some(OperationReplyOrRef::Reply(reply)) = operation.reply else {
+        panic!("Expected inline reply");
+    };
+    assert_eq!(reply.channel.unwrap().ref_path, "#/channels/user.replies");
+    let messages = reply.messages.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].ref_path,
+        "#/channels/user.replies/messages/UserFound"
+    );
+    let Some(OperationReplyAddressOrRef::Address(address)) = reply.address else {
+        panic!("Expected inline reply address");
+    };
+    assert_eq!(address.location, "$message.header#/replyTo");
+}
+
+#[test]
+fn test_operation_reply_in_spec() {
+    let spec = UserLookupApi::try_asyncapi().unwrap();
+    assert!(validate_spec(&spec).is_ok());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&protofolio::to_json(&spec).unwrap()).unwrap();
+    let reply = &json["operations"]["get-user"]["reply"];
+    assert_eq!(reply["channel"]["$ref"], "#/channels/user.replies");
+    assert_eq!(
+        reply["messages"][0]["$ref"],
+        "#/channels/user.replies/messages/UserFound"
+    );
+    assert_eq!(reply["address"]["location"], "$message.header#/replyTo");
+}
+
+#[test]
+fn test_spec_serialization_is_deterministic() {
+    // Two generations of the same spec must be byte-identical, so committed
+    // specs don't churn between runs
+    let first = UserLookupApi::asyncapi();
+    let second = UserLookupApi::asyncapi();
+    assert_eq!(
+        protofolio::to_json(&first).unwrap(),
+        protofolio::to_json(&second).unwrap()
+    );
+    assert_eq!(
+        protofolio::to_yaml(&first).unwrap(),
+        protofolio::to_yaml(&second).unwrap()
+    );
+
+    let first = TestAsyncApiWithOperations::asyncapi();
+    let second = TestAsyncApiWithOperations::try_asyncapi().unwrap();
+    assert_eq!(
+        protofolio::to_json(&first).unwrap(),
+        protofolio::to_json(&second).unwrap()
+    );
+}
+
+#[test]
+fn test_spec_preserves_declaration_order() {
+    let spec = TestAsyncApiWithOperations::asyncapi();
+    let channels: Vec<_> = spec.channels.keys().map(String::as_str).collect();
+    assert_eq!(channels, ["test.channel", "simple.channel"]);
+    let operations: Vec<_> = spec
+        .operations
+        .as_ref()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        operations,
+        ["publish-test-message", "subscribe-simple-message"]
+    );
+
+    let json = protofolio::to_json(&spec).unwrap();
+    assert!(json.find("\"test.channel\"").unwrap() < json.find("\"simple.channel\"").unwrap());
+}
+
+// Channel parameters
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(
+    channel = "orders.{tenantId}.created",
+    parameters((
+        name = "tenantId",
+        description = "Tenant the order belongs to",
+        enum = ["acme", "globex"],
+        default = "acme",
+        location = "$message.payload#/tenant"
+    ))
+)]
+struct TenantOrderCreated {
+    pub tenant: String,
+    pub order_id: String,
+}
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Tenant Orders", version = "1.0.0"),
+    channels("orders.{tenantId}.created"),
+    messages(TenantOrderCreated)
+)]
+struct TenantOrdersApi;
+
+#[test]
+fn test_channel_parameters() {
+    assert!(TestMessage::parameters().is_none());
+
+    let spec = TenantOrdersApi::try_asyncapi().unwrap();
+    let channel = &spec.channels["orders.{tenantId}.created"];
+    let parameter = &channel.parameters.as_ref().unwrap()["tenantId"];
+    assert_eq!(
+        parameter.description.as_deref(),
+        Some("Tenant the order belongs to")
+    );
+    assert_eq!(
+        parameter.enum_values,
+        Some(vec!["acme".to_string(), "globex".to_string()])
+    );
+    assert_eq!(parameter.default.as_deref(), Some("acme"));
+    assert_eq!(
+        parameter.location.as_deref(),
+        Some("$message.payload#/tenant")
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&TenantOrdersApi::asyncapi_json().unwrap()).unwrap();
+    assert_eq!(
+        json["channels"]["orders.{tenantId}.created"]["parameters"]["tenantId"]["enum"],
+        serde_json::json!(["acme", "globex"])
+    );
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(
+    channel = "orders.{tenantId}.created",
+    parameters((name = "tenantId", enum = ["initech"]))
+)]
+struct InitechOrderCreated {
+    pub order_id: String,
+}
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Tenant Orders", version = "1.0.0"),
+    channels("orders.{tenantId}.created"),
+    messages(TenantOrderCreated, InitechOrderCreated)
+)]
+struct ConflictingParametersApi;
+
+#[test]
+fn test_conflicting_channel_parameters() {
+    match ConflictingParametersApi::try_asyncapi() {
+        Err(protofolio::ValidationError::InvalidChannelParameter(message)) => {
+            assert!(message.contains("'InitechOrderCreated'"));
+            assert!(message.contains("'tenantId'"));
+        }
+        other => panic!("Expected InvalidChannelParameter error, got: {:?}", other),
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "declares parameter 'tenantId' of channel 'orders.{tenantId}.created' differently"
+)]
+fn test_conflicting_channel_parameters_panic() {
+    let _ = ConflictingParametersApi::asyncapi();
+}
+
+// Specification extensions
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(
+    channel = "payments",
+    extensions(x_owner = "payments", x_internal = true, x_retention = 30)
+)]
+struct PaymentCaptured {
+    pub id: String,
+}
+
+#[derive(AsyncApiOperation)]
+#[asyncapi(
+    id = "publish-payment-captured",
+    action = "send",
+    channel = "payments",
+    messages(PaymentCaptured),
+    extensions("x-slo_ms" = 250, x_teams = ["payments", "billing"])
+)]
+struct PublishPaymentCaptured;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Payments", version = "1.0.0"),
+    channels("payments"),
+    messages(PaymentCaptured),
+    operations(PublishPaymentCaptured),
+    extensions(x_owner = "payments")
+)]
+struct PaymentsApi;
+
+#[test]
+fn test_derive_extensions() {
+    let spec = PaymentsApi::try_asyncapi().unwrap();
+    let json = serde_json::to_value(&spec).unwrap();
+
+    assert_eq!(json["x-owner"], "payments");
+    assert_eq!(
+        json["channels"]["payments"]["messages"]["PaymentCaptured"]["x-owner"],
+        "payments"
+    );
+    assert_eq!(
+        json["channels"]["payments"]["messages"]["PaymentCaptured"]["x-internal"],
+        true
+    );
+    assert_eq!(
+        json["channels"]["payments"]["messages"]["PaymentCaptured"]["x-retention"],
+        30
+    );
+    assert_eq!(
+        json["operations"]["publish-payment-captured"]["x-slo_ms"],
+        250
+    );
+    assert_eq!(
+        json["operations"]["publish-payment-captured"]["x-teams"],
+        serde_json::json!(["payments", "billing"])
+    );
+    assert!(TestMessage::extensions().is_empty());
+    assert!(json["channels"]["payments"].get("x-owner").is_none());
+}
+
+#[test]
+fn test_extensions_round_trip() {
+    let document = serde_json::json!({
+        "asyncapi": "3.0.0",
+        "x-owner": "payments",
+        "info": {
+            "title": "Payments",
+            "version": "1.0.0",
+            "x-internal": true,
+            "contact": {"name": "Payments team", "x-slack": "#payments"}
+        },
+        "servers": {
+            "production": {
+                "host": "nats.example.com",
+                "protocol": "nats",
+                "x-region": "eu-west-1"
+            }
+        },
+        "channels": {
+            "payments": {
+                "address": "payments",
+                "x-retention": "7d",
+                "messages": {
+                    "PaymentCaptured": {
+                        "payload": {"type": "object"},
+                        "tags": [{"name": "payments", "x-color": "green"}],
+                        "x-owner": "payments"
+                    }
+                }
+            }
+        },
+        "operations": {
+            "capture": {
+                "operationId": "capture",
+                "action": "send",
+                "channel": {"$ref": "#/channels/payments"},
+                "messages": [{"$ref": "#/channels/payments/messages/PaymentCaptured"}],
+                "x-internal": false
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "user": {"type": "userPassword", "x-rotation": "90d"}
+            },
+            "x-generated": true
+        }
+    });
+
+    let spec: protofolio::AsyncApiSpec = serde_json::from_value(document.clone()).unwrap();
+    assert_eq!(spec.extensions["x-owner"], "payments");
+    assert_eq!(spec.info.extensions["x-internal"], true);
+    assert_eq!(
+        spec.channels["payments"].extensions["x-retention"],
+        serde_json::json!("7d")
+    );
+    assert_eq!(serde_json::to_value(&spec).unwrap(), document);
+}
+
+#[test]
+fn test_unknown_non_extension_fields_are_dropped() {
+    let spec: protofolio::AsyncApiSpec = serde_json::from_value(serde_json::json!({
+        "asyncapi": "3.0.0",
+        "info": {"title": "Payments", "version": "1.0.0", "owner": "payments"},
+        "channels": {}
+    }))
+    .unwrap();
+
+    assert!(spec.info.extensions.is_empty());
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "user/signedup", messageId = "user-signed-up-v1")]
+struct UserSignedUp {
+    pub user_id: String,
+}
+
+#[derive(AsyncApiOperation)]
+#[asyncapi(
+    id = "on-user-signed-up",
+    action = "receive",
+    channel = "user/signedup",
+    messages(UserSignedUp)
+)]
+struct OnUserSignedUp;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Users", version = "1.0.0"),
+    channels("user/signedup"),
+    messages(UserSignedUp),
+    operations(OnUserSignedUp)
+)]
+struct UsersApi;
+
+#[test]
+fn test_refs_escape_channel_names() {
+    let spec = UsersApi::asyncapi();
+    let operation = &spec.operations.as_ref().unwrap()["on-user-signed-up"];
+    assert_eq!(operation.channel.ref_path, "#/channels/user~1signedup");
+    assert_eq!(
+        operation.messages[0].ref_path,
+        "#/channels/user~1signedup/messages/UserSignedUp"
+    );
+    assert!(validate_spec(&spec).is_ok());
+
+    let channel: protofolio::Channel = spec.resolve(&operation.channel.ref_path).unwrap();
+    assert_eq!(channel.address, "user/signedup");
+    let message: protofolio::Message = spec.resolve(&operation.messages[0].ref_path).unwrap();
+    assert_eq!(message.message_id.as_deref(), Some("user-signed-up-v1"));
+}
+
+#[test]
+fn test_dereferenced_spec() {
+    let protofolio::MessageOrRef::Message(message) =
+        UsersApi::asyncapi().channels["user/signedup"].messages["UserSignedUp"].clone()
+    else {
+        panic!("derived messages are inline");
+    };
+    let spec = protofolio::AsyncApiBuilder::new()
+        .info(protofolio::Info {
+            title: "Users".to_string(),
+            version: "1.0.0".to_string(),
+            description: None,
+            external_docs: None,
+            contact: None,
+            license: None,
+            terms_of_service: None,
+            extensions: Default::default(),
+        })
+        .component_message("UserSignedUp".to_string(), message)
+        .channel(
+            "users".to_string(),
+            protofolio::Channel {
+                address: "users".to_string(),
+                description: None,
+                messages: protofolio::IndexMap::new(),
+                servers: None,
+                parameters: None,
+                bindings: None,
+                extensions: Default::default(),
+            },
+        )
+        .channel_message_ref(
+            "users".to_string(),
+            "UserSignedUp".to_string(),
+            "UserSignedUp".to_string(),
+        )
+        .build();
+
+    let dereferenced = spec.dereferenced().unwrap();
+    let protofolio::MessageOrRef::Message(message) =
+        &dereferenced.channels["users"].messages["UserSignedUp"]
+    else {
+        panic!("component message was not inlined");
+    };
+    assert_eq!(message.message_id.as_deref(), Some("user-signed-up-v1"));
+    // Components are kept
+    assert!(dereferenced
+        .components
+        .unwrap()
+        .messages
+        .unwrap()
+        .contains_key("UserSignedUp"));
+}
+
+#[test]
+fn test_server_security_schemes() {
+    #[derive(AsyncApi)]
+    #[asyncapi(
+        info(title = "Secure API", version = "1.0.0"),
+        servers(
+            (name = "nats", url = "nats://localhost:4222", protocol = "nats", security = ["userPassword", "apiKey"])
+        ),
+        security_schemes(
+            (name = "userPassword", type = "userPassword"),
+            (name = "apiKey", type = "httpApiKey", name_param = "X-API-Key", in = "header")
+        ),
+        channels("test.channel"),
+        messages(TestMessage)
+    )]
+    struct SecureApi;
+
+    let spec = SecureApi::asyncapi();
+    let schemes = spec
+        .components
+        .as_ref()
+        .unwrap()
+        .security_schemes
+        .as_ref()
+        .unwrap();
+    assert!(schemes.contains_key("userPassword"));
+    assert!(schemes.contains_key("apiKey"));
+    assert!(validate_spec(&spec).is_ok());
+}
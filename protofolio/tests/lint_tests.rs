@@ -0,0 +1,155 @@
+//! Tests for linting specifications against conventions
+
+use protofolio::{lint, AsyncApiSpec, Linter, RuleLevel, RuleSet};
+use serde_json::json;
+
+fn spec() -> AsyncApiSpec {
+    serde_json::from_value(json!({
+        "asyncapi": "3.0.0",
+        "info": {"title": "Orders", "version": "1.0.0"},
+        "tags": [{"name": "orders"}],
+        "channels": {
+            "created": {
+                "address": "orders.{orderId}.created",
+                "parameters": {"orderId": {}},
+                "messages": {
+                    "OrderCreated": {"$ref": "#/components/messages/OrderCreated"}
+                }
+            },
+            "cancelled": {
+                "address": "orders/cancelled",
+                "messages": {
+                    "OrderCancelled": {
+                        "messageId": "order-cancelled",
+                        "tags": [{"name": "orders"}, {"name": "billing"}],
+                        "payload": {"type": "object"}
+                    }
+                }
+            }
+        },
+        "operations": {
+            "publish-order-created": {
+                "operationId": "PublishOrderCreated",
+                "action": "send",
+                "channel": {"$ref": "#/channels/created"},
+                "messages": [{"$ref": "#/channels/created/messages/OrderCreated"}]
+            }
+        },
+        "components": {
+            "messages": {
+                "OrderCreated": {
+                    "messageId": "order-created-v1",
+                    "tags": [{"name": "orders"}],
+                    "payload": {"type": "object"},
+                    "traits": [{"$ref": "#/components/messageTraits/Documented"}]
+                }
+            },
+            "messageTraits": {
+                "Documented": {"summary": "Documented message", "examples": [{}]}
+            }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_builtin_rules() {
+    let report = lint(&spec());
+    let issues: Vec<_> = report
+        .issues()
+        .iter()
+        .map(|issue| (issue.rule.as_str(), issue.pointer.as_str()))
+        .collect();
+
+    assert_eq!(
+        issues,
+        [
+            ("channel-address-dot-case", "/channels/cancelled/address"),
+            (
+                "message-id-version",
+                "/channels/cancelled/messages/OrderCancelled/messageId"
+            ),
+            (
+                "message-summary",
+                "/channels/cancelled/messages/OrderCancelled"
+            ),
+            (
+                "message-example",
+                "/channels/cancelled/messages/OrderCancelled"
+            ),
+            (
+                "operation-id-camel-case",
+                "/operations/publish-order-created/operationId"
+            ),
+            (
+                "tags-defined",
+                "/channels/cancelled/messages/OrderCancelled/tags/1"
+            ),
+        ]
+    );
+    assert!(!report.has_errors());
+    assert_eq!(
+        report.issues()[5].to_string(),
+        "warning at /channels/cancelled/messages/OrderCancelled/tags/1: Tag 'billing' is not declared in the root tags [tags-defined]"
+    );
+}
+
+/// Write `contents` to a temporary file with the given extension and read it as a ruleset
+fn ruleset_file(extension: &str, contents: &str) -> Result<RuleSet, protofolio::LintError> {
+    let path = std::env::temp_dir().join(format!(
+        "protofolio-lint-{}.{}",
+        std::process::id(),
+        extension
+    ));
+    std::fs::write(&path, contents).unwrap();
+    let ruleset = RuleSet::from_path(&path);
+    std::fs::remove_file(&path).unwrap();
+    ruleset
+}
+
+#[test]
+fn test_ruleset_file() {
+    let ruleset = ruleset_file(
+        "yaml",
+        "rules:\n  operation-id-camel-case: error\n  tags-defined: off\n",
+    )
+    .unwrap();
+    assert_eq!(
+        ruleset.level("operation-id-camel-case"),
+        Some(RuleLevel::Error)
+    );
+
+    let report = Linter::new().with_ruleset(ruleset).unwrap().lint(&spec());
+    assert_eq!(report.issues().len(), 5);
+    let errors: Vec<_> = report.errors().map(|issue| issue.rule.as_str()).collect();
+    assert_eq!(errors, ["operation-id-camel-case"]);
+    assert!(report
+        .issues()
+        .iter()
+        .all(|issue| issue.rule != "tags-defined"));
+    assert_eq!(report.warnings().count(), 4);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_toml_ruleset_file() {
+    let ruleset = ruleset_file(
+        "toml",
+        "[rules]\noperation-id-camel-case = \"error\"\ntags-defined = \"off\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        ruleset.level("operation-id-camel-case"),
+        Some(RuleLevel::Error)
+    );
+    assert_eq!(ruleset.level("tags-defined"), Some(RuleLevel::Off));
+}
+
+#[cfg(not(feature = "toml"))]
+#[test]
+fn test_toml_ruleset_file_without_feature() {
+    assert!(matches!(
+        ruleset_file("toml", "[rules]\n"),
+        Err(protofolio::LintError::Parse(_))
+    ));
+}
@@ -0,0 +1,10 @@
+// Test that a value outside a binding field's enum is rejected
+
+use protofolio_derive::AsyncApi;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Billing", version = "1.0.0"),
+    channels(("invoices", amqp(is = "exchange")))
+)]
+pub struct BillingApi;
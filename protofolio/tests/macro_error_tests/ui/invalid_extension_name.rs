@@ -0,0 +1,11 @@
+// Test that specification extensions must start with `x-`
+
+use protofolio_derive::AsyncApiMessage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "payments", extensions(owner = "payments"))]
+pub struct PaymentCaptured {
+    pub id: String,
+}
@@ -0,0 +1,19 @@
+// Test that a server URL with nested placeholder braces produces a helpful error
+
+use protofolio_derive::AsyncApi;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Test API", version = "1.0.0"),
+    servers(
+        (
+            name = "nats",
+            url = "nats://{region{host}:4222",
+            protocol = "nats",
+            variables = [(name = "host", default = "localhost")]
+        )
+    ),
+    channels("events"),
+    messages()
+)]
+pub struct MyApi;
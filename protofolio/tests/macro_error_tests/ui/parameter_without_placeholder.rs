@@ -0,0 +1,11 @@
+// Test that a parameter missing from the channel address produces a helpful error
+
+use protofolio_derive::AsyncApiMessage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "orders.created", parameters((name = "tenantId")))]
+pub struct OrderCreated {
+    pub id: String,
+}
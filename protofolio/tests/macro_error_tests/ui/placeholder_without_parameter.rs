@@ -0,0 +1,11 @@
+// Test that a channel address placeholder without a parameter produces a helpful error
+
+use protofolio_derive::AsyncApiMessage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "orders.{tenantId}.created")]
+pub struct OrderCreated {
+    pub id: String,
+}
@@ -0,0 +1,13 @@
+// Test that reply messages without a reply channel produce a helpful error
+
+use protofolio_derive::AsyncApiOperation;
+
+#[derive(AsyncApiOperation)]
+#[asyncapi(
+    id = "get-user",
+    action = "send",
+    channel = "user.requests",
+    messages(GetUser),
+    reply(messages(UserFound))
+)]
+pub struct GetUserOperation;
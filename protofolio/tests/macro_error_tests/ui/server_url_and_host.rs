@@ -0,0 +1,12 @@
+// Test that giving both url and host for a server produces a helpful error
+
+use protofolio_derive::AsyncApi;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Test", version = "1.0.0"),
+    servers((name = "nats", url = "nats://localhost:4222", host = "localhost:4222", protocol = "nats")),
+    channels("events"),
+    messages()
+)]
+pub struct MyApi;
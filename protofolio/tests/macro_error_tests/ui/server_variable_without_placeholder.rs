@@ -0,0 +1,22 @@
+// Test that a server variable missing from the server URL produces a helpful error
+
+use protofolio_derive::AsyncApi;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Test API", version = "1.0.0"),
+    servers(
+        (
+            name = "nats",
+            url = "nats://{host}:4222",
+            protocol = "nats",
+            variables = [
+                (name = "host", default = "localhost"),
+                (name = "port", default = "4222")
+            ]
+        )
+    ),
+    channels("events"),
+    messages()
+)]
+pub struct MyApi;
@@ -0,0 +1,11 @@
+// Test that test_examples requires examples to test
+
+use protofolio_derive::AsyncApiMessage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "payments", test_examples)]
+pub struct PaymentCaptured {
+    pub id: String,
+}
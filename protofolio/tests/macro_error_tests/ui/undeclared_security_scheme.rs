@@ -0,0 +1,17 @@
+// Test that a server requiring an undeclared security scheme produces a helpful error
+
+use protofolio_derive::AsyncApi;
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Test API", version = "1.0.0"),
+    servers(
+        (name = "nats", url = "nats://localhost:4222", protocol = "nats", security = ["userPasword"])
+    ),
+    security_schemes(
+        (name = "userPassword", type = "userPassword")
+    ),
+    channels("events"),
+    messages()
+)]
+pub struct MyApi;
@@ -0,0 +1,11 @@
+// Test that a field unknown to the binding struct is rejected at compile time
+
+use protofolio_derive::AsyncApiMessage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "orders", kafka(partitions = 12))]
+pub struct OrderPlaced {
+    pub id: String,
+}
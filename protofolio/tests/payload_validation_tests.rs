@@ -0,0 +1,109 @@
+//! Tests for validating runtime payloads against generated message schemas
+
+use protofolio::{AsyncApi, PayloadError};
+use protofolio_derive::{AsyncApi, AsyncApiMessage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Address {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+#[asyncapi(channel = "users", messageId = "user-signed-up-v1")]
+struct UserSignedUp {
+    pub id: u64,
+    pub email: String,
+    pub plan: Plan,
+    pub address: Option<Address>,
+}
+
+#[derive(AsyncApi)]
+#[asyncapi(
+    info(title = "Users", version = "1.0.0"),
+    channels("users"),
+    messages(UserSignedUp)
+)]
+struct UsersApi;
+
+#[test]
+fn test_valid_payload() {
+    let spec = UsersApi::asyncapi();
+    let payload = serde_json::to_value(UserSignedUp {
+        id: 7,
+        email: "user@example.com".to_string(),
+        plan: Plan::Pro,
+        address: Some(Address {
+            street: "Main St".to_string(),
+            city: "Springfield".to_string(),
+        }),
+    })
+    .unwrap();
+
+    assert_eq!(
+        spec.validate_payload("users", "UserSignedUp", &payload),
+        Ok(())
+    );
+    // Messages without a headers schema accept any headers
+    assert_eq!(
+        spec.validate_headers("users", "UserSignedUp", &json!({"trace": 1})),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_invalid_payload_reports_every_violation() {
+    let spec = UsersApi::asyncapi();
+    let payload = json!({
+        "id": -1,
+        "plan": "Enterprise",
+        "address": {"street": "Main St", "city": 5}
+    });
+
+    let Err(PayloadError::Invalid(violations)) =
+        spec.validate_payload("users", "UserSignedUp", &payload)
+    else {
+        panic!("payload should be invalid");
+    };
+    let paths: Vec<&str> = violations
+        .iter()
+        .map(|violation| violation.instance_path.as_str())
+        .collect();
+    // `Option<Address>` is an `anyOf`, reported where no alternative matched
+    for path in ["", "/id", "/plan", "/address"] {
+        assert!(
+            paths.contains(&path),
+            "no violation at {:?}: {:?}",
+            path,
+            violations
+        );
+    }
+    assert!(violations
+        .iter()
+        .all(|violation| !violation.schema_path.is_empty()));
+}
+
+#[test]
+fn test_unknown_channel_or_message() {
+    let spec = UsersApi::asyncapi();
+    assert_eq!(
+        spec.validate_payload("orders", "UserSignedUp", &json!({})),
+        Err(PayloadError::ChannelNotFound("orders".to_string()))
+    );
+    assert_eq!(
+        spec.validate_payload("users", "OrderPlaced", &json!({})),
+        Err(PayloadError::MessageNotFound {
+            channel: "users".to_string(),
+            message: "OrderPlaced".to_string()
+        })
+    );
+}
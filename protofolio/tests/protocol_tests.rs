@@ -1,11 +1,11 @@
 //! Protocol-specific tests for Kafka and MQTT
 
+use protofolio::IndexMap;
 use protofolio::{
     validate_spec, AsyncApiBuilder, Channel, Info, KafkaProtocol, Message, MessagePayload,
     MqttProtocol, MqttQos, Protocol, Server, KAFKA_DEFAULT_PORT, KAFKA_PROTOCOL, MQTT_DEFAULT_PORT,
     MQTT_DEFAULT_SECURE_PORT, MQTT_PROTOCOL,
 };
-use std::collections::HashMap;
 
 #[test]
 fn test_kafka_protocol_constants() {
@@ -27,22 +27,24 @@ fn test_mqtt_protocol_constants() {
 #[test]
 fn test_kafka_channel_binding() {
     let binding = KafkaProtocol::channel_binding(Some("user-events".to_string()), Some(3), Some(2));
+    let binding = serde_json::to_value(binding).unwrap();
 
     assert!(binding["kafka"]["topic"].as_str().is_some());
     assert_eq!(binding["kafka"]["topic"], "user-events");
     assert_eq!(binding["kafka"]["partitions"], 3);
     assert_eq!(binding["kafka"]["replicas"], 2);
-    assert_eq!(binding["kafka"]["binding_version"], "0.4.0");
+    assert_eq!(binding["kafka"]["bindingVersion"], "0.4.0");
 }
 
 #[test]
 fn test_kafka_message_binding() {
     let key_schema = serde_json::json!({"type": "string"});
-    let binding = KafkaProtocol::message_binding(Some(key_schema.clone()));
+    let binding =
+        serde_json::to_value(KafkaProtocol::message_binding(Some(key_schema.clone()))).unwrap();
 
     assert!(!binding["kafka"]["key"].is_null());
     assert_eq!(binding["kafka"]["key"], key_schema);
-    assert_eq!(binding["kafka"]["binding_version"], "0.4.0");
+    assert_eq!(binding["kafka"]["bindingVersion"], "0.4.0");
 }
 
 #[test]
@@ -52,20 +54,22 @@ fn test_mqtt_channel_binding() {
         Some(MqttQos::AtLeastOnce),
         Some(false),
     );
+    let binding = serde_json::to_value(binding).unwrap();
 
     assert_eq!(binding["mqtt"]["topic"], "sensors/temperature");
     assert_eq!(binding["mqtt"]["qos"], 1);
     assert_eq!(binding["mqtt"]["retain"], false);
-    assert_eq!(binding["mqtt"]["binding_version"], "0.2.0");
+    assert_eq!(binding["mqtt"]["bindingVersion"], "0.2.0");
 }
 
 #[test]
 fn test_mqtt_message_binding() {
     let binding = MqttProtocol::message_binding(Some(MqttQos::ExactlyOnce), Some(true));
+    let binding = serde_json::to_value(binding).unwrap();
 
     assert_eq!(binding["mqtt"]["qos"], 2);
     assert_eq!(binding["mqtt"]["retain"], true);
-    assert_eq!(binding["mqtt"]["binding_version"], "0.2.0");
+    assert_eq!(binding["mqtt"]["bindingVersion"], "0.2.0");
 }
 
 #[test]
@@ -79,15 +83,24 @@ fn test_kafka_spec_with_builder() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .server(
             "kafka-broker".to_string(),
             Server {
-                url: format!("kafka://localhost:{}", KAFKA_DEFAULT_PORT),
+                host: format!("localhost:{}", KAFKA_DEFAULT_PORT),
                 protocol: KAFKA_PROTOCOL.to_string(),
+                protocol_version: None,
+                pathname: None,
+                title: None,
+                summary: None,
                 description: Some("Main Kafka broker".to_string()),
+                tags: None,
+                external_docs: None,
+                bindings: None,
                 security: None,
                 variables: None,
+                extensions: Default::default(),
             },
         )
         .kafka_channel(
@@ -96,7 +109,7 @@ fn test_kafka_spec_with_builder() {
                 address: "user.events".to_string(),
                 description: Some("User events channel".to_string()),
                 messages: {
-                    let mut m = HashMap::new();
+                    let mut m = IndexMap::new();
                     m.insert(
                         "UserEvent".to_string(),
                         protofolio::MessageOrRef::message(Message {
@@ -116,6 +129,7 @@ fn test_kafka_spec_with_builder() {
                             correlation_id: None,
                             traits: None,
                             bindings: None,
+                            extensions: Default::default(),
                         }),
                     );
                     m
@@ -123,6 +137,7 @@ fn test_kafka_spec_with_builder() {
                 servers: Some(vec!["kafka-broker".to_string()]),
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
             Some("user-events".to_string()),
             Some(3),
@@ -141,10 +156,11 @@ fn test_kafka_spec_with_builder() {
     let bindings = channel.bindings.as_ref().unwrap();
     match bindings {
         protofolio::ChannelBindingsOrRef::Bindings(b) => {
-            assert_eq!(b["kafka"]["topic"], "user-events");
-            assert_eq!(b["kafka"]["partitions"], 3);
+            let kafka = b.kafka.as_ref().unwrap();
+            assert_eq!(kafka.topic.as_deref(), Some("user-events"));
+            assert_eq!(kafka.partitions, Some(3));
         }
-        protofolio::ChannelBindingsOrRef::Ref(_) => panic!("Expected bindings, got reference"),
+        _ => panic!("Expected typed bindings"),
     }
 
     assert!(validate_spec(&spec).is_ok());
@@ -161,15 +177,24 @@ fn test_mqtt_spec_with_builder() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .server(
             "mqtt-broker".to_string(),
             Server {
-                url: format!("mqtt://mqtt.example.com:{}", MQTT_DEFAULT_PORT),
+                host: format!("mqtt.example.com:{}", MQTT_DEFAULT_PORT),
                 protocol: MQTT_PROTOCOL.to_string(),
+                protocol_version: None,
+                pathname: None,
+                title: None,
+                summary: None,
                 description: None,
+                tags: None,
+                external_docs: None,
+                bindings: None,
                 security: None,
                 variables: None,
+                extensions: Default::default(),
             },
         )
         .mqtt_channel(
@@ -178,7 +203,7 @@ fn test_mqtt_spec_with_builder() {
                 address: "sensors/temperature".to_string(),
                 description: Some("Temperature sensor data".to_string()),
                 messages: {
-                    let mut m = HashMap::new();
+                    let mut m = IndexMap::new();
                     m.insert(
                         "TemperatureReading".to_string(),
                         protofolio::MessageOrRef::message(Message {
@@ -198,6 +223,7 @@ fn test_mqtt_spec_with_builder() {
                             correlation_id: None,
                             traits: None,
                             bindings: None,
+                            extensions: Default::default(),
                         }),
                     );
                     m
@@ -205,6 +231,7 @@ fn test_mqtt_spec_with_builder() {
                 servers: Some(vec!["mqtt-broker".to_string()]),
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
             Some("sensors/temperature".to_string()),
             Some(MqttQos::AtLeastOnce),
@@ -223,16 +250,379 @@ fn test_mqtt_spec_with_builder() {
     let bindings = channel.bindings.as_ref().unwrap();
     match bindings {
         protofolio::ChannelBindingsOrRef::Bindings(b) => {
-            assert_eq!(b["mqtt"]["topic"], "sensors/temperature");
-            assert_eq!(b["mqtt"]["qos"], 1);
-            assert_eq!(b["mqtt"]["retain"], false);
+            let mqtt = b.mqtt.as_ref().unwrap();
+            assert_eq!(mqtt.topic.as_deref(), Some("sensors/temperature"));
+            assert_eq!(mqtt.qos, Some(1));
+            assert_eq!(mqtt.retain, Some(false));
         }
-        protofolio::ChannelBindingsOrRef::Ref(_) => panic!("Expected bindings, got reference"),
+        _ => panic!("Expected typed bindings"),
     }
 
     assert!(validate_spec(&spec).is_ok());
 }
 
+#[test]
+fn test_bindings_deserialization() {
+    use protofolio::{AsyncApiSpec, ChannelBindingsOrRef};
+
+    let spec: AsyncApiSpec = serde_json::from_value(serde_json::json!({
+        "asyncapi": "3.0.0",
+        "info": {"title": "Bindings", "version": "1.0.0"},
+        "channels": {
+            "typed": {
+                "address": "typed",
+                "messages": {},
+                "bindings": {"kafka": {"topic": "typed", "bindingVersion": "0.4.0"}}
+            },
+            "reference": {
+                "address": "reference",
+                "messages": {},
+                "bindings": {"$ref": "#/components/channelBindings/Kafka"}
+            },
+            "custom": {
+                "address": "custom",
+                "messages": {},
+                "bindings": {"ibmmq": {"queue": "orders"}}
+            }
+        }
+    }))
+    .unwrap();
+
+    match &spec.channels["typed"].bindings {
+        Some(ChannelBindingsOrRef::Bindings(b)) => {
+            let kafka = b.kafka.as_ref().unwrap();
+            assert_eq!(kafka.topic.as_deref(), Some("typed"));
+            assert_eq!(kafka.binding_version.as_deref(), Some("0.4.0"));
+        }
+        other => panic!("Expected typed bindings, got {:?}", other),
+    }
+    assert!(matches!(
+        spec.channels["reference"].bindings,
+        Some(ChannelBindingsOrRef::Ref(_))
+    ));
+    assert!(matches!(
+        spec.channels["custom"].bindings,
+        Some(ChannelBindingsOrRef::Custom(_))
+    ));
+
+    // Custom bindings are written back unchanged
+    let json = serde_json::to_value(&spec).unwrap();
+    assert_eq!(
+        json["channels"]["custom"]["bindings"],
+        serde_json::json!({"ibmmq": {"queue": "orders"}})
+    );
+}
+
+#[test]
+#[cfg(feature = "amqp")]
+fn test_amqp_spec_with_builder() {
+    use protofolio::{
+        AmqpChannelIs, AmqpExchange, AmqpExchangeType, AMQPS_PROTOCOL, AMQP_DEFAULT_SECURE_PORT,
+    };
+
+    let spec = AsyncApiBuilder::new()
+        .info(Info {
+            title: "Billing API".to_string(),
+            version: "1.0.0".to_string(),
+            description: None,
+            external_docs: None,
+            contact: None,
+            license: None,
+            terms_of_service: None,
+            extensions: Default::default(),
+        })
+        .server(
+            "rabbitmq".to_string(),
+            Server {
+                host: format!("rabbitmq.example.com:{}", AMQP_DEFAULT_SECURE_PORT),
+                protocol: AMQPS_PROTOCOL.to_string(),
+                protocol_version: Some("0.9.1".to_string()),
+                pathname: None,
+                title: None,
+                summary: None,
+                description: None,
+                tags: None,
+                external_docs: None,
+                bindings: None,
+                security: None,
+                variables: None,
+                extensions: Default::default(),
+            },
+        )
+        .amqp_channel(
+            "invoices".to_string(),
+            Channel {
+                address: "invoices.created".to_string(),
+                description: None,
+                messages: {
+                    let mut m = IndexMap::new();
+                    m.insert(
+                        "InvoiceCreated".to_string(),
+                        protofolio::MessageOrRef::message(Message {
+                            message_id: Some("invoice-created-v1".to_string()),
+                            name: Some("InvoiceCreated".to_string()),
+                            title: None,
+                            summary: None,
+                            description: None,
+                            content_type: None,
+                            tags: None,
+                            payload: MessagePayload {
+                                schema: serde_json::json!({"type": "object"}),
+                            },
+                            external_docs: None,
+                            examples: None,
+                            headers: None,
+                            correlation_id: None,
+                            traits: None,
+                            bindings: None,
+                            extensions: Default::default(),
+                        }),
+                    );
+                    m
+                },
+                servers: Some(vec!["rabbitmq".to_string()]),
+                parameters: None,
+                bindings: None,
+                extensions: Default::default(),
+            },
+            AmqpChannelIs::RoutingKey,
+            Some(AmqpExchange {
+                name: Some("billing".to_string()),
+                r#type: Some(AmqpExchangeType::Topic),
+                durable: Some(true),
+                auto_delete: None,
+                vhost: None,
+            }),
+            None,
+        )
+        .build();
+
+    let json = serde_json::to_value(&spec).unwrap();
+    assert_eq!(
+        json["channels"]["invoices"]["bindings"]["amqp"]["exchange"],
+        serde_json::json!({"name": "billing", "type": "topic", "durable": true})
+    );
+    assert!(validate_spec(&spec).is_ok());
+}
+
+#[test]
+#[cfg(feature = "ws")]
+fn test_ws_spec_with_builder() {
+    use protofolio::{WsMethod, WSS_PROTOCOL, WS_DEFAULT_SECURE_PORT};
+
+    let channel = || Channel {
+        address: "/notifications".to_string(),
+        description: None,
+        messages: {
+            let mut m = IndexMap::new();
+            m.insert(
+                "Notification".to_string(),
+                protofolio::MessageOrRef::message(Message {
+                    message_id: None,
+                    name: Some("Notification".to_string()),
+                    title: None,
+                    summary: None,
+                    description: None,
+                    content_type: None,
+                    tags: None,
+                    payload: MessagePayload {
+                        schema: serde_json::json!({"type": "object"}),
+                    },
+                    external_docs: None,
+                    examples: None,
+                    headers: None,
+                    correlation_id: None,
+                    traits: None,
+                    bindings: None,
+                    extensions: Default::default(),
+                }),
+            );
+            m
+        },
+        servers: Some(vec!["gateway".to_string()]),
+        parameters: None,
+        bindings: None,
+        extensions: Default::default(),
+    };
+    let spec = |query: serde_json::Value| {
+        AsyncApiBuilder::new()
+            .info(Info {
+                title: "Gateway API".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                external_docs: None,
+                contact: None,
+                license: None,
+                terms_of_service: None,
+                extensions: Default::default(),
+            })
+            .server(
+                "gateway".to_string(),
+                Server {
+                    host: format!("gateway.example.com:{}", WS_DEFAULT_SECURE_PORT),
+                    protocol: WSS_PROTOCOL.to_string(),
+                    protocol_version: None,
+                    pathname: None,
+                    title: None,
+                    summary: None,
+                    description: None,
+                    tags: None,
+                    external_docs: None,
+                    bindings: None,
+                    security: None,
+                    variables: None,
+                    extensions: Default::default(),
+                },
+            )
+            .ws_channel(
+                "notifications".to_string(),
+                channel(),
+                Some(WsMethod::Get),
+                Some(query),
+                None,
+            )
+            .build()
+    };
+
+    let valid = spec(serde_json::json!({
+        "type": "object",
+        "properties": {"token": {"type": "string"}}
+    }));
+    let json = serde_json::to_value(&valid).unwrap();
+    assert_eq!(
+        json["channels"]["notifications"]["bindings"]["ws"]["method"],
+        "GET"
+    );
+    assert!(validate_spec(&valid).is_ok());
+
+    let invalid = spec(serde_json::json!({"type": "string"}));
+    let err = validate_spec(&invalid).unwrap_err();
+    assert!(err.to_string().contains("'query' must be an object schema"));
+}
+
+#[test]
+fn test_custom_protocol_registry() {
+    use protofolio::{register_protocol, ChannelBindingsOrRef, Protocol, ValidationError};
+
+    struct RedisStreams;
+
+    impl Protocol for RedisStreams {
+        fn name() -> &'static str {
+            "Redis Streams"
+        }
+
+        fn identifier() -> &'static str {
+            "redis"
+        }
+
+        fn identifiers() -> Vec<&'static str> {
+            vec!["redis", "rediss"]
+        }
+
+        fn default_port() -> Option<u16> {
+            Some(6379)
+        }
+
+        fn validate_channel_bindings(
+            bindings: &ChannelBindingsOrRef,
+            channel_name: &str,
+        ) -> Result<(), ValidationError> {
+            let stream = match bindings {
+                ChannelBindingsOrRef::Custom(value) => value.pointer("/redis/stream"),
+                _ => None,
+            };
+            match stream {
+                Some(_) => Ok(()),
+                None => Err(ValidationError::InvalidSchema(format!(
+                    "Channel '{}': Redis channel bindings must name a stream",
+                    channel_name
+                ))),
+            }
+        }
+    }
+
+    let spec = |bindings: serde_json::Value| {
+        AsyncApiBuilder::new()
+            .info(Info {
+                title: "Streams".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                external_docs: None,
+                contact: None,
+                license: None,
+                terms_of_service: None,
+                extensions: Default::default(),
+            })
+            .server(
+                "redis".to_string(),
+                Server {
+                    host: "redis.example.com:6380".to_string(),
+                    protocol: "rediss".to_string(),
+                    protocol_version: None,
+                    pathname: None,
+                    title: None,
+                    summary: None,
+                    description: None,
+                    tags: None,
+                    external_docs: None,
+                    bindings: None,
+                    security: None,
+                    variables: None,
+                    extensions: Default::default(),
+                },
+            )
+            .channel(
+                "events".to_string(),
+                Channel {
+                    address: "events".to_string(),
+                    description: None,
+                    messages: {
+                        let mut m = IndexMap::new();
+                        m.insert(
+                            "Event".to_string(),
+                            protofolio::MessageOrRef::message(Message {
+                                message_id: None,
+                                name: None,
+                                title: None,
+                                summary: None,
+                                description: None,
+                                content_type: None,
+                                tags: None,
+                                payload: MessagePayload {
+                                    schema: serde_json::json!({"type": "object"}),
+                                },
+                                external_docs: None,
+                                examples: None,
+                                headers: None,
+                                correlation_id: None,
+                                traits: None,
+                                bindings: None,
+                                extensions: Default::default(),
+                            }),
+                        );
+                        m
+                    },
+                    servers: Some(vec!["redis".to_string()]),
+                    parameters: None,
+                    bindings: Some(ChannelBindingsOrRef::custom(bindings)),
+                    extensions: Default::default(),
+                },
+            )
+            .build()
+    };
+
+    let valid = spec(serde_json::json!({"redis": {"stream": "events"}}));
+    let err = validate_spec(&valid).unwrap_err();
+    assert!(err.to_string().contains("unsupported protocol 'rediss'"));
+
+    register_protocol::<RedisStreams>();
+    assert!(validate_spec(&valid).is_ok());
+
+    let invalid = spec(serde_json::json!({"redis": {}}));
+    let err = validate_spec(&invalid).unwrap_err();
+    assert!(err.to_string().contains("must name a stream"));
+}
+
 #[test]
 fn test_protocol_validation() {
     // Test protocol validation through server creation
@@ -245,35 +635,60 @@ fn test_protocol_validation() {
             contact: None,
             license: None,
             terms_of_service: None,
+            extensions: Default::default(),
         })
         .server(
             "kafka-server".to_string(),
             Server {
-                url: "kafka://localhost:9092".to_string(),
+                host: "localhost:9092".to_string(),
                 protocol: "kafka".to_string(),
+                protocol_version: None,
+                pathname: None,
+                title: None,
+                summary: None,
                 description: None,
+                tags: None,
+                external_docs: None,
+                bindings: None,
                 security: None,
                 variables: None,
+                extensions: Default::default(),
             },
         )
         .server(
             "mqtt-server".to_string(),
             Server {
-                url: "mqtt://localhost:1883".to_string(),
+                host: "localhost:1883".to_string(),
                 protocol: "mqtt".to_string(),
+                protocol_version: None,
+                pathname: None,
+                title: None,
+                summary: None,
                 description: None,
+                tags: None,
+                external_docs: None,
+                bindings: None,
                 security: None,
                 variables: None,
+                extensions: Default::default(),
             },
         )
         .server(
             "nats-server".to_string(),
             Server {
-                url: "nats://localhost:4222".to_string(),
+                host: "localhost:4222".to_string(),
                 protocol: "nats".to_string(),
+                protocol_version: None,
+                pathname: None,
+                title: None,
+                summary: None,
                 description: None,
+                tags: None,
+                external_docs: None,
+                bindings: None,
                 security: None,
                 variables: None,
+                extensions: Default::default(),
             },
         )
         .channel(
@@ -282,7 +697,7 @@ fn test_protocol_validation() {
                 address: "test.channel".to_string(),
                 description: None,
                 messages: {
-                    let mut m = HashMap::new();
+                    let mut m = IndexMap::new();
                     m.insert(
                         "TestMessage".to_string(),
                         protofolio::MessageOrRef::message(Message {
@@ -302,6 +717,7 @@ fn test_protocol_validation() {
                             correlation_id: None,
                             traits: None,
                             bindings: None,
+                            extensions: Default::default(),
                         }),
                     );
                     m
@@ -309,6 +725,7 @@ fn test_protocol_validation() {
                 servers: None,
                 parameters: None,
                 bindings: None,
+                extensions: Default::default(),
             },
         )
         .build();
@@ -328,3 +745,192 @@ fn test_mqtt_qos_enum() {
     assert_eq!(MqttQos::from_u8(2), Some(MqttQos::ExactlyOnce));
     assert_eq!(MqttQos::from_u8(3), None);
 }
+
+mod derive_bindings {
+    use protofolio::{AsyncApi, AsyncApiOperation, ChannelBindingsOrRef, MessageBindingsOrRef};
+    use protofolio_derive::{AsyncApi, AsyncApiMessage, AsyncApiOperation};
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+    #[asyncapi(channel = "orders", mqtt(qos = 1, retain = true))]
+    pub struct OrderPlaced {
+        pub id: String,
+    }
+
+    #[derive(AsyncApiOperation)]
+    #[asyncapi(
+        id = "consume-orders",
+        action = "receive",
+        channel = "orders",
+        messages(OrderPlaced),
+        nats(queue = "order-workers"),
+        kafka(group_id = "order-service")
+    )]
+    pub struct ConsumeOrders;
+
+    #[derive(AsyncApi)]
+    #[asyncapi(
+        info(title = "Orders", version = "1.0.0"),
+        channels(("orders", kafka(topic = "orders.v1", partitions = 12, replicas = 3))),
+        messages(OrderPlaced),
+        operations(ConsumeOrders)
+    )]
+    pub struct OrdersApi;
+
+    #[cfg(feature = "amqp")]
+    #[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+    #[asyncapi(
+        channel = "invoices",
+        amqp(content_encoding = "gzip", message_type = "billing.invoice.created")
+    )]
+    pub struct InvoiceCreated {
+        pub id: String,
+    }
+
+    #[cfg(feature = "amqp")]
+    #[derive(AsyncApiOperation)]
+    #[asyncapi(
+        id = "publish-invoice",
+        action = "send",
+        channel = "invoices",
+        messages(InvoiceCreated),
+        amqp(delivery_mode = 2, priority = 5, cc = ["audit"], mandatory = true)
+    )]
+    pub struct PublishInvoice;
+
+    #[cfg(feature = "amqp")]
+    #[derive(AsyncApi)]
+    #[asyncapi(
+        info(title = "Billing", version = "1.0.0"),
+        channels((
+            "invoices",
+            amqp(
+                is = "routingKey",
+                exchange(name = "billing", type = "topic", durable = true),
+                binding_version = "0.3.0"
+            )
+        )),
+        messages(InvoiceCreated),
+        operations(PublishInvoice)
+    )]
+    pub struct BillingApi;
+
+    #[cfg(feature = "ws")]
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    pub struct NotificationQuery {
+        pub token: String,
+        pub since: Option<u64>,
+    }
+
+    #[cfg(feature = "ws")]
+    #[derive(Serialize, Deserialize, JsonSchema, AsyncApiMessage)]
+    #[asyncapi(channel = "notifications")]
+    pub struct Notification {
+        pub text: String,
+    }
+
+    #[cfg(feature = "ws")]
+    #[derive(AsyncApi)]
+    #[asyncapi(
+        info(title = "Gateway", version = "1.0.0"),
+        channels(
+            ("notifications", ws(method = "GET", query = NotificationQuery)),
+            ("orders", kafka(topic = "orders.v1"))
+        ),
+        messages(Notification, OrderPlaced)
+    )]
+    pub struct GatewayApi;
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn test_derive_ws_bindings() {
+        let spec = GatewayApi::try_asyncapi().unwrap();
+
+        let Some(ChannelBindingsOrRef::Bindings(ref channel)) =
+            spec.channels["notifications"].bindings
+        else {
+            panic!("Expected inline channel bindings");
+        };
+        let ws = channel.ws.as_ref().unwrap();
+        assert_eq!(ws.method, Some(protofolio::WsMethod::Get));
+        assert_eq!(
+            ws.query,
+            Some(protofolio::schema_for_type::<NotificationQuery>().unwrap())
+        );
+        assert_eq!(ws.query.as_ref().unwrap()["type"], "object");
+        assert!(ws.headers.is_none());
+
+        let Some(ChannelBindingsOrRef::Bindings(ref orders)) = spec.channels["orders"].bindings
+        else {
+            panic!("Expected inline channel bindings");
+        };
+        assert!(orders.kafka.is_some());
+        assert!(orders.ws.is_none());
+    }
+
+    #[cfg(feature = "amqp")]
+    #[test]
+    fn test_derive_amqp_bindings() {
+        let spec = BillingApi::try_asyncapi().unwrap();
+        let json = serde_json::to_value(&spec).unwrap();
+
+        assert_eq!(
+            json["channels"]["invoices"]["bindings"],
+            serde_json::json!({
+                "amqp": {
+                    "is": "routingKey",
+                    "exchange": {"name": "billing", "type": "topic", "durable": true},
+                    "bindingVersion": "0.3.0"
+                }
+            })
+        );
+        assert_eq!(
+            json["operations"]["publish-invoice"]["bindings"],
+            serde_json::json!({
+                "amqp": {"cc": ["audit"], "priority": 5, "deliveryMode": 2, "mandatory": true}
+            })
+        );
+        assert_eq!(
+            json["channels"]["invoices"]["messages"]["InvoiceCreated"]["bindings"],
+            serde_json::json!({
+                "amqp": {"contentEncoding": "gzip", "messageType": "billing.invoice.created"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_derive_bindings() {
+        let spec = OrdersApi::try_asyncapi().unwrap();
+
+        let Some(ChannelBindingsOrRef::Bindings(ref channel)) = spec.channels["orders"].bindings
+        else {
+            panic!("Expected inline channel bindings");
+        };
+        let kafka = channel.kafka.as_ref().unwrap();
+        assert_eq!(kafka.topic.as_deref(), Some("orders.v1"));
+        assert_eq!(kafka.partitions, Some(12));
+        assert_eq!(kafka.replicas, Some(3));
+
+        let Some(MessageBindingsOrRef::Bindings(message)) = OrderPlaced::bindings() else {
+            panic!("Expected inline message bindings");
+        };
+        assert_eq!(
+            serde_json::to_value(message).unwrap(),
+            serde_json::json!({"mqtt": {"qos": 1, "retain": true}})
+        );
+
+        let operation = ConsumeOrders::to_operation();
+        let json = serde_json::to_value(&operation).unwrap();
+        assert_eq!(
+            json["bindings"],
+            serde_json::json!({
+                "kafka": {"groupId": "order-service"},
+                "nats": {"queue": "order-workers"}
+            })
+        );
+        assert!(spec.operations.unwrap()["consume-orders"]
+            .bindings
+            .is_some());
+    }
+}